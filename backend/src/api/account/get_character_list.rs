@@ -0,0 +1,75 @@
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+use crate::api::upstream::{parse_json, send, upstream_error};
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct CharacterListQuery {
+    #[serde(default)]
+    world_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct AccountCharacterInfo {
+    ocid: String,
+    character_name: String,
+    world_name: String,
+    character_class: String,
+    character_level: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct AccountInfo {
+    account_id: String,
+    character_list: Vec<AccountCharacterInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CharacterList {
+    account_list: Vec<AccountInfo>,
+}
+
+// API 키 소유자 계정의 캐릭터 목록 조회 (ocid 불필요)
+#[utoipa::path(
+    get,
+    path = "/getCharacterList",
+    tag = "account",
+    params(CharacterListQuery),
+    responses((status = 200, body = CharacterList))
+)]
+pub async fn get_character_list(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<CharacterListQuery>,
+) -> Result<Json<CharacterList>, AppError> {
+    // 요청할 API의 URL
+    let url = api_key.url("character/list");
+
+    // GET 요청 보내기
+    let response = send(&api_key, api_key.client.get(url)).await?;
+
+    // 응답 결과 확인
+    if !response.status().is_success() {
+        return Err(upstream_error(response).await);
+    }
+
+    let mut character_list: CharacterList = parse_json(response).await?;
+
+    // 월드 필터
+    if let Some(world_name) = query.world_name {
+        for account in character_list.account_list.iter_mut() {
+            account
+                .character_list
+                .retain(|character| character.world_name == world_name);
+        }
+        character_list
+            .account_list
+            .retain(|account| !account.character_list.is_empty());
+    }
+
+    Ok(Json(character_list))
+}
@@ -0,0 +1,308 @@
+use crate::api::client::NexonClient;
+use crate::api::error::AppError;
+use crate::api::metrics::record_cache;
+use crate::api::region::current_region;
+use crate::api::upstream::{request_error, send};
+
+#[cfg(feature = "server")]
+use axum::{
+    extract::Request,
+    http::{HeaderValue, header::HeaderName},
+    middleware::Next,
+    response::Response,
+};
+use bytes::Bytes;
+use dashmap::DashMap;
+use reqwest::{RequestBuilder, ResponseBuilderExt, Url};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub const CACHE_HEADER: &str = "x-melog-cache";
+
+// (ocid, 넥슨 API 경로, 조회 날짜)
+pub type CacheKey = (String, String, String);
+
+struct CacheEntry {
+    url: Url,
+    body: Bytes,
+    stored_at: Instant,
+    // 만료 순서 색인의 번호
+    seq: u64,
+}
+
+// 저장 순서 → 키
+// 모든 항목의 TTL 이 같으므로 저장 순서가 곧 만료 순서다
+#[derive(Default)]
+struct ExpiryIndex {
+    next_seq: u64,
+    keys: BTreeMap<u64, CacheKey>,
+}
+
+// 넥슨 API 응답 캐시
+// 캐릭터 데이터는 하루에 한 번 갱신되므로 같은 날짜의 응답을 TTL 동안 재사용한다
+pub struct ResponseCache {
+    entries: DashMap<CacheKey, CacheEntry>,
+    // 저장 / 제거는 색인 잠금 안에서 처리해 항목과 색인이 어긋나지 않게 한다
+    index: Mutex<ExpiryIndex>,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl ResponseCache {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            index: Mutex::new(ExpiryIndex::default()),
+            ttl,
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    // 만료된 항목의 색인은 다음 저장 때 정리한다
+    fn get(&self, key: &CacheKey) -> Option<reqwest::Response> {
+        let entry = self.entries.get(key)?;
+
+        if entry.stored_at.elapsed() > self.ttl {
+            drop(entry);
+            self.entries
+                .remove_if(key, |_, entry| entry.stored_at.elapsed() > self.ttl);
+            return None;
+        }
+
+        Some(to_response(entry.url.clone(), entry.body.clone()))
+    }
+
+    fn insert(&self, key: CacheKey, url: Url, body: Bytes) {
+        let mut index = self
+            .index
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let seq = index.next_seq;
+        index.next_seq += 1;
+        index.keys.insert(seq, key.clone());
+
+        let replaced = self.entries.insert(
+            key,
+            CacheEntry {
+                url,
+                body,
+                stored_at: Instant::now(),
+                seq,
+            },
+        );
+        if let Some(replaced) = replaced {
+            index.keys.remove(&replaced.seq);
+        }
+
+        self.evict(&mut index);
+    }
+
+    // 만료된 항목과 최대 항목 수를 넘는 항목을 오래된 순서로 제거
+    // 색인의 앞쪽만 확인하므로 제거하는 항목 수에 비례한다
+    fn evict(&self, index: &mut ExpiryIndex) {
+        while let Some(oldest) = index.keys.first_entry() {
+            let seq = *oldest.key();
+            let state = self
+                .entries
+                .get(oldest.get())
+                .filter(|cached| cached.seq == seq)
+                .map(|cached| cached.stored_at.elapsed() > self.ttl);
+
+            match state {
+                // 조회 중 만료되어 이미 제거된 항목
+                None => {
+                    oldest.remove();
+                }
+                Some(false) if self.entries.len() <= self.max_entries => break,
+                Some(_) => {
+                    let key = oldest.remove();
+                    self.entries.remove_if(&key, |_, cached| cached.seq == seq);
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// 캐시된 본문으로 reqwest 응답을 다시 만든다 (URL은 오류 메시지에 사용)
+fn to_response(url: Url, body: Bytes) -> reqwest::Response {
+    let response = http02::Response::builder()
+        .url(url)
+        .body(body)
+        .unwrap_or_default();
+
+    reqwest::Response::from(response)
+}
+
+// 요청 단위 캐시 상태 (?refresh=true 여부, 캐시 사용 결과)
+#[derive(Clone)]
+struct CacheContext {
+    refresh: bool,
+    hit: Arc<AtomicBool>,
+    miss: Arc<AtomicBool>,
+}
+
+tokio::task_local! {
+    static CACHE_CONTEXT: CacheContext;
+}
+
+// 캐시를 거쳐 넥슨 API 요청 전송
+// 성공 응답만 저장하고, ?refresh=true 인 경우 캐시를 무시하고 새로 조회한다
+pub async fn send_cached(
+    api: &NexonClient,
+    key: CacheKey,
+    request: RequestBuilder,
+) -> Result<reqwest::Response, AppError> {
+    let refresh = CACHE_CONTEXT
+        .try_with(|context| context.refresh)
+        .unwrap_or(false);
+
+    // 같은 경로라도 지역마다 데이터가 다르므로 지역 접두사를 붙여 저장한다
+    let endpoint = key.1.clone();
+    let key = (
+        key.0,
+        format!("{}/{}", current_region().api_prefix(), key.1),
+        key.2,
+    );
+
+    if !refresh && let Some(response) = api.cache.get(&key) {
+        let _ = CACHE_CONTEXT.try_with(|context| context.hit.store(true, Ordering::Relaxed));
+        record_cache(&endpoint, "hit");
+        return Ok(response);
+    }
+    let _ = CACHE_CONTEXT.try_with(|context| context.miss.store(true, Ordering::Relaxed));
+    record_cache(&endpoint, "miss");
+
+    let response = send(api, request).await?;
+    if !response.status().is_success() {
+        return Ok(response);
+    }
+
+    let url = response.url().clone();
+    let body = response.bytes().await.map_err(request_error)?;
+    api.cache.insert(key, url.clone(), body.clone());
+
+    Ok(to_response(url, body))
+}
+
+// 응답에 x-melog-cache 헤더 (hit / miss) 추가
+// 요청에 포함된 모든 캐시 조회가 캐시에서 처리된 경우에만 hit
+#[cfg(feature = "server")]
+pub async fn cache_status(request: Request, next: Next) -> Response {
+    let refresh = request
+        .uri()
+        .query()
+        .is_some_and(|query| query.split('&').any(|pair| pair == "refresh=true"));
+
+    let context = CacheContext {
+        refresh,
+        hit: Arc::new(AtomicBool::new(false)),
+        miss: Arc::new(AtomicBool::new(false)),
+    };
+    let (hit, miss) = (context.hit.clone(), context.miss.clone());
+
+    let mut response = CACHE_CONTEXT.scope(context, next.run(request)).await;
+
+    let hit = hit.load(Ordering::Relaxed);
+    let miss = miss.load(Ordering::Relaxed);
+    if hit || miss {
+        let value = if hit && !miss { "hit" } else { "miss" };
+        response.headers_mut().insert(
+            HeaderName::from_static(CACHE_HEADER),
+            HeaderValue::from_static(value),
+        );
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(ocid: &str) -> CacheKey {
+        (
+            ocid.to_string(),
+            "character/basic".to_string(),
+            "2024-06-01".to_string(),
+        )
+    }
+
+    fn insert(cache: &ResponseCache, ocid: &str) {
+        let url = Url::parse("https://open.api.nexon.com/maplestory/v1/character/basic").unwrap();
+        cache.insert(key(ocid), url, Bytes::from_static(b"{}"));
+    }
+
+    fn index_len(cache: &ResponseCache) -> usize {
+        cache.index.lock().unwrap().keys.len()
+    }
+
+    #[test]
+    fn evicts_oldest_entry_when_full() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 2);
+        insert(&cache, "a");
+        insert(&cache, "b");
+        insert(&cache, "c");
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key("a")).is_none());
+        assert!(cache.get(&key("b")).is_some());
+        assert!(cache.get(&key("c")).is_some());
+    }
+
+    #[test]
+    fn reinserting_moves_entry_to_the_back() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 2);
+        insert(&cache, "a");
+        insert(&cache, "b");
+        insert(&cache, "a");
+        insert(&cache, "c");
+
+        assert!(cache.get(&key("a")).is_some());
+        assert!(cache.get(&key("b")).is_none());
+        assert_eq!(index_len(&cache), 2);
+    }
+
+    #[test]
+    fn expired_entries_are_removed_on_insert() {
+        let cache = ResponseCache::new(Duration::from_millis(20), 10);
+        insert(&cache, "a");
+        insert(&cache, "b");
+        std::thread::sleep(Duration::from_millis(30));
+        // 조회로 먼저 제거된 항목의 색인도 함께 정리된다
+        assert!(cache.get(&key("a")).is_none());
+
+        insert(&cache, "c");
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(index_len(&cache), 1);
+        assert!(cache.get(&key("c")).is_some());
+    }
+
+    #[test]
+    fn entry_removed_by_get_and_stored_again_is_not_evicted_early() {
+        let cache = ResponseCache::new(Duration::from_millis(20), 2);
+        insert(&cache, "a");
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(cache.get(&key("a")).is_none());
+
+        // 예전 색인 번호가 남아 있어도 새로 저장한 "a" 는 가장 최근 항목이다
+        insert(&cache, "a");
+        insert(&cache, "b");
+        insert(&cache, "c");
+
+        assert!(cache.get(&key("a")).is_none());
+        assert!(cache.get(&key("b")).is_some());
+        assert!(cache.get(&key("c")).is_some());
+    }
+}
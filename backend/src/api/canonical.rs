@@ -0,0 +1,88 @@
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+// 키 순서에 관계없이 동일한 문자열이 나오도록 JSON을 정규화
+// 객체의 키는 사전순으로 정렬하고, 공백 없이 직렬화한다
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+// 정규화된 JSON의 SHA-256 해시 (hex)
+pub fn content_hash(value: &Value) -> String {
+    let digest = Sha256::digest(canonical_json(value).as_bytes());
+
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn canonical_json_sorts_nested_keys() {
+        let value = json!({ "b": { "y": 1, "x": [{ "d": null, "c": "가" }] }, "a": 1.5 });
+
+        assert_eq!(
+            canonical_json(&value),
+            r#"{"a":1.5,"b":{"x":[{"c":"가","d":null}],"y":1}}"#
+        );
+    }
+
+    #[test]
+    fn content_hash_ignores_key_order_but_not_array_order() {
+        let hash = content_hash(&json!({ "a": 1, "b": [1, 2], "c": { "x": true, "y": false } }));
+
+        assert_eq!(
+            hash,
+            content_hash(&json!({ "c": { "y": false, "x": true }, "b": [1, 2], "a": 1 }))
+        );
+        assert_ne!(
+            hash,
+            content_hash(&json!({ "a": 1, "b": [2, 1], "c": { "x": true, "y": false } }))
+        );
+        assert_eq!(hash.len(), 64);
+    }
+
+    #[test]
+    fn content_hash_is_sha256_of_canonical_json() {
+        // echo -n '{}' | sha256sum
+        assert_eq!(
+            content_hash(&json!({})),
+            "44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"
+        );
+    }
+}
@@ -1,57 +1,97 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
 
-use axum::{Extension, http::StatusCode, response::Json};
-use reqwest::{Client, header};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct UserOcid {
     pub ocid: String,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Character {
     nick_name: String,
 }
 
+// 닉네임 최대 길이 (한글 등 비 ASCII 문자는 2, 영문/숫자는 1로 계산)
+// 게임 규칙상 한글 6자, 영문 12자까지 허용된다
+const NICK_NAME_MAX_WIDTH: usize = 12;
+
+// 넥슨 API에 요청하기 전에 닉네임 형식을 확인
+pub fn validate_nick_name(nick_name: &str) -> Result<(), AppError> {
+    if nick_name.is_empty() {
+        return Err(AppError::Unprocessable("nickName must not be empty"));
+    }
+
+    if nick_name
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control())
+    {
+        return Err(AppError::Unprocessable(
+            "nickName must not contain whitespace or control characters",
+        ));
+    }
+
+    let width: usize = nick_name
+        .chars()
+        .map(|c| if c.is_ascii() { 1 } else { 2 })
+        .sum();
+    if width > NICK_NAME_MAX_WIDTH {
+        return Err(AppError::Unprocessable(
+            "nickName is too long (up to 6 Korean or 12 alphanumeric characters)",
+        ));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/getOcid",
+    tag = "character",
+    request_body = Character,
+    responses((status = 200, body = UserOcid))
+)]
 pub async fn get_ocid(
     Extension(api_key): Extension<Arc<API>>,
     Json(character): Json<Character>,
-) -> Result<Json<UserOcid>, (StatusCode, &'static str)> {
-    let client = Client::new();
-
-    // 요청할 API의 URL
-    let url = format!(
-        "https://open.api.nexon.com/maplestory/v1/id?character_name={}",
-        character.nick_name
-    );
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
-
-    // POST 요청 보내기
-    let response = client
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let userocid: UserOcid = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(userocid))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<UserOcid>, AppError> {
+    validate_nick_name(&character.nick_name)?;
+
+    let ocid = api_key.get_ocid(&character.nick_name).await?;
+
+    Ok(Json(UserOcid { ocid }))
 }
 
-// TODO : 장착 헤어, 성형, 피부 정보 조회
-// TODO : 장착 안드로이드 조회
-// TODO : 장착 펫 정보 조회
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_names_up_to_the_width_limit() {
+        for name in ["데모", "가나다라마바", "abcdefghijkl", "메이플12345", "a1"] {
+            assert!(validate_nick_name(name).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn rejects_empty_blank_and_long_names() {
+        let message = |name: &str| match validate_nick_name(name) {
+            Err(AppError::Unprocessable(message)) => message,
+            other => panic!("{name}: {other:?}"),
+        };
+
+        assert_eq!(message(""), "nickName must not be empty");
+        assert!(message("데 모").contains("whitespace"));
+        assert!(message("데모\t").contains("whitespace"));
+        assert!(message("데모\u{0}").contains("control"));
+        assert!(message("가나다라마바사").contains("too long"));
+        assert!(message("abcdefghijklm").contains("too long"));
+        // 한글 5자 + 영문 3자 = 13
+        assert!(message("가나다라마abc").contains("too long"));
+    }
+}
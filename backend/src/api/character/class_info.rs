@@ -0,0 +1,118 @@
+// 직업 메타데이터 (character_class 값 기준)
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassGender {
+    // 남녀 모두 생성 가능
+    Any,
+    Male,
+    Female,
+    // 성별이 다른 두 캐릭터를 번갈아 사용 (제로)
+    Both,
+}
+
+impl ClassGender {
+    pub fn is_fixed(self) -> bool {
+        self != ClassGender::Any
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClassInfo {
+    pub name: &'static str,
+    pub group: &'static str,
+    pub gender: ClassGender,
+}
+
+const fn class(name: &'static str, group: &'static str, gender: ClassGender) -> ClassInfo {
+    ClassInfo {
+        name,
+        group,
+        gender,
+    }
+}
+
+use ClassGender::{Any, Both, Female, Male};
+
+pub const CLASSES: [ClassInfo; 49] = [
+    class("히어로", "모험가", Any),
+    class("팔라딘", "모험가", Any),
+    class("다크나이트", "모험가", Any),
+    class("아크메이지(불,독)", "모험가", Any),
+    class("아크메이지(썬,콜)", "모험가", Any),
+    class("비숍", "모험가", Any),
+    class("보우마스터", "모험가", Any),
+    class("신궁", "모험가", Any),
+    class("패스파인더", "모험가", Any),
+    class("나이트로드", "모험가", Any),
+    class("섀도어", "모험가", Any),
+    class("듀얼블레이더", "모험가", Any),
+    class("바이퍼", "모험가", Any),
+    class("캡틴", "모험가", Any),
+    class("캐논마스터", "모험가", Any),
+    class("소울마스터", "시그너스 기사단", Any),
+    class("플레임위자드", "시그너스 기사단", Any),
+    class("윈드브레이커", "시그너스 기사단", Any),
+    class("나이트워커", "시그너스 기사단", Any),
+    class("스트라이커", "시그너스 기사단", Any),
+    class("미하일", "시그너스 기사단", Male),
+    class("아란", "영웅", Any),
+    class("에반", "영웅", Any),
+    class("루미너스", "영웅", Any),
+    class("메르세데스", "영웅", Any),
+    class("팬텀", "영웅", Any),
+    class("은월", "영웅", Male),
+    class("블래스터", "레지스탕스", Any),
+    class("배틀메이지", "레지스탕스", Any),
+    class("와일드헌터", "레지스탕스", Any),
+    class("메카닉", "레지스탕스", Any),
+    class("제논", "레지스탕스", Any),
+    class("데몬슬레이어", "레지스탕스", Any),
+    class("데몬어벤져", "레지스탕스", Any),
+    class("카이저", "노바", Male),
+    class("카인", "노바", Male),
+    class("카데나", "노바", Female),
+    class("엔젤릭버스터", "노바", Female),
+    class("아델", "레프", Male),
+    class("일리움", "레프", Male),
+    class("칼리", "레프", Female),
+    class("아크", "레프", Male),
+    class("라라", "아니마", Female),
+    class("호영", "아니마", Male),
+    class("제로", "초월자", Both),
+    class("키네시스", "프렌즈 월드", Male),
+    class("하야토", "전국", Male),
+    class("칸나", "전국", Female),
+    class("렌", "강호", Male),
+];
+
+// 목록에 없는 직업 (신규 직업 등) 은 None
+pub fn class_info(name: &str) -> Option<&'static ClassInfo> {
+    CLASSES.iter().find(|class| class.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_class_gender() {
+        assert_eq!(class_info("히어로").map(|c| c.gender), Some(Any));
+        assert_eq!(class_info("카이저").map(|c| c.gender), Some(Male));
+        assert_eq!(class_info("엔젤릭버스터").map(|c| c.gender), Some(Female));
+        assert_eq!(class_info("제로").map(|c| c.gender), Some(Both));
+        assert_eq!(class_info("없는 직업"), None);
+    }
+
+    #[test]
+    fn class_names_are_unique() {
+        for (i, class) in CLASSES.iter().enumerate() {
+            assert!(
+                CLASSES[i + 1..]
+                    .iter()
+                    .all(|other| other.name != class.name),
+                "{}",
+                class.name
+            );
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// HEXA 코어 최대 레벨
+pub const MAX_LEVEL: usize = 30;
+
+// 레벨별 솔 에르다 조각 소모량 (index 0 = 1레벨 활성화, index 29 = 30레벨 강화)
+const SKILL_CORE: [u32; MAX_LEVEL] = [
+    100, 30, 35, 40, 45, 50, 55, 60, 65, 200, 80, 90, 100, 110, 120, 130, 140, 150, 160, 350, 170,
+    180, 190, 200, 210, 220, 230, 240, 250, 500,
+];
+
+const MASTERY_CORE: [u32; MAX_LEVEL] = [
+    50, 15, 18, 20, 23, 25, 28, 30, 33, 100, 40, 45, 50, 55, 60, 65, 70, 75, 80, 175, 85, 90, 95,
+    100, 105, 110, 115, 120, 125, 250,
+];
+
+const ENHANCEMENT_CORE: [u32; MAX_LEVEL] = [
+    75, 23, 27, 30, 34, 38, 42, 45, 49, 150, 60, 68, 75, 83, 90, 98, 105, 113, 120, 263, 128, 135,
+    143, 150, 158, 165, 173, 180, 188, 375,
+];
+
+const COMMON_CORE: [u32; MAX_LEVEL] = [
+    125, 38, 44, 50, 57, 63, 69, 75, 82, 300, 110, 124, 138, 152, 165, 179, 193, 207, 220, 525,
+    234, 248, 262, 275, 289, 303, 317, 330, 344, 750,
+];
+
+// 코어 종류 (hexa_core_type) 별 소모량 표
+// 알 수 없는 종류 (새로 추가된 코어 등) 는 None
+pub fn cost_table(core_type: &str) -> Option<&'static [u32; MAX_LEVEL]> {
+    match core_type {
+        "스킬 코어" => Some(&SKILL_CORE),
+        "마스터리 코어" => Some(&MASTERY_CORE),
+        "강화 코어" => Some(&ENHANCEMENT_CORE),
+        "공용 코어" => Some(&COMMON_CORE),
+        _ => None,
+    }
+}
+
+// 현재 레벨까지 사용한 조각 수
+pub fn fragments_spent(table: &[u32; MAX_LEVEL], level: i8) -> u32 {
+    table[..clamp_level(level)].iter().sum()
+}
+
+// 최대 레벨까지 필요한 조각 수
+pub fn fragments_to_max(table: &[u32; MAX_LEVEL], level: i8) -> u32 {
+    table[clamp_level(level)..].iter().sum()
+}
+
+fn clamp_level(level: i8) -> usize {
+    usize::try_from(level).unwrap_or(0).min(MAX_LEVEL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_known_core_type_has_a_table() {
+        let totals = ["스킬 코어", "마스터리 코어", "강화 코어", "공용 코어"]
+            .map(|core_type| fragments_to_max(cost_table(core_type).unwrap(), 0));
+
+        assert_eq!(totals, [4500, 2252, 3383, 6268]);
+    }
+
+    #[test]
+    fn unknown_core_type_has_no_table() {
+        assert!(cost_table("신규 코어").is_none());
+        assert!(cost_table("").is_none());
+    }
+
+    #[test]
+    fn spent_and_remaining_add_up_to_the_full_table() {
+        let table = cost_table("스킬 코어").unwrap();
+
+        assert_eq!(fragments_spent(table, 1), 100);
+        assert_eq!(fragments_spent(table, 10), 680);
+        assert_eq!(fragments_to_max(table, 10), 3820);
+        for level in 0..=MAX_LEVEL as i8 {
+            assert_eq!(
+                fragments_spent(table, level) + fragments_to_max(table, level),
+                4500
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_levels_are_clamped() {
+        let table = cost_table("공용 코어").unwrap();
+
+        assert_eq!(fragments_spent(table, -1), 0);
+        assert_eq!(fragments_to_max(table, -1), 6268);
+        assert_eq!(fragments_spent(table, 40), 6268);
+        assert_eq!(fragments_to_max(table, 40), 0);
+    }
+}
@@ -1,17 +1,54 @@
+// 응답 파서 / 계산기
+pub mod class_info;
+pub mod hexa_cost;
+pub mod normalize;
+pub mod potential;
+
+// 핸들러와 응답 구조체
+#[allow(clippy::module_inception)]
+#[cfg(feature = "server")]
 pub mod character;
+#[cfg(feature = "server")]
 pub mod request;
+#[cfg(feature = "server")]
 pub mod user_ability;
+#[cfg(feature = "server")]
 pub mod user_android_equipment;
+#[cfg(feature = "server")]
+pub mod user_beauty_equipment;
+#[cfg(feature = "server")]
 pub mod user_cashitem_equipment;
+#[cfg(feature = "server")]
 pub mod user_characeter_skill;
+#[cfg(feature = "server")]
+pub mod user_character_image;
+#[cfg(feature = "server")]
 pub mod user_default_info;
+#[cfg(feature = "server")]
+pub mod user_digest;
+#[cfg(feature = "server")]
 pub mod user_dojang;
+#[cfg(feature = "server")]
 pub mod user_hexa_matrix;
+#[cfg(feature = "server")]
 pub mod user_hexa_matrix_stat;
+#[cfg(feature = "server")]
 pub mod user_hyper_stat_info;
+#[cfg(feature = "server")]
 pub mod user_item_equipment;
+#[cfg(feature = "server")]
+pub mod user_pet_equipment;
+#[cfg(feature = "server")]
+pub mod user_popularity;
+#[cfg(feature = "server")]
 pub mod user_propensity;
+#[cfg(feature = "server")]
 pub mod user_set_effect;
+#[cfg(feature = "server")]
 pub mod user_stat_info;
+#[cfg(feature = "server")]
+pub mod user_summary;
+#[cfg(feature = "server")]
 pub mod user_symbol_equipment;
+#[cfg(feature = "server")]
 pub mod user_v_matrix;
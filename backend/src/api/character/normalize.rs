@@ -0,0 +1,138 @@
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+// 아이템 옵션 값의 단위
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OptionUnit {
+    Flat,    // 고정 수치 (STR +10)
+    Percent, // 퍼센트 (보스 몬스터 데미지 +30%)
+    Level,   // 레벨 (착용 레벨 감소 -15)
+    Unknown, // 단위를 정하지 않은 필드 (넥슨 API에 새로 추가된 필드 등)
+}
+
+// 필드 이름별 단위
+// 같은 구조체 안에 정수/퍼센트/레벨 값이 섞여 있으므로 필드 단위로 구분한다
+// 목록에 없는 필드를 고정 수치로 잘못 표시하지 않도록 Unknown 으로 반환한다
+pub fn option_unit(field: &str) -> OptionUnit {
+    match field {
+        "str" | "dex" | "int" | "luk" | "max_hp" | "max_mp" | "attack_power" | "magic_power"
+        | "armor" | "speed" | "jump" => OptionUnit::Flat,
+        "boss_damage"
+        | "ignore_monster_armor"
+        | "all_stat"
+        | "damage"
+        | "max_hp_rate"
+        | "max_mp_rate" => OptionUnit::Percent,
+        "equipment_level_decrease" | "base_equipment_level" | "exceptional_upgrade" => {
+            OptionUnit::Level
+        }
+        _ => OptionUnit::Unknown,
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct OptionValue {
+    pub field: String,
+    pub value: f64,
+    pub unit: OptionUnit,
+}
+
+// "+9%", "-5", "1,000" 형태의 값을 숫자로 변환
+pub fn parse_option_value(raw: &str) -> Option<f64> {
+    let cleaned: String = raw
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '+' | '%' | ','))
+        .collect();
+
+    if cleaned.is_empty() {
+        return None;
+    }
+
+    cleaned.parse().ok()
+}
+
+// 옵션 구조체를 단위가 붙은 값 목록으로 변환 (0인 값은 제외)
+pub fn normalize_option<T: Serialize>(option: &T) -> Vec<OptionValue> {
+    let Ok(Value::Object(fields)) = serde_json::to_value(option) else {
+        return Vec::new();
+    };
+
+    fields
+        .into_iter()
+        .filter_map(|(field, value)| {
+            let value = match value {
+                Value::Number(number) => number.as_f64()?,
+                Value::String(raw) => parse_option_value(&raw)?,
+                _ => return None,
+            };
+
+            if value == 0.0 {
+                return None;
+            }
+
+            Some(OptionValue {
+                unit: option_unit(&field),
+                field,
+                value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unlisted_field_is_unknown() {
+        assert_eq!(option_unit("str"), OptionUnit::Flat);
+        assert_eq!(option_unit("boss_damage"), OptionUnit::Percent);
+        assert_eq!(option_unit("exceptional_upgrade"), OptionUnit::Level);
+        assert_eq!(option_unit("critical_damage"), OptionUnit::Unknown);
+    }
+
+    #[test]
+    fn parse_option_value_strips_sign_percent_and_commas() {
+        assert_eq!(parse_option_value("+9%"), Some(9.0));
+        assert_eq!(parse_option_value("-5"), Some(-5.0));
+        assert_eq!(parse_option_value("1,000"), Some(1000.0));
+        assert_eq!(parse_option_value(""), None);
+        assert_eq!(parse_option_value("abc"), None);
+    }
+
+    #[test]
+    fn normalize_option_skips_zero_and_non_numeric_values() {
+        let values = normalize_option(&json!({
+            "str": "+10",
+            "dex": "0",
+            "boss_damage": "30%",
+            "exceptional_upgrade": 1,
+            "name": "아케인셰이드",
+        }));
+
+        assert_eq!(
+            values,
+            [
+                OptionValue {
+                    field: "boss_damage".to_string(),
+                    value: 30.0,
+                    unit: OptionUnit::Percent
+                },
+                OptionValue {
+                    field: "exceptional_upgrade".to_string(),
+                    value: 1.0,
+                    unit: OptionUnit::Level
+                },
+                OptionValue {
+                    field: "str".to_string(),
+                    value: 10.0,
+                    unit: OptionUnit::Flat
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+// 잠재능력 옵션 종류
+// 같은 스탯의 고정 수치 / 퍼센트 옵션은 is_percent 로 구분한다
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PotentialStat {
+    Str,
+    Dex,
+    Int,
+    Luk,
+    AllStat,
+    MaxHp,
+    MaxMp,
+    AttackPower,
+    MagicPower,
+    Damage,
+    BossDamage,
+    IgnoreDefense,
+    CritRate,
+    CritDamage,
+    // 모든 스킬의 재사용 대기시간 (초)
+    CooldownReduction,
+    ItemDropRate,
+    MesoObtain,
+    // 알 수 없는 옵션 (원본 문자열)
+    Raw(String),
+}
+
+impl PotentialStat {
+    fn from_name(name: &str) -> Option<Self> {
+        let stat = match name {
+            "STR" => PotentialStat::Str,
+            "DEX" => PotentialStat::Dex,
+            "INT" => PotentialStat::Int,
+            "LUK" => PotentialStat::Luk,
+            "올스탯" => PotentialStat::AllStat,
+            "최대 HP" => PotentialStat::MaxHp,
+            "최대 MP" => PotentialStat::MaxMp,
+            "공격력" => PotentialStat::AttackPower,
+            "마력" => PotentialStat::MagicPower,
+            "데미지" => PotentialStat::Damage,
+            "보스 몬스터 공격 시 데미지" => PotentialStat::BossDamage,
+            "몬스터 방어율 무시" => PotentialStat::IgnoreDefense,
+            "크리티컬 확률" => PotentialStat::CritRate,
+            "크리티컬 데미지" => PotentialStat::CritDamage,
+            "모든 스킬의 재사용 대기시간" => PotentialStat::CooldownReduction,
+            "아이템 드롭률" => PotentialStat::ItemDropRate,
+            "메소 획득량" => PotentialStat::MesoObtain,
+            _ => return None,
+        };
+
+        Some(stat)
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct PotentialLine {
+    stat: PotentialStat,
+    // 부호를 포함한 값 ("-2초" → -2), 알 수 없는 옵션은 null
+    value: Option<f64>,
+    is_percent: bool,
+}
+
+// "+40%", "-2초", "+12" 형태의 값을 숫자로 변환
+fn parse_value(raw: &str) -> Option<(f64, bool)> {
+    let raw = raw.trim();
+    let is_percent = raw.ends_with('%');
+    let number = raw.trim_end_matches(['%', '초']).trim().replace(',', "");
+
+    number
+        .strip_prefix('+')
+        .unwrap_or(&number)
+        .parse()
+        .ok()
+        .map(|value| (value, is_percent))
+}
+
+// 잠재능력 한 줄 파싱 ("보스 몬스터 공격 시 데미지 : +40%")
+// 형식이 다르거나 알 수 없는 옵션은 Raw 로 원본을 유지한다
+pub fn parse_potential(line: &str) -> PotentialLine {
+    let raw = || PotentialLine {
+        stat: PotentialStat::Raw(line.to_string()),
+        value: None,
+        is_percent: false,
+    };
+
+    let Some((name, value)) = line.split_once(" : ") else {
+        return raw();
+    };
+
+    match (PotentialStat::from_name(name.trim()), parse_value(value)) {
+        (Some(stat), Some((value, is_percent))) => PotentialLine {
+            stat,
+            value: Some(value),
+            is_percent,
+        },
+        _ => raw(),
+    }
+}
+
+// 잠재능력 1~3 줄 파싱 (빈 줄은 제외)
+pub fn parse_potentials(lines: [&str; 3]) -> Vec<PotentialLine> {
+    lines
+        .into_iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_potential)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(stat: PotentialStat, value: f64, is_percent: bool) -> PotentialLine {
+        PotentialLine {
+            stat,
+            value: Some(value),
+            is_percent,
+        }
+    }
+
+    #[test]
+    fn parses_percent_and_flat_options() {
+        assert_eq!(
+            parse_potential("보스 몬스터 공격 시 데미지 : +40%"),
+            line(PotentialStat::BossDamage, 40.0, true)
+        );
+        assert_eq!(
+            parse_potential("STR : +12%"),
+            line(PotentialStat::Str, 12.0, true)
+        );
+        assert_eq!(
+            parse_potential("공격력 : +12"),
+            line(PotentialStat::AttackPower, 12.0, false)
+        );
+        assert_eq!(
+            parse_potential("최대 HP : +1,200"),
+            line(PotentialStat::MaxHp, 1200.0, false)
+        );
+    }
+
+    #[test]
+    fn keeps_sign_of_cooldown_seconds() {
+        assert_eq!(
+            parse_potential("모든 스킬의 재사용 대기시간 : -2초"),
+            line(PotentialStat::CooldownReduction, -2.0, false)
+        );
+    }
+
+    #[test]
+    fn unknown_or_malformed_lines_are_kept_raw() {
+        for raw in [
+            "HP 회복 아이템 및 회복 스킬 효율 : +30%",
+            "피격 시 10% 확률로 데미지의 50% 무시",
+            "STR : 알 수 없음",
+        ] {
+            assert_eq!(
+                parse_potential(raw),
+                PotentialLine {
+                    stat: PotentialStat::Raw(raw.to_string()),
+                    value: None,
+                    is_percent: false,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn skips_empty_lines() {
+        let lines = parse_potentials(["LUK : +9%", "", " "]);
+        assert_eq!(lines, [line(PotentialStat::Luk, 9.0, true)]);
+    }
+
+    #[test]
+    fn serializes_stat_names_in_snake_case() {
+        let value = serde_json::to_value(parse_potential("몬스터 방어율 무시 : +30%")).unwrap();
+        assert_eq!(value["stat"], "ignore_defense");
+
+        let value = serde_json::to_value(parse_potential("알 수 없는 옵션")).unwrap();
+        assert_eq!(value["stat"]["raw"], "알 수 없는 옵션");
+        assert_eq!(value["value"], serde_json::Value::Null);
+    }
+}
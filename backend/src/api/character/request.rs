@@ -1,31 +1,33 @@
+use crate::api::date::QueryDate;
+use crate::api::error::AppError;
 use crate::api::request::API;
 
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-use reqwest::{Client, header};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
+use utoipa::IntoParams;
 
-pub async fn request_parser(api_key: Arc<API>, kind: &str, user_ocid: &str) -> reqwest::Response {
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
-
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
-
-    let url = format!(
-        "https://open.api.nexon.com/maplestory/v1/character/{}?ocid={}&date={}",
-        kind, user_ocid, now_time
-    );
-
-    // POST 요청 보내기
-    let response = Client::new()
-        .get(url)
-        .headers(headers)
-        .send()
+// 넥슨 API를 조회하고 응답을 T로 변환
+pub async fn fetch_character<T: DeserializeOwned>(
+    api_key: Arc<API>,
+    kind: &str,
+    user_ocid: &str,
+    date: &QueryDate,
+) -> Result<T, AppError> {
+    api_key
+        .get_character(&format!("character/{kind}"), user_ocid, date)
         .await
-        .expect("Failed to send request");
+}
+
+// v2 엔드포인트의 필터 옵션 (?filter=active)
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct FilterQuery {
+    #[serde(default)]
+    filter: Option<String>,
+}
 
-    return response;
+impl FilterQuery {
+    pub fn is_active(&self) -> bool {
+        self.filter.as_deref() == Some("active")
+    }
 }
@@ -1,41 +1,71 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
 use super::character::UserOcid;
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct AbilityInfo {
     ability_no: String,
     ability_grade: String,
     ability_value: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct AbilityPreset {
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    ability_preset_grade: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    ability_info: Vec<AbilityInfo>,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct Ability {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     ability_grade: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     ability_info: Vec<AbilityInfo>,
+    #[serde(default)]
+    remain_fame: Option<u32>,
+    #[serde(default)]
+    preset_no: Option<u8>,
+    // 해금하지 않은 프리셋은 null
+    #[serde(default)]
+    ability_preset_1: Option<AbilityPreset>,
+    #[serde(default)]
+    ability_preset_2: Option<AbilityPreset>,
+    #[serde(default)]
+    ability_preset_3: Option<AbilityPreset>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserAbility",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = Ability))
+)]
 pub async fn get_user_ability(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<Ability>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "ability", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_ability: Ability = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_ability))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<Ability>, AppError> {
+    fetch_character(api_key, "ability", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(Json)
 }
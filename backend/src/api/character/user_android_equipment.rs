@@ -1,36 +1,72 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::character::user_beauty_equipment::{FaceInfo, HairInfo};
+use crate::api::character::user_cashitem_equipment::CashItemEquipmentInfo;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct AndroidSkinInfo {
+    skin_name: String,
+    color_style: Option<String>,
+    hue: Option<i32>,
+    saturation: Option<i32>,
+    brightness: Option<i32>,
+}
+
+// 안드로이드를 장착하지 않은 경우 모든 값이 null
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct AndroidEquipment {
-    android_name: String,
-    android_nickname: String,
-    android_icon: String,
-    android_description: String,
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    android_name: Option<String>,
+    #[serde(default)]
+    android_nickname: Option<String>,
+    #[serde(default)]
+    android_icon: Option<String>,
+    #[serde(default)]
+    android_description: Option<String>,
+    #[serde(default)]
+    android_hair: Option<HairInfo>,
+    #[serde(default)]
+    android_face: Option<FaceInfo>,
+    #[serde(default)]
+    android_skin: Option<AndroidSkinInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    android_cash_item_equipment: Vec<CashItemEquipmentInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserAndroidEquipment",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = AndroidEquipment))
+)]
 pub async fn get_user_android_equipment(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<AndroidEquipment>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "android-equipment", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_item_equipment: AndroidEquipment = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_item_equipment))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<AndroidEquipment>, AppError> {
+    fetch_character(
+        api_key,
+        "android-equipment",
+        &user_ocid.ocid,
+        &date.resolve()?,
+    )
+    .await
+    .map(Json)
 }
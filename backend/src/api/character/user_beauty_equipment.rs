@@ -0,0 +1,67 @@
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+
+use super::character::UserOcid;
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct HairInfo {
+    hair_name: String,
+    base_color: String,
+    mix_color: Option<String>, // 믹스 염색이 없는 경우 null
+    mix_rate: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct FaceInfo {
+    face_name: String,
+    base_color: String,
+    mix_color: Option<String>, // 믹스 렌즈가 없는 경우 null
+    mix_rate: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct BeautyEquipment {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    character_hair: Option<HairInfo>,
+    character_face: Option<FaceInfo>,
+    character_skin_name: Option<String>,
+    // 제로, 엔젤릭버스터만 값이 존재
+    #[serde(default)]
+    additional_character_hair: Option<HairInfo>,
+    #[serde(default)]
+    additional_character_face: Option<FaceInfo>,
+    #[serde(default)]
+    additional_character_skin_name: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserBeautyEquipment",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = BeautyEquipment))
+)]
+pub async fn get_user_beauty_equipment(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<BeautyEquipment>, AppError> {
+    fetch_character(
+        api_key,
+        "beauty-equipment",
+        &user_ocid.ocid,
+        &date.resolve()?,
+    )
+    .await
+    .map(Json)
+}
@@ -1,62 +1,107 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Serialize, Debug)]
-pub struct CaseItemOption {
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct CashItemOption {
     option_type: String,
-    option_value: i8,
+    option_value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct CashItemColoringPrism {
+    color_range: String,
+    hue: i32,
+    saturation: i32,
+    value: i32,
 }
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
-pub struct SymbolInfo {
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct CashItemEquipmentInfo {
     cash_item_equipment_part: String,
     cash_item_equipment_slot: String,
     cash_item_name: String,
     cash_item_icon: String,
     #[serde_as(deserialize_as = "DefaultOnNull")]
     cash_item_description: String,
-    cash_item_option: Vec<CaseItemOption>,
+    #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
-    date_expire: String,
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    date_option_expire: String,
+    cash_item_option: Vec<CashItemOption>,
+    date_expire: Option<String>,        // 영구 아이템은 null
+    date_option_expire: Option<String>, // 영구 아이템은 null
     #[serde_as(deserialize_as = "DefaultOnNull")]
     cash_item_label: String,
+    #[serde(default)]
+    cash_item_coloring_prism: Option<CashItemColoringPrism>,
+    #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
-    cash_item_coloring_prism: String,
-    #[serde_as(deserialize_as = "DefaultOnNull")]
-    item_gender: String,
+    item_gender: String, // 안드로이드 캐시 아이템에는 없음
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-pub struct Symbol {
-    cash_item_equipment_base: Vec<SymbolInfo>,
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct CashItemEquipment {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    preset_no: Option<u8>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    cash_item_equipment_base: Vec<CashItemEquipmentInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    cash_item_equipment_preset_1: Vec<CashItemEquipmentInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    cash_item_equipment_preset_2: Vec<CashItemEquipmentInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    cash_item_equipment_preset_3: Vec<CashItemEquipmentInfo>,
+    // 제로, 엔젤릭버스터 등 추가 외형을 가진 직업만 값이 존재
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    additional_cash_item_equipment_base: Vec<CashItemEquipmentInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    additional_cash_item_equipment_preset_1: Vec<CashItemEquipmentInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    additional_cash_item_equipment_preset_2: Vec<CashItemEquipmentInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    additional_cash_item_equipment_preset_3: Vec<CashItemEquipmentInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserCashItemEquipment",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = CashItemEquipment))
+)]
 pub async fn get_user_cash_item_equipment(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<Symbol>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "cashitem-equipment", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_symbol: Symbol = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_symbol))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<CashItemEquipment>, AppError> {
+    fetch_character(
+        api_key,
+        "cashitem-equipment",
+        &user_ocid.ocid,
+        &date.resolve()?,
+    )
+    .await
+    .map(Json)
 }
@@ -1,18 +1,23 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, QueryDate, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, ready_response, send, upstream_error};
 
 use super::character::UserOcid;
 
 use axum::{Extension, http::StatusCode, response::Json};
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-use reqwest::{Client, header};
-use serde::{Deserialize, Serialize};
+use futures::future::join_all;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use serde_with::{DefaultOnNull, serde_as};
+use std::collections::BTreeMap;
+use std::fmt;
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct SkillInfo {
     skill_name: String,
     skill_description: String,
@@ -24,76 +29,345 @@ pub struct SkillInfo {
     skill_effect_next: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct CharacterSkill {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     character_skill: Vec<SkillInfo>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+// 스킬 차수 (0~6차, 하이퍼 패시브/액티브)
+// 숫자 또는 "hyperpassive" / "hyperactive" 문자열로 요청한다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillGrade {
+    Grade(u8),
+    HyperPassive,
+    HyperActive,
+}
+
+impl SkillGrade {
+    const VALID: &'static str = "0, 1, 2, 3, 4, 5, 6, hyperpassive, hyperactive";
+
+    const ALL: [SkillGrade; 9] = [
+        SkillGrade::Grade(0),
+        SkillGrade::Grade(1),
+        SkillGrade::Grade(2),
+        SkillGrade::Grade(3),
+        SkillGrade::Grade(4),
+        SkillGrade::Grade(5),
+        SkillGrade::Grade(6),
+        SkillGrade::HyperPassive,
+        SkillGrade::HyperActive,
+    ];
+
+    fn parse(grade: &str) -> Option<Self> {
+        match grade {
+            "hyperpassive" => Some(SkillGrade::HyperPassive),
+            "hyperactive" => Some(SkillGrade::HyperActive),
+            _ => match grade.parse::<u8>() {
+                Ok(grade @ 0..=6) => Some(SkillGrade::Grade(grade)),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for SkillGrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkillGrade::Grade(grade) => write!(f, "{grade}"),
+            SkillGrade::HyperPassive => f.write_str("hyperpassive"),
+            SkillGrade::HyperActive => f.write_str("hyperactive"),
+        }
+    }
+}
+
+impl Serialize for SkillGrade {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SkillGrade::Grade(grade) => serializer.serialize_u8(*grade),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SkillGrade {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawGrade {
+            Number(i64),
+            Text(String),
+        }
+
+        let raw = match RawGrade::deserialize(deserializer) {
+            Ok(RawGrade::Number(grade)) => grade.to_string(),
+            Ok(RawGrade::Text(grade)) => grade,
+            Err(_) => String::new(),
+        };
+
+        // 지원하지 않는 차수는 넥슨 API로 넘기지 않고 422로 응답
+        SkillGrade::parse(&raw).ok_or_else(|| {
+            de::Error::custom(format!(
+                "unsupported skill grade `{raw}`, expected one of: {}",
+                SkillGrade::VALID
+            ))
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct CharacterSkilLevel {
     user_ocid: UserOcid,
-    level: i8,
+    // 0~6, hyperpassive, hyperactive
+    #[schema(value_type = String, example = "6")]
+    level: SkillGrade,
+}
+
+async fn fetch_character_skill(
+    api_key: Arc<API>,
+    ocid: &str,
+    grade: SkillGrade,
+    date: &QueryDate,
+) -> Result<CharacterSkill, AppError> {
+    if ocid.trim().is_empty() {
+        return Err(AppError::OcidNotFound);
+    }
+
+    let mut response = send_skill_request(&api_key, ocid, grade, date).await?;
+
+    // 기본 날짜의 데이터가 아직 준비되지 않은 경우에만 전날 데이터로 다시 조회
+    if let Some(previous) = date.fallback() {
+        response = match ready_response(response).await? {
+            Some(response) => response,
+            None => send_skill_request(&api_key, ocid, grade, &previous).await?,
+        };
+    }
+
+    // 응답 결과 확인
+    if response.status().is_success() {
+        let user_character_skill: CharacterSkill = parse_json(response).await?;
+
+        Ok(user_character_skill)
+    } else {
+        Err(upstream_error(response).await)
+    }
+}
+
+async fn send_skill_request(
+    api_key: &API,
+    ocid: &str,
+    grade: SkillGrade,
+    date: &str,
+) -> Result<reqwest::Response, AppError> {
+    let url = api_key.url("character/skill");
+    let request = api_key.client.get(url).query(&[
+        ("ocid", ocid.to_string()),
+        ("date", date.to_string()),
+        ("character_skill_grade", grade.to_string()),
+    ]);
+
+    // POST 요청 보내기
+    send(api_key, request).await
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserCharacterSkill",
+    tag = "character",
+    params(DateQuery),
+    request_body = CharacterSkilLevel,
+    responses((status = 200, body = CharacterSkill))
+)]
 pub async fn get_user_characeter_skill(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(character_skil_level): Json<CharacterSkilLevel>,
-) -> Result<Json<CharacterSkill>, (StatusCode, &'static str)> {
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
+) -> Result<Json<CharacterSkill>, AppError> {
+    fetch_character_skill(
+        api_key,
+        &character_skil_level.user_ocid.ocid,
+        character_skil_level.level,
+        &date.resolve()?,
+    )
+    .await
+    .map(Json)
+}
 
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CharacterSkillAll {
+    date: String,
+    // 차수 → 스킬 목록 (해당 차수가 없는 직업은 빈 배열)
+    character_skill: BTreeMap<String, Vec<SkillInfo>>,
+}
 
-    let url = format!(
-        "https://open.api.nexon.com/maplestory/v1/character/skill?ocid={}&date={}&character_skill_grade={}",
-        character_skil_level.user_ocid.ocid, now_time, character_skil_level.level
-    );
+// 모든 차수의 스킬을 동시에 조회
+#[utoipa::path(
+    post,
+    path = "/getUserCharacterSkillAll",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = CharacterSkillAll))
+)]
+pub async fn get_user_character_skill_all(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date_query): Query<DateQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<CharacterSkillAll>, AppError> {
+    let query_date = date_query.resolve()?;
 
-    // POST 요청 보내기
-    let response = Client::new()
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
+    // 지역 / 캐시 컨텍스트(task_local)를 유지하도록 현재 태스크에서 동시에 조회
+    let results = join_all(SkillGrade::ALL.iter().map(|&grade| {
+        let api_key = api_key.clone();
+        let ocid = &user_ocid.ocid;
+        let query_date = &query_date;
+        async move {
+            let skill = fetch_character_skill(api_key, ocid, grade, query_date).await;
+            (grade, skill)
+        }
+    }))
+    .await;
 
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_character_skill: CharacterSkill = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+    let mut date = String::new();
+    let mut fetched = false;
+    let mut character_skill: BTreeMap<String, Vec<SkillInfo>> = SkillGrade::ALL
+        .iter()
+        .map(|grade| (grade.to_string(), Vec::new()))
+        .collect();
 
-        Ok(Json(user_character_skill))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+    for (grade, skill) in results {
+        let Ok(skill) = skill else {
+            continue;
+        };
+
+        fetched = true;
+        date = date.max(skill.date);
+        character_skill.insert(grade.to_string(), skill.character_skill);
+    }
+
+    if !fetched {
+        return Err(AppError::Upstream {
+            status: StatusCode::BAD_GATEWAY,
+            message: "Failed to fetch character skill".to_string(),
+        });
     }
+
+    Ok(Json(CharacterSkillAll {
+        date,
+        character_skill,
+    }))
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct CharacterLinkSkill {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub date: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     pub character_link_skill: Vec<SkillInfo>,
+    // 저레벨 캐릭터는 프리셋이 없으므로 null
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub character_link_skill_preset_1: Vec<SkillInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub character_link_skill_preset_2: Vec<SkillInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub character_link_skill_preset_3: Vec<SkillInfo>,
+    // 이 캐릭터가 제공하는 링크 스킬
+    #[serde(default)]
+    pub character_owned_link_skill: Option<SkillInfo>,
+    #[serde(default)]
+    pub character_owned_link_skill_preset_1: Option<SkillInfo>,
+    #[serde(default)]
+    pub character_owned_link_skill_preset_2: Option<SkillInfo>,
+    #[serde(default)]
+    pub character_owned_link_skill_preset_3: Option<SkillInfo>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CharacterLinkSkillPreset {
+    date: String,
+    preset_no: u8,
+    character_link_skill: Vec<SkillInfo>,
+    character_owned_link_skill: Option<SkillInfo>,
+}
+
+impl CharacterLinkSkill {
+    // 지정한 프리셋만 추출 (1~3)
+    fn into_preset(self, preset_no: u8) -> Option<CharacterLinkSkillPreset> {
+        let (character_link_skill, character_owned_link_skill) = match preset_no {
+            1 => (
+                self.character_link_skill_preset_1,
+                self.character_owned_link_skill_preset_1,
+            ),
+            2 => (
+                self.character_link_skill_preset_2,
+                self.character_owned_link_skill_preset_2,
+            ),
+            3 => (
+                self.character_link_skill_preset_3,
+                self.character_owned_link_skill_preset_3,
+            ),
+            _ => return None,
+        };
+
+        Some(CharacterLinkSkillPreset {
+            date: self.date,
+            preset_no,
+            character_link_skill,
+            character_owned_link_skill,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct LinkSkillQuery {
+    #[serde(default)]
+    preset: Option<u8>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(untagged)]
+pub enum LinkSkillResponse {
+    All(Box<CharacterLinkSkill>),
+    Preset(CharacterLinkSkillPreset),
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserCharacterLinkSkill",
+    tag = "character",
+    params(DateQuery, LinkSkillQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = LinkSkillResponse))
+)]
 pub async fn get_user_characeter_link_skill(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<LinkSkillQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<CharacterLinkSkill>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "link-skill", &user_ocid.ocid).await;
+) -> Result<Json<LinkSkillResponse>, AppError> {
+    if query
+        .preset
+        .is_some_and(|preset| !(1..=3).contains(&preset))
+    {
+        return Err(AppError::Unprocessable("preset must be one of: 1, 2, 3"));
+    }
 
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_character_link_skill: CharacterLinkSkill = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+    let user_character_link_skill: CharacterLinkSkill =
+        fetch_character(api_key, "link-skill", &user_ocid.ocid, &date.resolve()?).await?;
 
-        Ok(Json(user_character_link_skill))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+    match query.preset {
+        Some(preset_no) => user_character_link_skill
+            .into_preset(preset_no)
+            .map(|preset| Json(LinkSkillResponse::Preset(preset)))
+            .ok_or(AppError::Unprocessable("preset must be one of: 1, 2, 3")),
+        None => Ok(Json(LinkSkillResponse::All(Box::new(
+            user_character_link_skill,
+        )))),
     }
 }
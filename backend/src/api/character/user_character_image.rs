@@ -0,0 +1,140 @@
+use crate::api::character::user_default_info::{CharacterImageQuery, character_image_url};
+use crate::api::date::DateQuery;
+use crate::api::error::AppError;
+use crate::api::image_cache::CachedImage;
+use crate::api::metrics::record_cache;
+use crate::api::query::Query;
+use crate::api::request::API;
+use crate::api::upstream::request_error;
+
+use super::character::UserOcid;
+
+use axum::{
+    Extension,
+    body::Bytes,
+    http::{StatusCode, header},
+    response::{IntoResponse, Json, Response},
+};
+use std::sync::Arc;
+
+const IMAGE_ENDPOINT: &str = "character/image";
+// 캐릭터 이미지 최대 크기 (이보다 큰 응답은 이미지가 아닌 것으로 보고 거부)
+const MAX_IMAGE_BYTES: usize = 2 * 1024 * 1024;
+// 같은 날짜의 캐릭터 이미지는 바뀌지 않으므로 브라우저에서 오래 캐시한다
+const IMAGE_CACHE_CONTROL: &str = "public, max-age=86400";
+
+fn invalid_image(reason: String) -> AppError {
+    AppError::Deserialize {
+        endpoint: IMAGE_ENDPOINT.to_string(),
+        reason,
+    }
+}
+
+// 캐릭터 이미지 다운로드 (크기 제한을 넘으면 중간에 중단)
+// character_image 는 넥슨 응답에 담긴 값이므로 넥슨 이미지 주소가 아니면 요청하지 않고,
+// API 키가 다른 곳으로 새지 않도록 키를 붙이지 않는 이미지 전용 클라이언트로 받는다
+async fn fetch_image(api_key: &API, url: reqwest::Url) -> Result<CachedImage, AppError> {
+    if url.origin() != api_key.image_origin.origin() {
+        return Err(invalid_image(
+            "character_image is not a Nexon image URL".to_string(),
+        ));
+    }
+
+    let mut response = api_key
+        .image_client
+        .get(url)
+        .send()
+        .await
+        .map_err(request_error)?;
+
+    if !response.status().is_success() {
+        return Err(AppError::Upstream {
+            status: StatusCode::BAD_GATEWAY,
+            message: format!(
+                "Character image request failed with status {}",
+                response.status().as_u16()
+            ),
+        });
+    }
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|content_type| content_type.starts_with("image/"))
+        .ok_or_else(|| invalid_image("response is not an image".to_string()))?
+        .to_string();
+
+    let too_large = || invalid_image(format!("image is larger than {MAX_IMAGE_BYTES} bytes"));
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_IMAGE_BYTES as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(request_error)? {
+        if body.len() + chunk.len() > MAX_IMAGE_BYTES {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(CachedImage {
+        content_type,
+        body: Bytes::from(body),
+    })
+}
+
+// 캐릭터 이미지 프록시 (넥슨 응답의 Content-Type 을 그대로 전달)
+// 브라우저가 넥슨 이미지 주소를 직접 불러오지 못하는 경우를 위해 서버에서 받아 전달한다
+#[utoipa::path(
+    post,
+    path = "/getCharacterImage",
+    tag = "character",
+    params(DateQuery, CharacterImageQuery),
+    request_body = UserOcid,
+    responses((status = 200, content_type = "image/png", body = Vec<u8>))
+)]
+pub async fn get_character_image(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<CharacterImageQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Response, AppError> {
+    let params = query.params()?;
+    let date = date.resolve()?;
+
+    let params_key = params
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    let key = (user_ocid.ocid.clone(), params_key, date.to_string());
+
+    let image = match api_key.image_cache.get(&key) {
+        Some(image) => {
+            record_cache(IMAGE_ENDPOINT, "hit");
+            image
+        }
+        None => {
+            record_cache(IMAGE_ENDPOINT, "miss");
+
+            let (_, image_url) =
+                character_image_url(api_key.clone(), &user_ocid.ocid, &date, params).await?;
+            let image = fetch_image(&api_key, image_url).await?;
+            api_key.image_cache.insert(key, image.clone());
+            image
+        }
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, image.content_type),
+            (header::CACHE_CONTROL, IMAGE_CACHE_CONTROL.to_string()),
+        ],
+        image.body,
+    )
+        .into_response())
+}
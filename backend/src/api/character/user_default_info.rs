@@ -1,14 +1,37 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::class_info::CLASSES;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, QueryDate, deserialize_date, normalize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
-use axum::{Extension, http::StatusCode, response::Json};
-use serde::{Deserialize, Serialize};
+use axum::{Extension, response::Json};
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Asia::Seoul;
+use once_cell::sync::Lazy;
+use reqwest::Url;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
 use super::character::UserOcid;
 
-#[derive(Serialize, Deserialize, Debug)]
+// 성별이 고정된 직업 (성별 표시가 의미 없음)
+static FIXED_GENDER_CLASSES: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    CLASSES
+        .iter()
+        .filter(|class| class.gender.is_fixed())
+        .map(|class| class.name)
+        .collect()
+});
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UserDefaultData {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     character_name: String,
     world_name: String,
     character_gender: String,
@@ -17,29 +40,496 @@ pub struct UserDefaultData {
     character_level: i16,
     character_exp: i64,
     character_exp_rate: String,
-    character_guild_name: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    character_guild_name: String, // 길드가 없는 경우 null 또는 빈 문자열
     character_image: String,
-    character_date_create: String,
+    // 캐릭터 생성일 ("2023-12-21T00:00+09:00" → "2023-12-21")
+    #[serde(default, deserialize_with = "deserialize_create_date")]
+    character_date_create: Option<NaiveDate>,
+    // 생성일로부터 지난 일수 (한국 시간 기준)
+    #[serde(skip_deserializing)]
+    character_age_days: Option<i64>,
+    // 최근 7일 이내 접속 여부
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    access_flag: Option<bool>,
+    // 해방 퀘스트 완료 여부
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    liberation_quest_clear_flag: Option<bool>,
+    // 해방 퀘스트 완료 단계 (0: 미완료, 1: 제네시스, 2: 데스티니)
+    #[serde(default, deserialize_with = "deserialize_level")]
+    liberation_quest_clear: Option<u8>,
+}
+
+impl UserDefaultData {
+    // 생성일로부터 지난 일수를 채운다
+    pub fn fill_age(self) -> Self {
+        self.fill_age_at(Utc::now().with_timezone(&Seoul).date_naive())
+    }
+
+    fn fill_age_at(mut self, today: NaiveDate) -> Self {
+        self.character_age_days = self
+            .character_date_create
+            .map(|created| (today - created).num_days().max(0));
+
+        self
+    }
+}
+
+// 생성일 역직렬화 (없거나 형식이 다르면 null)
+fn deserialize_create_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let date: Option<String> = Option::deserialize(deserializer)?;
+
+    Ok(date.and_then(|date| NaiveDate::parse_from_str(&normalize_date(&date), "%Y-%m-%d").ok()))
+}
+
+// "true" / "false" 문자열 플래그 역직렬화 (bool 값도 허용, 알 수 없는 값은 null)
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let flag = match Value::deserialize(deserializer)? {
+        Value::Bool(flag) => Some(flag),
+        Value::String(flag) => flag.trim().parse().ok(),
+        _ => None,
+    };
+
+    Ok(flag)
 }
 
+// "1" 처럼 문자열로 오는 단계 값 역직렬화 (숫자도 허용, 알 수 없는 값은 null)
+fn deserialize_level<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let level = match Value::deserialize(deserializer)? {
+        Value::Number(level) => level.as_u64().and_then(|level| u8::try_from(level).ok()),
+        Value::String(level) => level.trim().parse().ok(),
+        _ => None,
+    };
+
+    Ok(level)
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    Male,
+    Female,
+    NotApplicable,
+    Unknown,
+}
+
+impl Gender {
+    fn from_character(gender: &str, class: &str) -> Self {
+        if FIXED_GENDER_CLASSES.contains(&class) {
+            return Gender::NotApplicable;
+        }
+
+        match gender {
+            "남" => Gender::Male,
+            "여" => Gender::Female,
+            _ => Gender::Unknown,
+        }
+    }
+}
+
+// 프론트엔드에서 바로 사용할 수 있도록 정규화한 기본 정보
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NormalizedUserDefaultData {
+    date: String,
+    character_name: String,
+    world_name: String,
+    character_gender: Gender,
+    character_class: String,
+    character_class_level: Option<u8>,
+    character_level: i16,
+    character_exp: i64,
+    character_exp_rate: String,
+    character_guild_name: Option<String>,
+    character_image: String,
+    character_date_create: Option<NaiveDate>,
+    character_age_days: Option<i64>,
+    access_flag: Option<bool>,
+    liberation_quest_clear_flag: Option<bool>,
+    liberation_quest_clear: Option<u8>,
+}
+
+impl From<UserDefaultData> for NormalizedUserDefaultData {
+    fn from(data: UserDefaultData) -> Self {
+        let guild_name = data.character_guild_name.trim();
+
+        Self {
+            character_gender: Gender::from_character(&data.character_gender, &data.character_class),
+            character_class_level: data.character_class_level.trim().parse().ok(),
+            character_guild_name: (!guild_name.is_empty()).then(|| guild_name.to_string()),
+            date: data.date,
+            character_name: data.character_name,
+            world_name: data.world_name,
+            character_class: data.character_class,
+            character_level: data.character_level,
+            character_exp: data.character_exp,
+            character_exp_rate: data.character_exp_rate,
+            character_image: data.character_image,
+            character_date_create: data.character_date_create,
+            character_age_days: data.character_age_days,
+            access_flag: data.access_flag,
+            liberation_quest_clear_flag: data.liberation_quest_clear_flag,
+            liberation_quest_clear: data.liberation_quest_clear,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct UserDefaultQuery {
+    #[serde(default)]
+    normalized: bool,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(untagged)]
+pub enum UserDefaultResponse {
+    Raw(UserDefaultData),
+    Normalized(NormalizedUserDefaultData),
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserInfo",
+    tag = "character",
+    params(DateQuery, UserDefaultQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserDefaultResponse))
+)]
 pub async fn get_user_default_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<UserDefaultQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UserDefaultData>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "basic", &user_ocid.ocid).await;
+) -> Result<Json<UserDefaultResponse>, AppError> {
+    let user_data: UserDefaultData =
+        fetch_character(api_key, "basic", &user_ocid.ocid, &date.resolve()?).await?;
+    let user_data = user_data.fill_age();
+
+    if query.normalized {
+        Ok(Json(UserDefaultResponse::Normalized(user_data.into())))
+    } else {
+        Ok(Json(UserDefaultResponse::Raw(user_data)))
+    }
+}
 
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let mut user_data: UserDefaultData = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+// 캐릭터 이미지 옵션 (넥슨 API 캐릭터 외형 이미지의 쿼리 파라미터)
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct CharacterImageQuery {
+    // 캐릭터 액션 (A00 ~ A41)
+    #[serde(default)]
+    action: Option<String>,
+    // 캐릭터 감정 표현 (E00 ~ E24)
+    #[serde(default)]
+    emotion: Option<String>,
+    // 무기 모션 (W00 ~ W04)
+    #[serde(default)]
+    wmotion: Option<String>,
+    // 이미지 크기 (96 ~ 1000)
+    #[serde(default)]
+    width: Option<u16>,
+    #[serde(default)]
+    height: Option<u16>,
+    // 이미지 안의 캐릭터 좌표 (0 ~ 1000)
+    #[serde(default)]
+    x: Option<u16>,
+    #[serde(default)]
+    y: Option<u16>,
+}
 
-        user_data.character_date_create = user_data.character_date_create[..10].to_string();
+// "A00" 처럼 접두사 + 두 자리 번호 (0 ~ max) 형태인지 확인
+fn is_valid_code(code: &str, prefix: char, max: u8) -> bool {
+    code.strip_prefix(prefix)
+        .filter(|number| number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|number| number.parse::<u8>().ok())
+        .is_some_and(|number| number <= max)
+}
 
-        Ok(Json(user_data))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+impl CharacterImageQuery {
+    // 확인한 옵션을 (이름, 값) 목록으로 반환
+    pub fn params(&self) -> Result<Vec<(&'static str, String)>, AppError> {
+        let mut params = Vec::new();
+
+        if let Some(action) = &self.action {
+            if !is_valid_code(action, 'A', 41) {
+                return Err(AppError::Unprocessable("action must be one of A00 ~ A41"));
+            }
+            params.push(("action", action.clone()));
+        }
+        if let Some(emotion) = &self.emotion {
+            if !is_valid_code(emotion, 'E', 24) {
+                return Err(AppError::Unprocessable("emotion must be one of E00 ~ E24"));
+            }
+            params.push(("emotion", emotion.clone()));
+        }
+        if let Some(wmotion) = &self.wmotion {
+            if !is_valid_code(wmotion, 'W', 4) {
+                return Err(AppError::Unprocessable("wmotion must be one of W00 ~ W04"));
+            }
+            params.push(("wmotion", wmotion.clone()));
+        }
+
+        for (name, size) in [("width", self.width), ("height", self.height)] {
+            if let Some(size) = size {
+                if !(96..=1000).contains(&size) {
+                    return Err(AppError::Unprocessable(
+                        "width and height must be between 96 and 1000",
+                    ));
+                }
+                params.push((name, size.to_string()));
+            }
+        }
+        for (name, position) in [("x", self.x), ("y", self.y)] {
+            if let Some(position) = position {
+                if position > 1000 {
+                    return Err(AppError::Unprocessable(
+                        "x and y must be between 0 and 1000",
+                    ));
+                }
+                params.push((name, position.to_string()));
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CharacterImageUrl {
+    // 기본 정보의 이미지 주소
+    character_image: String,
+    // 옵션을 적용한 이미지 주소
+    image_url: String,
+}
+
+// 기본 정보의 캐릭터 이미지 주소와 이미지 옵션을 붙인 주소
+// 기본 정보는 응답 캐시를 거치므로 같은 캐릭터는 넥슨 API를 다시 호출하지 않는다
+pub async fn character_image_url(
+    api_key: Arc<API>,
+    ocid: &str,
+    date: &QueryDate,
+    params: Vec<(&'static str, String)>,
+) -> Result<(String, Url), AppError> {
+    let user_data: UserDefaultData = fetch_character(api_key, "basic", ocid, date).await?;
+
+    let mut image_url =
+        Url::parse(&user_data.character_image).map_err(|error| AppError::Deserialize {
+            endpoint: "basic".to_string(),
+            reason: format!("invalid character_image: {error}"),
+        })?;
+    if !params.is_empty() {
+        image_url.query_pairs_mut().extend_pairs(params);
+    }
+
+    Ok((user_data.character_image, image_url))
+}
+
+#[utoipa::path(
+    post,
+    path = "/getCharacterImageUrl",
+    tag = "character",
+    params(DateQuery, CharacterImageQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = CharacterImageUrl))
+)]
+pub async fn get_character_image_url(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<CharacterImageQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<CharacterImageUrl>, AppError> {
+    let params = query.params()?;
+    let (character_image, image_url) =
+        character_image_url(api_key, &user_ocid.ocid, &date.resolve()?, params).await?;
+
+    Ok(Json(CharacterImageUrl {
+        character_image,
+        image_url: image_url.to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image_query(query: &str) -> CharacterImageQuery {
+        let uri = format!("/getCharacterImageUrl?{query}").parse().unwrap();
+        axum::extract::Query::try_from_uri(&uri).unwrap().0
+    }
+
+    #[test]
+    fn image_codes_are_prefix_and_two_digits() {
+        assert!(is_valid_code("A00", 'A', 41));
+        assert!(is_valid_code("A41", 'A', 41));
+        assert!(!is_valid_code("A42", 'A', 41));
+        assert!(!is_valid_code("A1", 'A', 41));
+        assert!(!is_valid_code("a01", 'A', 41));
+        assert!(!is_valid_code("E01", 'A', 41));
+        assert!(!is_valid_code("A+1", 'A', 41));
+    }
+
+    #[test]
+    fn image_params_keep_validated_options_in_order() {
+        let params = image_query("width=300&emotion=E10&action=A00&x=150&wmotion=W04")
+            .params()
+            .unwrap();
+
+        assert_eq!(
+            params,
+            [
+                ("action", "A00".to_string()),
+                ("emotion", "E10".to_string()),
+                ("wmotion", "W04".to_string()),
+                ("width", "300".to_string()),
+                ("x", "150".to_string()),
+            ]
+        );
+        assert!(image_query("").params().unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_image_params_list_allowed_values() {
+        for (query, message) in [
+            ("action=A99", "action must be one of A00 ~ A41"),
+            ("emotion=smile", "emotion must be one of E00 ~ E24"),
+            ("wmotion=W05", "wmotion must be one of W00 ~ W04"),
+            ("height=95", "width and height must be between 96 and 1000"),
+            ("width=1001", "width and height must be between 96 and 1000"),
+            ("y=1001", "x and y must be between 0 and 1000"),
+        ] {
+            assert!(
+                matches!(
+                    image_query(query).params(),
+                    Err(AppError::Unprocessable(error)) if error == message
+                ),
+                "{query}"
+            );
+        }
+    }
+
+    fn basic(overrides: Value) -> UserDefaultData {
+        let mut basic: Value =
+            serde_json::from_str(include_str!("../../../tests/fixtures/character_basic.json"))
+                .unwrap();
+        for (key, value) in overrides.as_object().unwrap() {
+            match value {
+                Value::Null => basic.as_object_mut().unwrap().remove(key),
+                value => basic
+                    .as_object_mut()
+                    .unwrap()
+                    .insert(key.clone(), value.clone()),
+            };
+        }
+
+        serde_json::from_value(basic).unwrap()
+    }
+
+    #[test]
+    fn string_flags_become_booleans() {
+        let data = basic(serde_json::json!({}));
+
+        assert_eq!(data.access_flag, Some(true));
+        assert_eq!(data.liberation_quest_clear_flag, Some(false));
+        assert_eq!(data.liberation_quest_clear, Some(0));
+    }
+
+    #[test]
+    fn flags_accept_booleans_numbers_and_unknown_values() {
+        let data = basic(serde_json::json!({
+            "access_flag": false,
+            "liberation_quest_clear_flag": "yes",
+            "liberation_quest_clear": 2,
+        }));
+
+        assert_eq!(data.access_flag, Some(false));
+        assert_eq!(data.liberation_quest_clear_flag, None);
+        assert_eq!(data.liberation_quest_clear, Some(2));
+    }
+
+    #[test]
+    fn old_snapshots_without_new_fields_deserialize() {
+        let data = basic(serde_json::json!({
+            "access_flag": null,
+            "liberation_quest_clear_flag": null,
+            "liberation_quest_clear": null,
+            "character_date_create": null,
+        }));
+
+        assert_eq!(data.access_flag, None);
+        assert_eq!(data.liberation_quest_clear_flag, None);
+        assert_eq!(data.liberation_quest_clear, None);
+        assert_eq!(data.character_date_create, None);
+        assert_eq!(data.fill_age().character_age_days, None);
+    }
+
+    #[test]
+    fn age_is_counted_from_the_create_date() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let data = basic(serde_json::json!({})).fill_age_at(today);
+
+        assert_eq!(
+            data.character_date_create,
+            NaiveDate::from_ymd_opt(2020, 1, 1)
+        );
+        assert_eq!(data.character_age_days, Some(1613));
+
+        // 생성일이 조회 날짜보다 늦은 경우 (시간대 차이) 음수가 되지 않는다
+        let data = basic(serde_json::json!({}))
+            .fill_age_at(NaiveDate::from_ymd_opt(2019, 12, 31).unwrap());
+        assert_eq!(data.character_age_days, Some(0));
+    }
+
+    #[test]
+    fn gender_is_not_applicable_for_fixed_gender_classes() {
+        assert_eq!(Gender::from_character("남", "히어로"), Gender::Male);
+        assert_eq!(Gender::from_character("여", "히어로"), Gender::Female);
+        assert_eq!(
+            Gender::from_character("여", "엔젤릭버스터"),
+            Gender::NotApplicable
+        );
+        assert_eq!(Gender::from_character("남", "제로"), Gender::NotApplicable);
+        assert_eq!(
+            Gender::from_character("남", "카이저"),
+            Gender::NotApplicable
+        );
+        assert_eq!(
+            Gender::from_character("여", "카데나"),
+            Gender::NotApplicable
+        );
+        assert_eq!(Gender::from_character("", "히어로"), Gender::Unknown);
+    }
+
+    #[test]
+    fn normalized_view_parses_class_level_and_guild() {
+        let normalized = NormalizedUserDefaultData::from(basic(serde_json::json!({})));
+
+        assert_eq!(normalized.character_gender, Gender::Male);
+        assert_eq!(normalized.character_class_level, Some(6));
+        assert_eq!(normalized.character_guild_name, None);
+
+        let normalized = NormalizedUserDefaultData::from(basic(serde_json::json!({
+            "character_guild_name": " 길드 ",
+            "character_class_level": "",
+        })));
+        assert_eq!(normalized.character_guild_name.as_deref(), Some("길드"));
+        assert_eq!(normalized.character_class_level, None);
+    }
+
+    #[test]
+    fn normalized_gender_serializes_in_snake_case() {
+        let normalized = NormalizedUserDefaultData::from(basic(serde_json::json!({
+            "character_class": "제로",
+        })));
+        let value = serde_json::to_value(normalized).unwrap();
+
+        assert_eq!(value["character_gender"], "not_applicable");
+        assert_eq!(value["character_guild_name"], Value::Null);
     }
 }
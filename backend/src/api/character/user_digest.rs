@@ -0,0 +1,282 @@
+use crate::api::canonical::content_hash;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, QueryDate, normalize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+
+use super::character::UserOcid;
+use super::user_ability::Ability;
+use super::user_cashitem_equipment::CashItemEquipment;
+use super::user_characeter_skill::CharacterLinkSkill;
+use super::user_default_info::{NormalizedUserDefaultData, UserDefaultData};
+use super::user_dojang::Dojang;
+use super::user_hexa_matrix::HexaMatrix;
+use super::user_hexa_matrix_stat::UserHexaStatData;
+use super::user_hyper_stat_info::UserHyperStatData;
+use super::user_item_equipment::ItemEquipment;
+use super::user_propensity::Propensity;
+use super::user_set_effect::SetEffect;
+use super::user_stat_info::{NormalizedUserStatData, UserStatData};
+use super::user_symbol_equipment::Symbol;
+use super::user_v_matrix::{GroupedVMatrix, VMatrix};
+
+use axum::{Extension, http::StatusCode, response::Json};
+use futures::future::join_all;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+// 다이제스트에 포함되는 섹션 (Nexon character API 경로)
+const SECTIONS: [&str; 14] = [
+    "basic",
+    "stat",
+    "hyper-stat",
+    "propensity",
+    "ability",
+    "item-equipment",
+    "cashitem-equipment",
+    "symbol-equipment",
+    "set-effect",
+    "link-skill",
+    "vmatrix",
+    "hexamatrix",
+    "hexamatrix-stat",
+    "dojang",
+];
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UserDigest {
+    date: String,
+    // 섹션 이름 → 내용 해시 (조회 실패 시 null)
+    sections: BTreeMap<String, Option<String>>,
+}
+
+// 응답 구조체 T 로 받아 정규화한 형태 N 의 JSON
+// (null → 빈 목록 등 정규화 결과가 같으면 원본 표현이 달라도 같은 해시)
+fn normalize<T, N>(section: T) -> Result<Value, serde_json::Error>
+where
+    N: From<T> + Serialize,
+{
+    serde_json::to_value(N::from(section))
+}
+
+async fn fetch_normalized<T, N>(
+    api_key: Arc<API>,
+    kind: &str,
+    ocid: &str,
+    date: &QueryDate,
+) -> Result<Value, AppError>
+where
+    T: DeserializeOwned,
+    N: From<T> + Serialize,
+{
+    let section: T = fetch_character(api_key, kind, ocid, date).await?;
+
+    normalize::<T, N>(section).map_err(|error| AppError::Deserialize {
+        endpoint: format!("character/{kind}"),
+        reason: error.to_string(),
+    })
+}
+
+// 각 섹션 조회 API 와 같은 구조체로 정규화한 섹션 내용
+// 캐릭터 생성 후 경과일처럼 조회 시점에 따라 바뀌는 값은 포함하지 않는다
+async fn fetch_section(
+    api_key: Arc<API>,
+    kind: &str,
+    ocid: &str,
+    date: &QueryDate,
+) -> Result<Value, AppError> {
+    match kind {
+        "basic" => {
+            fetch_normalized::<UserDefaultData, NormalizedUserDefaultData>(
+                api_key, kind, ocid, date,
+            )
+            .await
+        }
+        "stat" => {
+            fetch_normalized::<UserStatData, NormalizedUserStatData>(api_key, kind, ocid, date)
+                .await
+        }
+        "hyper-stat" => {
+            fetch_normalized::<UserHyperStatData, UserHyperStatData>(api_key, kind, ocid, date)
+                .await
+        }
+        "propensity" => fetch_normalized::<Propensity, Propensity>(api_key, kind, ocid, date).await,
+        "ability" => fetch_normalized::<Ability, Ability>(api_key, kind, ocid, date).await,
+        "item-equipment" => {
+            fetch_normalized::<ItemEquipment, ItemEquipment>(api_key, kind, ocid, date).await
+        }
+        "cashitem-equipment" => {
+            fetch_normalized::<CashItemEquipment, CashItemEquipment>(api_key, kind, ocid, date)
+                .await
+        }
+        "symbol-equipment" => fetch_normalized::<Symbol, Symbol>(api_key, kind, ocid, date).await,
+        "set-effect" => fetch_normalized::<SetEffect, SetEffect>(api_key, kind, ocid, date).await,
+        "link-skill" => {
+            fetch_normalized::<CharacterLinkSkill, CharacterLinkSkill>(api_key, kind, ocid, date)
+                .await
+        }
+        "vmatrix" => fetch_normalized::<VMatrix, GroupedVMatrix>(api_key, kind, ocid, date).await,
+        "hexamatrix" => fetch_normalized::<HexaMatrix, HexaMatrix>(api_key, kind, ocid, date).await,
+        "hexamatrix-stat" => {
+            fetch_normalized::<UserHexaStatData, UserHexaStatData>(api_key, kind, ocid, date).await
+        }
+        "dojang" => fetch_normalized::<Dojang, Dojang>(api_key, kind, ocid, date).await,
+        _ => fetch_character(api_key, kind, ocid, date).await,
+    }
+}
+
+// 섹션 내용 해시와 섹션 날짜
+// 날짜가 바뀌어도 내용이 같으면 같은 해시가 나오도록 date는 제외
+fn section_hash(mut section: Value) -> (String, Option<String>) {
+    let date = section
+        .as_object_mut()
+        .and_then(|object| object.remove("date"))
+        .and_then(|value| value.as_str().map(normalize_date));
+
+    (content_hash(&section), date)
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserDigest",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserDigest))
+)]
+pub async fn get_user_digest(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date_query): Query<DateQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<UserDigest>, AppError> {
+    let query_date = date_query.resolve()?;
+
+    // 지역 / 캐시 컨텍스트(task_local)를 유지하도록 별도 태스크로 spawn 하지 않고 현재 태스크에서 동시에 조회
+    let results = join_all(SECTIONS.iter().map(|&kind| {
+        let api_key = api_key.clone();
+        let ocid = &user_ocid.ocid;
+        let query_date = &query_date;
+        async move {
+            let section = fetch_section(api_key, kind, ocid, query_date).await;
+            (kind, section.ok())
+        }
+    }))
+    .await;
+
+    let mut date = String::new();
+    let mut sections: BTreeMap<String, Option<String>> = SECTIONS
+        .iter()
+        .map(|kind| (kind.to_string(), None))
+        .collect();
+
+    for (kind, section) in results {
+        let hash = section.map(|section| {
+            let (hash, section_date) = section_hash(section);
+            if let Some(section_date) = section_date {
+                date = date.clone().max(section_date);
+            }
+            hash
+        });
+
+        sections.insert(kind.to_string(), hash);
+    }
+
+    if sections.values().all(Option::is_none) {
+        return Err(AppError::Upstream {
+            status: StatusCode::BAD_GATEWAY,
+            message: "Failed to fetch every digest section".to_string(),
+        });
+    }
+
+    Ok(Json(UserDigest { date, sections }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn section_hash_ignores_date_and_key_order() {
+        let (hash, date) = section_hash(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "character_name": "데모",
+            "character_level": 280
+        }));
+        let (next_day, _) = section_hash(json!({
+            "character_level": 280,
+            "character_name": "데모",
+            "date": "2024-06-02T00:00+09:00"
+        }));
+        let (level_up, _) = section_hash(json!({
+            "date": "2024-06-02T00:00+09:00",
+            "character_name": "데모",
+            "character_level": 281
+        }));
+
+        assert_eq!(date.as_deref(), Some("2024-06-01"));
+        assert_eq!(hash, next_day);
+        assert_ne!(hash, level_up);
+    }
+
+    fn link_skill_hash(section: Value) -> String {
+        let section: CharacterLinkSkill = serde_json::from_value(section).unwrap();
+        let section = normalize::<CharacterLinkSkill, CharacterLinkSkill>(section).unwrap();
+
+        section_hash(section).0
+    }
+
+    #[test]
+    fn section_hash_uses_normalized_section() {
+        // 저레벨 캐릭터의 프리셋은 null 또는 빈 목록으로 내려온다
+        let null_preset = link_skill_hash(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "character_link_skill": [],
+            "character_link_skill_preset_1": null
+        }));
+        let empty_preset = link_skill_hash(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "character_link_skill": [],
+            "character_link_skill_preset_1": []
+        }));
+        // 응답 구조체에 없는 필드는 해시에 영향을 주지 않는다
+        let new_field = link_skill_hash(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "character_link_skill": [],
+            "unknown_field": 1
+        }));
+
+        assert_eq!(null_preset, empty_preset);
+        assert_eq!(null_preset, new_field);
+    }
+
+    #[test]
+    fn basic_section_is_the_normalized_view() {
+        let basic: UserDefaultData = serde_json::from_value(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "character_name": "데모",
+            "world_name": "스카니아",
+            "character_gender": "남",
+            "character_class": "카이저",
+            "character_class_level": "6",
+            "character_level": 280,
+            "character_exp": 0,
+            "character_exp_rate": "0.000",
+            "character_guild_name": null,
+            "character_image": "https://open.api.nexon.com/static/maplestory/character/look/abc",
+            "character_date_create": "2020-01-01T00:00+09:00"
+        }))
+        .unwrap();
+        let section = normalize::<UserDefaultData, NormalizedUserDefaultData>(basic).unwrap();
+
+        assert_eq!(section["character_gender"], "not_applicable");
+        assert_eq!(section["character_class_level"], 6);
+        // 조회한 날에 따라 바뀌는 경과일은 해시 대상이 아니다
+        assert_eq!(section["character_age_days"], Value::Null);
+    }
+}
@@ -1,38 +1,154 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date, parse_datetime, weekly_reset};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::{Asia::Seoul, Tz};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct Dojang {
-    dojang_best_floor: i8,
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    dojang_best_floor: i16,
     #[serde_as(deserialize_as = "DefaultOnNull")]
     date_dojang_record: String,
     dojang_best_time: i32,
+    // 최고 기록 시간 ("mm:ss")
+    #[serde(skip_deserializing)]
+    dojang_best_time_formatted: String,
+    // 기록 달성 일시 (기록이 없으면 null)
+    #[serde(skip_deserializing)]
+    dojang_record_at: Option<DateTime<FixedOffset>>,
+    // 이번 주 (목요일 00시 초기화 이후) 에 달성한 기록인지
+    #[serde(skip_deserializing)]
+    is_current_week: bool,
 }
 
+impl Dojang {
+    // 기록 시간 / 일시를 화면에 표시하기 쉬운 형태로 채운다
+    pub fn enrich(self) -> Self {
+        self.enrich_at(Utc::now().with_timezone(&Seoul))
+    }
+
+    // now 기준으로 이번 주 기록인지 판단한다
+    fn enrich_at(mut self, now: DateTime<Tz>) -> Self {
+        let seconds = self.dojang_best_time.max(0);
+        self.dojang_best_time_formatted = format!("{:02}:{:02}", seconds / 60, seconds % 60);
+
+        self.dojang_record_at = parse_datetime(&self.date_dojang_record);
+        self.is_current_week = self
+            .dojang_record_at
+            .is_some_and(|record_at| record_at >= weekly_reset(now).fixed_offset());
+
+        self
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserDojang",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = Dojang))
+)]
 pub async fn get_user_dojang(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<Dojang>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "dojang", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_dojang: Dojang = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_dojang))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<Json<Dojang>, AppError> {
+    fetch_character(api_key, "dojang", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(|dojang: Dojang| Json(dojang.enrich()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn dojang(best_time: i32, record: Option<&str>) -> Dojang {
+        serde_json::from_value(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "dojang_best_floor": 120,
+            "date_dojang_record": record,
+            "dojang_best_time": best_time,
+        }))
+        .unwrap()
+    }
+
+    // 2024-06-06 은 목요일
+    fn seoul(day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+        Seoul
+            .with_ymd_and_hms(2024, 6, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn formats_best_time_as_minutes_and_seconds() {
+        let now = seoul(7, 12, 0);
+
+        assert_eq!(
+            dojang(754, None).enrich_at(now).dojang_best_time_formatted,
+            "12:34"
+        );
+        assert_eq!(
+            dojang(5, None).enrich_at(now).dojang_best_time_formatted,
+            "00:05"
+        );
+        assert_eq!(
+            dojang(-1, None).enrich_at(now).dojang_best_time_formatted,
+            "00:00"
+        );
+    }
+
+    #[test]
+    fn record_at_reset_boundary_belongs_to_the_new_week() {
+        let now = seoul(8, 12, 0);
+
+        let at_reset = dojang(600, Some("2024-06-06T00:00+09:00")).enrich_at(now);
+        assert!(at_reset.is_current_week);
+        assert_eq!(
+            at_reset.dojang_record_at,
+            Some(seoul(6, 0, 0).fixed_offset())
+        );
+
+        let before_reset = dojang(600, Some("2024-06-05T23:59+09:00")).enrich_at(now);
+        assert!(!before_reset.is_current_week);
+    }
+
+    #[test]
+    fn record_before_thursday_is_current_until_the_next_reset() {
+        let record = "2024-06-03T20:00+09:00";
+
+        assert!(
+            dojang(600, Some(record))
+                .enrich_at(seoul(5, 23, 59))
+                .is_current_week
+        );
+        assert!(
+            !dojang(600, Some(record))
+                .enrich_at(seoul(6, 0, 0))
+                .is_current_week
+        );
+    }
+
+    #[test]
+    fn missing_record_is_never_current() {
+        let dojang = dojang(0, None).enrich_at(seoul(7, 12, 0));
+
+        assert_eq!(dojang.dojang_record_at, None);
+        assert!(!dojang.is_current_week);
     }
 }
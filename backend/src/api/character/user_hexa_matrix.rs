@@ -1,18 +1,23 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::hexa_cost::{cost_table, fragments_spent, fragments_to_max};
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct HexaSkillInfo {
     hexa_skill_id: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct HexaMatrixInfo {
     hexa_core_name: String,
     hexa_core_level: i8,
@@ -20,27 +25,177 @@ pub struct HexaMatrixInfo {
     linked_skill: Vec<HexaSkillInfo>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct HexaMatrix {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     character_hexa_core_equipment: Vec<HexaMatrixInfo>,
+    // 솔 에르다 조각 기준 진행도 (?raw=true 인 경우 제외)
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    progress: Option<HexaProgress>,
 }
 
+// 코어 종류별 솔 에르다 조각 사용량
+// 소모량 표가 없는 종류는 조각 수가 null 이며 전체 합계에서 제외된다
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HexaTypeProgress {
+    hexa_core_type: String,
+    core_count: u32,
+    fragments_spent: Option<u32>,
+    fragments_to_max: Option<u32>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HexaProgress {
+    by_type: Vec<HexaTypeProgress>,
+    fragments_spent: u32,
+    fragments_to_max: u32,
+    // 사용한 조각 / 전체 필요 조각 (%)
+    completion_percent: f64,
+}
+
+impl HexaProgress {
+    fn from_cores(cores: &[HexaMatrixInfo]) -> Self {
+        let mut by_type: Vec<HexaTypeProgress> = Vec::new();
+
+        for core in cores {
+            let index = match by_type
+                .iter()
+                .position(|progress| progress.hexa_core_type == core.hexa_core_type)
+            {
+                Some(index) => index,
+                None => {
+                    let known = cost_table(&core.hexa_core_type).is_some();
+                    by_type.push(HexaTypeProgress {
+                        hexa_core_type: core.hexa_core_type.clone(),
+                        core_count: 0,
+                        fragments_spent: known.then_some(0),
+                        fragments_to_max: known.then_some(0),
+                    });
+                    by_type.len() - 1
+                }
+            };
+
+            let progress = &mut by_type[index];
+            progress.core_count += 1;
+
+            if let Some(table) = cost_table(&core.hexa_core_type) {
+                let level = core.hexa_core_level;
+                progress.fragments_spent = progress
+                    .fragments_spent
+                    .map(|spent| spent + fragments_spent(table, level));
+                progress.fragments_to_max = progress
+                    .fragments_to_max
+                    .map(|to_max| to_max + fragments_to_max(table, level));
+            }
+        }
+
+        let fragments_spent: u32 = by_type.iter().filter_map(|p| p.fragments_spent).sum();
+        let fragments_to_max: u32 = by_type.iter().filter_map(|p| p.fragments_to_max).sum();
+        let total = fragments_spent + fragments_to_max;
+        let completion_percent = if total == 0 {
+            0.0
+        } else {
+            (f64::from(fragments_spent) / f64::from(total) * 1000.0).round() / 10.0
+        };
+
+        HexaProgress {
+            by_type,
+            fragments_spent,
+            fragments_to_max,
+            completion_percent,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct HexaMatrixQuery {
+    // true 이면 넥슨 API 응답을 그대로 반환
+    #[serde(default)]
+    raw: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserHexaMatrix",
+    tag = "character",
+    params(DateQuery, HexaMatrixQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = HexaMatrix))
+)]
 pub async fn get_user_hexa_matrix(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<HexaMatrixQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<HexaMatrix>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "hexamatrix", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_hexa_matrix: HexaMatrix = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_hexa_matrix))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<Json<HexaMatrix>, AppError> {
+    let mut hexa_matrix: HexaMatrix =
+        fetch_character(api_key, "hexamatrix", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if !query.raw {
+        hexa_matrix.progress = Some(HexaProgress::from_cores(
+            &hexa_matrix.character_hexa_core_equipment,
+        ));
+    }
+
+    Ok(Json(hexa_matrix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn core(hexa_core_type: &str, hexa_core_level: i8) -> HexaMatrixInfo {
+        HexaMatrixInfo {
+            hexa_core_name: String::new(),
+            hexa_core_level,
+            hexa_core_type: hexa_core_type.to_string(),
+            linked_skill: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn progress_is_summed_per_core_type() {
+        let progress = HexaProgress::from_cores(&[
+            core("스킬 코어", 10),
+            core("마스터리 코어", 30),
+            core("마스터리 코어", 0),
+        ]);
+
+        let by_type: Vec<_> = progress
+            .by_type
+            .iter()
+            .map(|p| (p.hexa_core_type.as_str(), p.core_count, p.fragments_spent))
+            .collect();
+        assert_eq!(
+            by_type,
+            [
+                ("스킬 코어", 1, Some(680)),
+                ("마스터리 코어", 2, Some(2252))
+            ]
+        );
+        assert_eq!(progress.fragments_spent, 2932);
+        assert_eq!(progress.fragments_to_max, 3820 + 2252);
+        assert_eq!(progress.completion_percent, 32.6);
+    }
+
+    #[test]
+    fn unknown_core_types_pass_through_without_fragments() {
+        let progress = HexaProgress::from_cores(&[core("스킬 코어", 30), core("신규 코어", 5)]);
+
+        assert_eq!(progress.by_type[1].hexa_core_type, "신규 코어");
+        assert_eq!(progress.by_type[1].core_count, 1);
+        assert_eq!(progress.by_type[1].fragments_spent, None);
+        assert_eq!(progress.by_type[1].fragments_to_max, None);
+        assert_eq!(progress.fragments_spent, 4500);
+        assert_eq!(progress.completion_percent, 100.0);
+    }
+
+    #[test]
+    fn empty_matrix_has_zero_completion() {
+        let progress = HexaProgress::from_cores(&[]);
+
+        assert!(progress.by_type.is_empty());
+        assert_eq!(progress.completion_percent, 0.0);
     }
 }
@@ -1,13 +1,17 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct CharacterHexaStatCore {
     slot_id: String,
     main_stat_name: Option<String>,
@@ -19,8 +23,10 @@ pub struct CharacterHexaStatCore {
     stat_grade: i8,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct UserHexaStatData {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     character_hexa_stat_core: Vec<CharacterHexaStatCore>,
     character_hexa_stat_core_2: Vec<CharacterHexaStatCore>,
     character_hexa_stat_core_3: Vec<CharacterHexaStatCore>,
@@ -29,84 +35,90 @@ pub struct UserHexaStatData {
     preset_hexa_stat_core_3: Vec<CharacterHexaStatCore>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserHexStatInfo",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserHexaStatData))
+)]
 pub async fn get_user_hexa_stat_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UserHexaStatData>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "hexamatrix-stat", &user_ocid.ocid).await;
+) -> Result<Json<UserHexaStatData>, AppError> {
+    let user_hexa_stat_data: UserHexaStatData = fetch_character(
+        api_key,
+        "hexamatrix-stat",
+        &user_ocid.ocid,
+        &date.resolve()?,
+    )
+    .await?;
 
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_hexa_stat_data: UserHexaStatData = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+    let filtered_data = UserHexaStatData {
+        date: user_hexa_stat_data.date,
 
-        let filtered_data = UserHexaStatData {
-            character_hexa_stat_core: user_hexa_stat_data
-                .character_hexa_stat_core
-                .into_iter()
-                .filter(|stat| {
-                    stat.main_stat_name.is_some()
-                        && stat.sub_stat_name_1.is_some()
-                        && stat.sub_stat_name_2.is_some()
-                })
-                .collect(),
+        character_hexa_stat_core: user_hexa_stat_data
+            .character_hexa_stat_core
+            .into_iter()
+            .filter(|stat| {
+                stat.main_stat_name.is_some()
+                    && stat.sub_stat_name_1.is_some()
+                    && stat.sub_stat_name_2.is_some()
+            })
+            .collect(),
 
-            character_hexa_stat_core_2: user_hexa_stat_data
-                .character_hexa_stat_core_2
-                .into_iter()
-                .filter(|stat| {
-                    stat.main_stat_name.is_some()
-                        && stat.sub_stat_name_1.is_some()
-                        && stat.sub_stat_name_2.is_some()
-                })
-                .collect(),
+        character_hexa_stat_core_2: user_hexa_stat_data
+            .character_hexa_stat_core_2
+            .into_iter()
+            .filter(|stat| {
+                stat.main_stat_name.is_some()
+                    && stat.sub_stat_name_1.is_some()
+                    && stat.sub_stat_name_2.is_some()
+            })
+            .collect(),
 
-            character_hexa_stat_core_3: user_hexa_stat_data
-                .character_hexa_stat_core_3
-                .into_iter()
-                .filter(|stat| {
-                    stat.main_stat_name.is_some()
-                        && stat.sub_stat_name_1.is_some()
-                        && stat.sub_stat_name_2.is_some()
-                })
-                .collect(),
+        character_hexa_stat_core_3: user_hexa_stat_data
+            .character_hexa_stat_core_3
+            .into_iter()
+            .filter(|stat| {
+                stat.main_stat_name.is_some()
+                    && stat.sub_stat_name_1.is_some()
+                    && stat.sub_stat_name_2.is_some()
+            })
+            .collect(),
 
-            preset_hexa_stat_core: user_hexa_stat_data
-                .preset_hexa_stat_core
-                .into_iter()
-                .filter(|stat| {
-                    stat.main_stat_name.is_some()
-                        && stat.sub_stat_name_1.is_some()
-                        && stat.sub_stat_name_2.is_some()
-                })
-                .collect(),
+        preset_hexa_stat_core: user_hexa_stat_data
+            .preset_hexa_stat_core
+            .into_iter()
+            .filter(|stat| {
+                stat.main_stat_name.is_some()
+                    && stat.sub_stat_name_1.is_some()
+                    && stat.sub_stat_name_2.is_some()
+            })
+            .collect(),
 
-            preset_hexa_stat_core_2: user_hexa_stat_data
-                .preset_hexa_stat_core_2
-                .into_iter()
-                .filter(|stat| {
-                    stat.main_stat_name.is_some()
-                        && stat.sub_stat_name_1.is_some()
-                        && stat.sub_stat_name_2.is_some()
-                })
-                .collect(),
+        preset_hexa_stat_core_2: user_hexa_stat_data
+            .preset_hexa_stat_core_2
+            .into_iter()
+            .filter(|stat| {
+                stat.main_stat_name.is_some()
+                    && stat.sub_stat_name_1.is_some()
+                    && stat.sub_stat_name_2.is_some()
+            })
+            .collect(),
 
-            preset_hexa_stat_core_3: user_hexa_stat_data
-                .preset_hexa_stat_core_3
-                .into_iter()
-                .filter(|stat| {
-                    stat.main_stat_name.is_some()
-                        && stat.sub_stat_name_1.is_some()
-                        && stat.sub_stat_name_2.is_some()
-                })
-                .collect(),
-        };
+        preset_hexa_stat_core_3: user_hexa_stat_data
+            .preset_hexa_stat_core_3
+            .into_iter()
+            .filter(|stat| {
+                stat.main_stat_name.is_some()
+                    && stat.sub_stat_name_1.is_some()
+                    && stat.sub_stat_name_2.is_some()
+            })
+            .collect(),
+    };
 
-        Ok(Json(filtered_data))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+    Ok(Json(filtered_data))
 }
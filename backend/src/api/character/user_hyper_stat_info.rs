@@ -1,13 +1,17 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::{FilterQuery, fetch_character};
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct HyperStat {
     stat_type: String,
     stat_point: Option<u32>, // null을 허용하기 위해 Option 사용
@@ -15,55 +19,176 @@ pub struct HyperStat {
     stat_increase: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct UserHyperStatData {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     hyper_stat_preset_1: Vec<HyperStat>,
     hyper_stat_preset_1_remain_point: i32,
     hyper_stat_preset_2: Vec<HyperStat>,
     hyper_stat_preset_2_remain_point: i32,
     hyper_stat_preset_3: Vec<HyperStat>,
     hyper_stat_preset_3_remain_point: i32,
+    // 현재 적용 중인 프리셋 번호
+    #[serde(default)]
+    use_preset_no: Option<String>,
+    // 사용 가능한 최대 하이퍼 스탯 포인트
+    #[serde(default)]
+    use_available_hyper_stat: Option<i64>,
 }
 
+// 포인트를 투자하지 않은 하이퍼 스탯 제외
+pub fn filter_active_hyper_stat(data: UserHyperStatData) -> UserHyperStatData {
+    let filter = |stats: Vec<HyperStat>| -> Vec<HyperStat> {
+        stats
+            .into_iter()
+            .filter(|stat| stat.stat_point.is_some() && stat.stat_increase.is_some())
+            .collect()
+    };
+
+    UserHyperStatData {
+        date: data.date,
+
+        hyper_stat_preset_1: filter(data.hyper_stat_preset_1),
+        hyper_stat_preset_1_remain_point: data.hyper_stat_preset_1_remain_point,
+
+        hyper_stat_preset_2: filter(data.hyper_stat_preset_2),
+        hyper_stat_preset_2_remain_point: data.hyper_stat_preset_2_remain_point,
+
+        hyper_stat_preset_3: filter(data.hyper_stat_preset_3),
+        hyper_stat_preset_3_remain_point: data.hyper_stat_preset_3_remain_point,
+
+        use_preset_no: data.use_preset_no,
+        use_available_hyper_stat: data.use_available_hyper_stat,
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct HyperStatQuery {
+    // true 이면 포인트를 투자하지 않은 스탯도 그대로 반환
+    #[serde(default)]
+    include_empty: bool,
+}
+
+// v1: 투자한 스탯만 반환 (?include_empty=true 인 경우 전체 반환)
+#[utoipa::path(
+    post,
+    path = "/getUserHyperStatInfo",
+    tag = "character",
+    params(DateQuery, HyperStatQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserHyperStatData))
+)]
 pub async fn get_user_hyper_stat_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<HyperStatQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<UserHyperStatData>, AppError> {
+    let user_hyper_stat_data: UserHyperStatData =
+        fetch_character(api_key, "hyper-stat", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if query.include_empty {
+        Ok(Json(user_hyper_stat_data))
+    } else {
+        Ok(Json(filter_active_hyper_stat(user_hyper_stat_data)))
+    }
+}
+
+// v2: 전체 스탯을 반환하고, ?filter=active 인 경우에만 필터링
+#[utoipa::path(
+    post,
+    path = "/v2/getUserHyperStatInfo",
+    tag = "character",
+    params(DateQuery, FilterQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserHyperStatData))
+)]
+pub async fn get_user_hyper_stat_info_v2(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<FilterQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UserHyperStatData>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "hyper-stat", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_hyper_stat_data: UserHyperStatData = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        let filtered_data = UserHyperStatData {
-            hyper_stat_preset_1: user_hyper_stat_data
-                .hyper_stat_preset_1
-                .into_iter()
-                .filter(|stat| stat.stat_point.is_some() && stat.stat_increase.is_some())
-                .collect(),
-            hyper_stat_preset_1_remain_point: user_hyper_stat_data.hyper_stat_preset_1_remain_point,
-
-            hyper_stat_preset_2: user_hyper_stat_data
-                .hyper_stat_preset_2
-                .into_iter()
-                .filter(|stat| stat.stat_point.is_some() && stat.stat_increase.is_some())
-                .collect(),
-            hyper_stat_preset_2_remain_point: user_hyper_stat_data.hyper_stat_preset_2_remain_point,
-
-            hyper_stat_preset_3: user_hyper_stat_data
-                .hyper_stat_preset_3
-                .into_iter()
-                .filter(|stat| stat.stat_point.is_some() && stat.stat_increase.is_some())
-                .collect(),
-            hyper_stat_preset_3_remain_point: user_hyper_stat_data.hyper_stat_preset_3_remain_point,
-        };
-
-        Ok(Json(filtered_data))
+) -> Result<Json<UserHyperStatData>, AppError> {
+    let user_hyper_stat_data: UserHyperStatData =
+        fetch_character(api_key, "hyper-stat", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if query.is_active() {
+        Ok(Json(filter_active_hyper_stat(user_hyper_stat_data)))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Ok(Json(user_hyper_stat_data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn fixture() -> Value {
+        let preset = json!([
+            { "stat_type": "STR", "stat_point": 55, "stat_level": 10, "stat_increase": "힘 150 증가" },
+            { "stat_type": "DEX", "stat_point": null, "stat_level": 0, "stat_increase": null },
+            { "stat_type": "보스 몬스터 공격 시 데미지 증가", "stat_point": 50, "stat_level": 10, "stat_increase": "보스 몬스터 공격 시 데미지 35% 증가" }
+        ]);
+
+        json!({
+            "date": "2024-06-01T00:00+09:00",
+            "use_preset_no": "2",
+            "use_available_hyper_stat": 1342,
+            "hyper_stat_preset_1": preset,
+            "hyper_stat_preset_1_remain_point": 3,
+            "hyper_stat_preset_2": preset,
+            "hyper_stat_preset_2_remain_point": 7,
+            "hyper_stat_preset_3": [],
+            "hyper_stat_preset_3_remain_point": 1342
+        })
+    }
+
+    fn stat_types(data: &Value, preset: &str) -> Vec<String> {
+        data[preset]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|stat| stat["stat_type"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn filtered_mode_drops_stats_without_points() {
+        let data: UserHyperStatData = serde_json::from_value(fixture()).unwrap();
+        let filtered = serde_json::to_value(filter_active_hyper_stat(data)).unwrap();
+
+        for preset in ["hyper_stat_preset_1", "hyper_stat_preset_2"] {
+            assert_eq!(
+                stat_types(&filtered, preset),
+                ["STR", "보스 몬스터 공격 시 데미지 증가"]
+            );
+        }
+        assert_eq!(filtered["hyper_stat_preset_2_remain_point"], 7);
+        assert_eq!(filtered["use_preset_no"], "2");
+        assert_eq!(filtered["use_available_hyper_stat"], 1342);
+    }
+
+    #[test]
+    fn unfiltered_mode_returns_presets_as_received() {
+        let data: UserHyperStatData = serde_json::from_value(fixture()).unwrap();
+        let raw = serde_json::to_value(data).unwrap();
+
+        assert_eq!(stat_types(&raw, "hyper_stat_preset_1").len(), 3);
+        assert_eq!(raw["hyper_stat_preset_1"][1]["stat_point"], Value::Null);
+        assert_eq!(raw["use_preset_no"], "2");
+    }
+
+    #[test]
+    fn preset_fields_are_optional_in_old_snapshots() {
+        let mut fixture = fixture();
+        let fields = fixture.as_object_mut().unwrap();
+        fields.remove("use_preset_no");
+        fields.remove("use_available_hyper_stat");
+
+        let data: UserHyperStatData = serde_json::from_value(fixture).unwrap();
+        assert_eq!(data.use_preset_no, None);
+        assert_eq!(data.use_available_hyper_stat, None);
     }
 }
@@ -1,14 +1,24 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::normalize::{OptionValue, normalize_option};
+use crate::api::character::potential::{PotentialLine, parse_potentials};
+use crate::api::character::request::fetch_character;
+use crate::api::compact::MaybeCompact;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::{DefaultOnNull, serde_as};
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ItemEquipmentInfoOption {
     str: String,
     dex: String,
@@ -35,7 +45,7 @@ pub struct ItemEquipmentInfoOption {
 }
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ItemEquipmentInfoExceptionalOption {
     str: String,
     dex: String,
@@ -62,7 +72,7 @@ pub struct ItemEquipmentInfoExceptionalOption {
     equipment_level_decrease: i16,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ItemEquipmentStatOption {
     str: String,
     dex: String,
@@ -78,7 +88,7 @@ pub struct ItemEquipmentStatOption {
 }
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ItemEquipmentInfo {
     item_equipment_part: String,
     item_equipment_slot: String,
@@ -119,29 +129,905 @@ pub struct ItemEquipmentInfo {
     item_etc_option: ItemEquipmentStatOption,
     item_starforce_option: ItemEquipmentStatOption,
     special_ring_level: i8,
+    // starforce 를 숫자로 변환한 값과 장비 레벨 기준 최대 스타포스 (스타포스 강화 불가 장비는 0)
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    starforce_level: Option<u8>,
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    max_starforce: Option<u8>,
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    normalized_option: Option<NormalizedItemOption>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+// 단위가 붙은 아이템 옵션 값
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NormalizedItemOption {
+    total: Vec<OptionValue>,
+    base: Vec<OptionValue>,
+    exceptional: Vec<OptionValue>,
+    add: Vec<OptionValue>,
+    etc: Vec<OptionValue>,
+    starforce: Vec<OptionValue>,
+    // potential_option_1~3 / additional_potential_option_1~3 을 파싱한 값
+    potential: Vec<PotentialLine>,
+    additional_potential: Vec<PotentialLine>,
+}
+
+// 슈페리얼 장비 (최대 스타포스가 일반 장비보다 낮다)
+const SUPERIOR_PREFIXES: [&str; 3] = ["타일런트", "노바", "헬리시움"];
+// 스타포스 강화가 불가능한 부위
+const NO_STARFORCE_SLOTS: [&str; 5] = ["엠블렘", "보조무기", "뱃지", "포켓 아이템", "훈장"];
+
+// 장비 레벨 구간별 최대 스타포스
+fn max_starforce(level: i16, superior: bool) -> u8 {
+    let band = match level {
+        ..95 => 0,
+        95..108 => 1,
+        108..118 => 2,
+        118..128 => 3,
+        128..138 => 4,
+        _ => 5,
+    };
+
+    if superior {
+        [3, 5, 8, 10, 12, 15][band]
+    } else {
+        [5, 8, 10, 15, 20, 25][band]
+    }
+}
+
+impl ItemEquipmentInfo {
+    // 스타포스 값과 최대 스타포스를 채우고 (현재, 최대) 를 반환
+    fn fill_starforce(&mut self) -> (u8, u8) {
+        let starforce = self.starforce.trim().parse().unwrap_or(0);
+
+        let superior = SUPERIOR_PREFIXES
+            .iter()
+            .any(|prefix| self.item_name.starts_with(prefix));
+        let max = if NO_STARFORCE_SLOTS.contains(&self.item_equipment_slot.as_str())
+            || self.special_ring_level > 0
+        {
+            0
+        } else {
+            max_starforce(self.item_base_option.base_equipment_level, superior)
+        };
+
+        self.starforce_level = Some(starforce);
+        self.max_starforce = Some(max);
+        (starforce, max)
+    }
+
+    fn normalize(&mut self) {
+        self.normalized_option = Some(NormalizedItemOption {
+            total: normalize_option(&self.item_total_option),
+            base: normalize_option(&self.item_base_option),
+            exceptional: normalize_option(&self.item_exceptional_option),
+            add: normalize_option(&self.item_add_option),
+            etc: normalize_option(&self.item_etc_option),
+            starforce: normalize_option(&self.item_starforce_option),
+            potential: parse_potentials([
+                &self.potential_option_1,
+                &self.potential_option_2,
+                &self.potential_option_3,
+            ]),
+            additional_potential: parse_potentials([
+                &self.additional_potential_option_1,
+                &self.additional_potential_option_2,
+                &self.additional_potential_option_3,
+            ]),
+        });
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct ItemEquipment {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    preset_no: Option<u8>, // 현재 적용 중인 프리셋 번호
     item_equipment: Vec<ItemEquipmentInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    item_equipment_preset_1: Vec<ItemEquipmentInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    item_equipment_preset_2: Vec<ItemEquipmentInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    item_equipment_preset_3: Vec<ItemEquipmentInfo>,
+    // 에반(드래곤), 메카닉 전용 장비 (필드 구성이 일반 장비와 달라 그대로 전달)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    dragon_equipment: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    mechanic_equipment: Vec<Value>,
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    starforce_summary: Option<StarforceSummary>,
+}
+
+// 스타포스 강화가 가능한 장비 (item_equipment) 의 스타포스 요약
+#[derive(Serialize, Debug, Default, ToSchema)]
+pub struct StarforceSummary {
+    total_stars: u32,
+    // 스타포스 → 장비 개수
+    counts: BTreeMap<u8, u32>,
+    // ?min_star=N 을 지정한 경우 N성 미만인 장비
+    #[serde(skip_serializing_if = "Option::is_none")]
+    below_min_star: Option<Vec<StarforceItem>>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct StarforceItem {
+    item_equipment_slot: String,
+    item_name: String,
+    starforce: u8,
+    max_starforce: u8,
+}
+
+impl ItemEquipment {
+    // 장비별 스타포스 값을 채우고 요약을 만든다
+    fn summarize_starforce(&mut self, min_star: Option<u8>) {
+        for item in self
+            .item_equipment_preset_1
+            .iter_mut()
+            .chain(self.item_equipment_preset_2.iter_mut())
+            .chain(self.item_equipment_preset_3.iter_mut())
+        {
+            item.fill_starforce();
+        }
+
+        let mut summary = StarforceSummary {
+            below_min_star: min_star.map(|_| Vec::new()),
+            ..Default::default()
+        };
+
+        for item in self.item_equipment.iter_mut() {
+            let (starforce, max) = item.fill_starforce();
+            if max == 0 {
+                continue;
+            }
+
+            summary.total_stars += u32::from(starforce);
+            *summary.counts.entry(starforce).or_default() += 1;
+
+            if let (Some(min_star), Some(items)) = (min_star, summary.below_min_star.as_mut())
+                && starforce < min_star.min(max)
+            {
+                items.push(StarforceItem {
+                    item_equipment_slot: item.item_equipment_slot.clone(),
+                    item_name: item.item_name.clone(),
+                    starforce,
+                    max_starforce: max,
+                });
+            }
+        }
+
+        self.starforce_summary = Some(summary);
+    }
+
+    // 요청한 프리셋의 장비만 item_equipment에 남긴다
+    fn select_preset(&mut self, preset: EquipmentPreset) {
+        let preset_no = match preset {
+            EquipmentPreset::Preset(preset_no) => preset_no,
+            EquipmentPreset::Active => match self.preset_no {
+                Some(preset_no) => preset_no,
+                None => return,
+            },
+        };
+
+        let items = match preset_no {
+            1 => std::mem::take(&mut self.item_equipment_preset_1),
+            2 => std::mem::take(&mut self.item_equipment_preset_2),
+            3 => std::mem::take(&mut self.item_equipment_preset_3),
+            _ => return,
+        };
+
+        self.item_equipment = items;
+        self.preset_no = Some(preset_no);
+        self.item_equipment_preset_1.clear();
+        self.item_equipment_preset_2.clear();
+        self.item_equipment_preset_3.clear();
+    }
+
+    // 필터와 일치하는 장비만 남긴다 (프리셋 장비 포함)
+    fn retain_items(&mut self, filter: &ItemFilter) {
+        for items in [
+            &mut self.item_equipment,
+            &mut self.item_equipment_preset_1,
+            &mut self.item_equipment_preset_2,
+            &mut self.item_equipment_preset_3,
+        ] {
+            items.retain(|item| filter.matches(item));
+        }
+    }
+}
+
+// ?preset=1|2|3|active
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquipmentPreset {
+    Preset(u8),
+    Active,
+}
+
+impl EquipmentPreset {
+    fn parse(preset: &str) -> Option<Self> {
+        match preset {
+            "1" => Some(EquipmentPreset::Preset(1)),
+            "2" => Some(EquipmentPreset::Preset(2)),
+            "3" => Some(EquipmentPreset::Preset(3)),
+            "active" => Some(EquipmentPreset::Active),
+            _ => None,
+        }
+    }
 }
 
+// ?preset= 값 검사 (지정하지 않은 경우 None)
+fn parse_preset(preset: Option<&str>) -> Result<Option<EquipmentPreset>, AppError> {
+    match preset.map(EquipmentPreset::parse) {
+        Some(None) => Err(AppError::Unprocessable(
+            "preset must be one of: 1, 2, 3, active",
+        )),
+        Some(preset) => Ok(preset),
+        None => Ok(None),
+    }
+}
+
+// 넥슨 API 장비 슬롯 이름 (item_equipment_slot)
+const EQUIPMENT_SLOTS: [&str; 24] = [
+    "모자",
+    "얼굴장식",
+    "눈장식",
+    "귀고리",
+    "상의",
+    "하의",
+    "신발",
+    "장갑",
+    "망토",
+    "보조무기",
+    "무기",
+    "반지1",
+    "반지2",
+    "반지3",
+    "반지4",
+    "펜던트",
+    "펜던트2",
+    "훈장",
+    "벨트",
+    "어깨장식",
+    "포켓 아이템",
+    "기계 심장",
+    "뱃지",
+    "엠블렘",
+];
+
+// 지원하지 않는 슬롯을 요청한 경우의 오류 메시지 (EQUIPMENT_SLOTS 에서 생성)
+static INVALID_SLOT: Lazy<String> = Lazy::new(|| {
+    format!(
+        "slot must be a comma separated list of: {}",
+        EQUIPMENT_SLOTS.join(", ")
+    )
+});
+const INVALID_PART: &str = "part must be a comma separated list of equipment part names (letters, digits and spaces, up to 20 characters)";
+// 부위 이름 최대 길이 ("듀얼 보우건", "건틀렛 리볼버" 등)
+const MAX_PART_LENGTH: usize = 20;
+
+// 부위(part)는 직업이 추가될 때마다 무기 / 보조무기 종류가 늘어나므로 목록 대신 이름 형식을 확인한다
+fn is_valid_part(part: &str) -> bool {
+    part.chars().count() <= MAX_PART_LENGTH && part.chars().all(|c| c.is_alphanumeric() || c == ' ')
+}
+
+// 장비 슬롯 / 부위 필터 (?slot=무기,엠블렘&part=반지)
+// 둘 다 지정하면 하나라도 일치하는 장비를 남긴다
+struct ItemFilter {
+    slots: Vec<String>,
+    parts: Vec<String>,
+}
+
+impl ItemFilter {
+    // 형식은 맞지만 없는 슬롯 / 잘못된 부위 이름은 422
+    fn parse(slot: Option<&str>, part: Option<&str>) -> Result<Option<Self>, AppError> {
+        let split = |raw: Option<&str>| -> Vec<String> {
+            raw.into_iter()
+                .flat_map(|raw| raw.split(','))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+
+        let slots = split(slot);
+        let parts = split(part);
+
+        if slots
+            .iter()
+            .any(|slot| !EQUIPMENT_SLOTS.contains(&slot.as_str()))
+        {
+            return Err(AppError::Unprocessable(INVALID_SLOT.as_str()));
+        }
+
+        if !parts.iter().all(|part| is_valid_part(part)) {
+            return Err(AppError::Unprocessable(INVALID_PART));
+        }
+
+        if slots.is_empty() && parts.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self { slots, parts }))
+    }
+
+    fn matches(&self, item: &ItemEquipmentInfo) -> bool {
+        self.slots.contains(&item.item_equipment_slot)
+            || self.parts.contains(&item.item_equipment_part)
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct ItemEquipmentQuery {
+    #[serde(default)]
+    compact: bool,
+    #[serde(default)]
+    normalized: bool,
+    #[serde(default)]
+    preset: Option<String>,
+    // 이 값 미만의 스타포스 장비를 starforce_summary.below_min_star 에 표시
+    #[serde(default)]
+    min_star: Option<u8>,
+    // 쉼표로 구분한 장비 슬롯 (예: 무기,보조무기,엠블렘)
+    #[serde(default)]
+    slot: Option<String>,
+    // 쉼표로 구분한 장비 부위 (예: 반지,펜던트)
+    #[serde(default)]
+    part: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserItemEquipment",
+    tag = "character",
+    params(DateQuery, ItemEquipmentQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = ItemEquipment))
+)]
 pub async fn get_user_item_equipment(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<ItemEquipmentQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<ItemEquipment>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "item-equipment", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_item_equipment: ItemEquipment = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_item_equipment))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<MaybeCompact<ItemEquipment>, AppError> {
+    let preset = parse_preset(query.preset.as_deref())?;
+    let filter = ItemFilter::parse(query.slot.as_deref(), query.part.as_deref())?;
+
+    let mut user_item_equipment: ItemEquipment =
+        fetch_character(api_key, "item-equipment", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if let Some(preset) = preset {
+        user_item_equipment.select_preset(preset);
+    }
+    // 스타포스 요약은 필터와 관계없이 전체 장비 기준
+    user_item_equipment.summarize_starforce(query.min_star);
+    if let Some(filter) = filter {
+        user_item_equipment.retain_items(&filter);
+    }
+
+    if query.normalized {
+        user_item_equipment
+            .item_equipment
+            .iter_mut()
+            .for_each(ItemEquipmentInfo::normalize);
+    }
+
+    Ok(MaybeCompact {
+        data: user_item_equipment,
+        compact: query.compact,
+    })
+}
+
+// 옵션 합계 (문자열 옵션 값을 숫자로 변환해 더한 값)
+// 올스탯은 STR / DEX / INT / LUK 에 합치지 않고 따로 표시한다
+#[derive(Serialize, Debug, Default, Clone, PartialEq, ToSchema)]
+pub struct OptionTotals {
+    str: i64,
+    dex: i64,
+    int: i64,
+    luk: i64,
+    max_hp: i64,
+    max_mp: i64,
+    attack_power: i64,
+    magic_power: i64,
+    armor: i64,
+    speed: i64,
+    jump: i64,
+    boss_damage: f64,
+    // 방어율 무시는 곱연산으로 합친다 (1 - (1 - a)(1 - b)...)
+    ignore_monster_armor: f64,
+    all_stat: f64,
+    damage: f64,
+    max_hp_rate: f64,
+    max_mp_rate: f64,
+}
+
+impl OptionTotals {
+    fn add<T: Serialize>(&mut self, option: &T) {
+        for OptionValue { field, value, .. } in normalize_option(option) {
+            let flat = value.round() as i64;
+
+            match field.as_str() {
+                "str" => self.str += flat,
+                "dex" => self.dex += flat,
+                "int" => self.int += flat,
+                "luk" => self.luk += flat,
+                "max_hp" => self.max_hp += flat,
+                "max_mp" => self.max_mp += flat,
+                "attack_power" => self.attack_power += flat,
+                "magic_power" => self.magic_power += flat,
+                "armor" => self.armor += flat,
+                "speed" => self.speed += flat,
+                "jump" => self.jump += flat,
+                "boss_damage" => self.boss_damage += value,
+                "ignore_monster_armor" => {
+                    self.ignore_monster_armor =
+                        100.0 - (100.0 - self.ignore_monster_armor) * (100.0 - value) / 100.0;
+                }
+                "all_stat" => self.all_stat += value,
+                "damage" => self.damage += value,
+                "max_hp_rate" => self.max_hp_rate += value,
+                "max_mp_rate" => self.max_mp_rate += value,
+                // 착용 레벨 감소 등 스탯이 아닌 값
+                _ => {}
+            }
+        }
+    }
+
+    fn merge(&mut self, other: &OptionTotals) {
+        self.str += other.str;
+        self.dex += other.dex;
+        self.int += other.int;
+        self.luk += other.luk;
+        self.max_hp += other.max_hp;
+        self.max_mp += other.max_mp;
+        self.attack_power += other.attack_power;
+        self.magic_power += other.magic_power;
+        self.armor += other.armor;
+        self.speed += other.speed;
+        self.jump += other.jump;
+        self.boss_damage += other.boss_damage;
+        self.ignore_monster_armor = 100.0
+            - (100.0 - self.ignore_monster_armor) * (100.0 - other.ignore_monster_armor) / 100.0;
+        self.all_stat += other.all_stat;
+        self.damage += other.damage;
+        self.max_hp_rate += other.max_hp_rate;
+        self.max_mp_rate += other.max_mp_rate;
+    }
+}
+
+// 장비 한 부위의 합계
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SlotTotals {
+    item_equipment_slot: String,
+    item_name: String,
+    total: OptionTotals,
+}
+
+// 착용 장비 전체의 옵션 합계
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ItemEquipmentTotals {
+    date: String,
+    preset_no: Option<u8>,
+    // item_total_option 합계
+    total: OptionTotals,
+    // 옵션 종류별 합계 (기본 / 추가 옵션(환생의 불꽃) / 익셉셔널 / 주문서 / 스타포스)
+    base: OptionTotals,
+    add: OptionTotals,
+    exceptional: OptionTotals,
+    etc: OptionTotals,
+    starforce: OptionTotals,
+    slots: Vec<SlotTotals>,
+}
+
+impl From<ItemEquipment> for ItemEquipmentTotals {
+    fn from(equipment: ItemEquipment) -> Self {
+        let mut totals = ItemEquipmentTotals {
+            date: equipment.date,
+            preset_no: equipment.preset_no,
+            total: OptionTotals::default(),
+            base: OptionTotals::default(),
+            add: OptionTotals::default(),
+            exceptional: OptionTotals::default(),
+            etc: OptionTotals::default(),
+            starforce: OptionTotals::default(),
+            slots: Vec::with_capacity(equipment.item_equipment.len()),
+        };
+
+        for item in equipment.item_equipment {
+            let mut slot_total = OptionTotals::default();
+            slot_total.add(&item.item_total_option);
+
+            totals.total.merge(&slot_total);
+            totals.base.add(&item.item_base_option);
+            totals.add.add(&item.item_add_option);
+            totals.exceptional.add(&item.item_exceptional_option);
+            totals.etc.add(&item.item_etc_option);
+            totals.starforce.add(&item.item_starforce_option);
+
+            totals.slots.push(SlotTotals {
+                item_equipment_slot: item.item_equipment_slot,
+                item_name: item.item_name,
+                total: slot_total,
+            });
+        }
+
+        totals
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct ItemEquipmentTotalsQuery {
+    #[serde(default)]
+    preset: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserItemEquipmentTotals",
+    tag = "character",
+    params(DateQuery, ItemEquipmentTotalsQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = ItemEquipmentTotals))
+)]
+pub async fn get_user_item_equipment_totals(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<ItemEquipmentTotalsQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<ItemEquipmentTotals>, AppError> {
+    let preset = parse_preset(query.preset.as_deref())?;
+
+    let mut user_item_equipment: ItemEquipment =
+        fetch_character(api_key, "item-equipment", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if let Some(preset) = preset {
+        user_item_equipment.select_preset(preset);
+    }
+
+    Ok(Json(user_item_equipment.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::character::normalize::OptionUnit;
+
+    #[test]
+    fn invalid_slot_message_lists_every_slot() {
+        for slot in EQUIPMENT_SLOTS {
+            assert!(INVALID_SLOT.contains(slot), "{slot}");
+        }
+    }
+
+    #[test]
+    fn filter_accepts_known_slots_and_part_names() {
+        let filter = ItemFilter::parse(Some("무기, 포켓 아이템"), Some("반지,듀얼 보우건"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(filter.slots, ["무기", "포켓 아이템"]);
+        assert_eq!(filter.parts, ["반지", "듀얼 보우건"]);
+        assert!(ItemFilter::parse(None, Some(" , ")).unwrap().is_none());
+    }
+
+    #[test]
+    fn filter_rejects_unknown_slots_and_malformed_parts() {
+        assert!(matches!(
+            ItemFilter::parse(Some("무기,반지"), None),
+            Err(AppError::Unprocessable(message)) if message.starts_with("slot must be")
+        ));
+        assert!(matches!(
+            ItemFilter::parse(None, Some("반지&slot=무기")),
+            Err(AppError::Unprocessable(INVALID_PART))
+        ));
+        assert!(matches!(
+            ItemFilter::parse(None, Some(&"가".repeat(MAX_PART_LENGTH + 1))),
+            Err(AppError::Unprocessable(INVALID_PART))
+        ));
+    }
+
+    // 옵션 구조체의 모든 필드에 단위가 정해져 있는지 확인 (필드 → 단위)
+    const OPTION_UNITS: [(&str, OptionUnit); 20] = [
+        ("str", OptionUnit::Flat),
+        ("dex", OptionUnit::Flat),
+        ("int", OptionUnit::Flat),
+        ("luk", OptionUnit::Flat),
+        ("max_hp", OptionUnit::Flat),
+        ("max_mp", OptionUnit::Flat),
+        ("attack_power", OptionUnit::Flat),
+        ("magic_power", OptionUnit::Flat),
+        ("armor", OptionUnit::Flat),
+        ("speed", OptionUnit::Flat),
+        ("jump", OptionUnit::Flat),
+        ("boss_damage", OptionUnit::Percent),
+        ("ignore_monster_armor", OptionUnit::Percent),
+        ("all_stat", OptionUnit::Percent),
+        ("damage", OptionUnit::Percent),
+        ("max_hp_rate", OptionUnit::Percent),
+        ("max_mp_rate", OptionUnit::Percent),
+        ("equipment_level_decrease", OptionUnit::Level),
+        ("base_equipment_level", OptionUnit::Level),
+        ("exceptional_upgrade", OptionUnit::Level),
+    ];
+
+    // 모든 필드를 1 로 채운 옵션 구조체의 정규화 결과 (레벨 필드는 숫자, 나머지는 문자열)
+    fn normalized<T: Serialize + serde::de::DeserializeOwned>() -> Vec<OptionValue> {
+        let fields = OPTION_UNITS
+            .iter()
+            .map(|(field, unit)| {
+                let value = match unit {
+                    OptionUnit::Level => serde_json::json!(1),
+                    _ => serde_json::json!("1"),
+                };
+                (field.to_string(), value)
+            })
+            .collect();
+        let option: T = serde_json::from_value(serde_json::Value::Object(fields)).unwrap();
+
+        normalize_option(&option)
+    }
+
+    fn assert_units(values: Vec<OptionValue>, expected_fields: usize) {
+        assert_eq!(values.len(), expected_fields);
+        for OptionValue { field, unit, .. } in values {
+            let expected = OPTION_UNITS
+                .iter()
+                .find(|(name, _)| *name == field)
+                .map(|(_, unit)| *unit);
+            assert_eq!(Some(unit), expected, "{field}");
+        }
+    }
+
+    #[test]
+    fn every_option_field_has_a_unit() {
+        assert_units(normalized::<ItemEquipmentInfoOption>(), 19);
+        assert_units(normalized::<ItemEquipmentInfoExceptionalOption>(), 15);
+        assert_units(normalized::<ItemEquipmentStatOption>(), 11);
+    }
+
+    // 지정한 필드 외에는 0 으로 채운 옵션 구조체
+    fn option<T: serde::de::DeserializeOwned>(values: &[(&str, &str)]) -> T {
+        let fields = OPTION_UNITS
+            .iter()
+            .map(|(field, unit)| {
+                let value = values
+                    .iter()
+                    .find(|(name, _)| name == field)
+                    .map_or("0", |(_, value)| value);
+                let value = match unit {
+                    OptionUnit::Level => serde_json::json!(value.parse::<i16>().unwrap()),
+                    _ => serde_json::json!(value),
+                };
+                (field.to_string(), value)
+            })
+            .collect();
+
+        serde_json::from_value(Value::Object(fields)).unwrap()
+    }
+
+    #[test]
+    fn totals_add_flat_and_percent_options() {
+        let mut totals = OptionTotals::default();
+        totals.add(&option::<ItemEquipmentInfoOption>(&[
+            ("str", "+150"),
+            ("all_stat", "6%"),
+            ("boss_damage", "30"),
+            ("equipment_level_decrease", "5"),
+        ]));
+        totals.add(&option::<ItemEquipmentInfoOption>(&[
+            ("str", "40"),
+            ("attack_power", "1,200"),
+            ("boss_damage", "12.5"),
+        ]));
+
+        assert_eq!(totals.str, 190);
+        assert_eq!(totals.attack_power, 1200);
+        assert_eq!(totals.all_stat, 6.0);
+        assert_eq!(totals.boss_damage, 42.5);
+        // 올스탯은 STR 에 합치지 않는다
+        assert_eq!(totals.dex, 0);
+    }
+
+    #[test]
+    fn ignore_monster_armor_is_combined_multiplicatively() {
+        let mut totals = OptionTotals::default();
+        for value in ["30", "40"] {
+            totals.add(&option::<ItemEquipmentInfoOption>(&[(
+                "ignore_monster_armor",
+                value,
+            )]));
+        }
+        assert!((totals.ignore_monster_armor - 58.0).abs() < 1e-9);
+
+        let mut merged = OptionTotals::default();
+        merged.add(&option::<ItemEquipmentInfoOption>(&[(
+            "ignore_monster_armor",
+            "50",
+        )]));
+        merged.merge(&totals);
+        assert!((merged.ignore_monster_armor - 79.0).abs() < 1e-9);
+    }
+
+    // 장비 24 부위를 모두 착용한 히어로의 응답
+    fn fixture() -> ItemEquipment {
+        serde_json::from_str(include_str!("../../../tests/fixtures/item_equipment.json")).unwrap()
+    }
+
+    fn item<'a>(equipment: &'a mut ItemEquipment, slot: &str) -> &'a mut ItemEquipmentInfo {
+        equipment
+            .item_equipment
+            .iter_mut()
+            .find(|item| item.item_equipment_slot == slot)
+            .unwrap()
+    }
+
+    #[test]
+    fn equipment_totals_sum_every_slot() {
+        let totals = ItemEquipmentTotals::from(fixture());
+
+        assert_eq!(totals.preset_no, Some(1));
+
+        // 부위별 합계 (STR, 공격력)
+        let slots: Vec<_> = totals
+            .slots
+            .iter()
+            .map(|slot| {
+                (
+                    slot.item_equipment_slot.as_str(),
+                    slot.total.str,
+                    slot.total.attack_power,
+                )
+            })
+            .collect();
+        assert_eq!(
+            slots,
+            [
+                ("모자", 265, 85),
+                ("얼굴장식", 97, 5),
+                ("눈장식", 129, 1),
+                ("귀고리", 225, 45),
+                ("상의", 240, 60),
+                ("하의", 240, 60),
+                ("신발", 240, 85),
+                ("장갑", 210, 126),
+                ("망토", 235, 87),
+                ("보조무기", 10, 3),
+                ("무기", 140, 667),
+                ("반지1", 4, 4),
+                ("반지2", 70, 2),
+                ("반지3", 80, 1),
+                ("반지4", 41, 0),
+                ("펜던트", 206, 58),
+                ("펜던트2", 115, 1),
+                ("훈장", 7, 7),
+                ("벨트", 201, 56),
+                ("어깨장식", 176, 95),
+                ("포켓 아이템", 41, 5),
+                ("기계 심장", 0, 101),
+                ("뱃지", 10, 10),
+                ("엠블렘", 10, 2),
+            ]
+        );
+        let weapon = &totals.slots[10];
+        assert_eq!(weapon.item_name, "아케인셰이드 투핸드소드");
+        assert_eq!(weapon.total.boss_damage, 42.0);
+        assert_eq!(weapon.total.damage, 6.0);
+
+        let total = &totals.total;
+        assert_eq!(
+            (total.str, total.dex, total.int, total.luk),
+            (2992, 2152, 153, 153)
+        );
+        assert_eq!((total.attack_power, total.magic_power), (1566, 59));
+        assert_eq!((total.armor, total.speed, total.jump), (1050, 10, 7));
+        assert_eq!(total.boss_damage, 42.0);
+        assert_eq!(total.all_stat, 39.0);
+        assert_eq!(total.damage, 6.0);
+        // 모자 10%, 상의 / 하의 5%, 무기 20% 를 곱연산으로 합친 값
+        assert!((total.ignore_monster_armor - 35.02).abs() < 1e-9);
+
+        // 옵션 종류별 합계는 전체 합계와 같다
+        let parts = [
+            &totals.base,
+            &totals.add,
+            &totals.etc,
+            &totals.starforce,
+            &totals.exceptional,
+        ];
+        assert_eq!(parts.map(|part| part.str), [408, 534, 326, 1724, 0]);
+        assert_eq!(parts.map(|part| part.attack_power), [439, 150, 108, 869, 0]);
+        assert_eq!(parts.iter().map(|part| part.dex).sum::<i64>(), total.dex);
+        assert_eq!(totals.add.all_stat, total.all_stat);
+        assert!((totals.base.ignore_monster_armor - total.ignore_monster_armor).abs() < 1e-9);
+    }
+
+    #[test]
+    fn max_starforce_follows_level_bands() {
+        assert_eq!(max_starforce(77, false), 5);
+        assert_eq!(max_starforce(100, false), 8);
+        assert_eq!(max_starforce(130, false), 20);
+        assert_eq!(max_starforce(160, false), 25);
+        assert_eq!(max_starforce(150, true), 15);
+        assert_eq!(max_starforce(110, true), 8);
+    }
+
+    #[test]
+    fn fill_starforce_handles_superior_and_unenhanceable_items() {
+        let mut equipment = fixture();
+        assert_eq!(item(&mut equipment, "모자").fill_starforce(), (22, 25));
+        assert_eq!(item(&mut equipment, "반지4").fill_starforce(), (8, 10));
+        assert_eq!(item(&mut equipment, "엠블렘").fill_starforce(), (0, 0));
+
+        let ring = item(&mut equipment, "반지1");
+        assert_eq!(ring.fill_starforce(), (0, 0));
+        assert_eq!(ring.starforce_level, Some(0));
+
+        let shoes = item(&mut equipment, "신발");
+        shoes.item_name = "타일런트 히아데스 부츠".to_string();
+        shoes.item_base_option.base_equipment_level = 150;
+        shoes.starforce = "12".to_string();
+        assert_eq!(shoes.fill_starforce(), (12, 15));
+
+        shoes.item_equipment_slot = "엠블렘".to_string();
+        assert_eq!(shoes.fill_starforce(), (12, 0));
+    }
+
+    #[test]
+    fn starforce_summary_counts_stars_and_lists_items_below_min_star() {
+        let mut equipment = fixture();
+        equipment.summarize_starforce(Some(20));
+
+        let summary = equipment.starforce_summary.unwrap();
+        assert_eq!(summary.total_stars, 332);
+        assert_eq!(
+            summary.counts,
+            BTreeMap::from([(8, 2), (15, 3), (17, 3), (22, 10)])
+        );
+
+        // 최대 스타포스가 20 보다 낮은 장비는 최대 스타포스 기준 (펜던트2 는 15 / 15)
+        let below: Vec<_> = summary
+            .below_min_star
+            .unwrap()
+            .into_iter()
+            .map(|item| (item.item_equipment_slot, item.starforce, item.max_starforce))
+            .collect();
+        assert_eq!(
+            below,
+            [
+                ("얼굴장식".to_string(), 15, 25),
+                ("눈장식".to_string(), 17, 25),
+                ("무기".to_string(), 17, 25),
+                ("반지2".to_string(), 15, 25),
+                ("반지3".to_string(), 17, 25),
+                ("반지4".to_string(), 8, 10),
+                ("기계 심장".to_string(), 8, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn starforce_summary_skips_unenhanceable_items() {
+        let mut equipment = fixture();
+        item(&mut equipment, "무기").item_equipment_slot = "보조무기".to_string();
+        equipment.summarize_starforce(None);
+
+        let summary = equipment.starforce_summary.unwrap();
+        assert_eq!(summary.total_stars, 332 - 17);
+        assert!(summary.below_min_star.is_none());
+        assert_eq!(equipment.item_equipment[10].max_starforce, Some(0));
+        // 원래 보조무기 / 엠블렘 / 뱃지 / 포켓 아이템 / 훈장 / 시드링도 제외
+        let unenhanceable = equipment
+            .item_equipment
+            .iter()
+            .filter(|item| item.max_starforce == Some(0))
+            .count();
+        assert_eq!(unenhanceable, 7);
     }
 }
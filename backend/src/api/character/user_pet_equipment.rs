@@ -0,0 +1,119 @@
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, normalize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+
+use super::character::UserOcid;
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::{DefaultOnNull, serde_as};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct PetItemOption {
+    option_type: String,
+    option_value: String,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct PetEquipmentInfo {
+    item_name: String,
+    item_icon: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    item_description: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    item_option: Vec<PetItemOption>,
+    #[serde(default)]
+    scroll_upgrade: u8,
+    #[serde(default)]
+    scroll_upgradable: u8,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct PetInfo {
+    #[serde(default)]
+    slot: u8,
+    name: String,
+    #[serde(default)]
+    nickname: Option<String>,
+    #[serde(default)]
+    icon: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    pet_type: Option<String>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    skill: Vec<String>,
+    #[serde(default)]
+    equipment: Option<PetEquipmentInfo>,
+    #[serde(default)]
+    date_expire: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PetEquipment {
+    date: String,
+    pets: Vec<PetInfo>,
+}
+
+// pet_1_*, pet_2_*, pet_3_* 로 나뉜 필드를 슬롯별 PetInfo 목록으로 변환
+// 펫이 없는 슬롯(이름이 null)은 제외
+fn collect_pets(payload: &Map<String, Value>) -> Vec<PetInfo> {
+    (1..=3u8)
+        .filter_map(|slot| {
+            let prefix = format!("pet_{slot}_");
+            let fields: Map<String, Value> = payload
+                .iter()
+                .filter_map(|(key, value)| {
+                    key.strip_prefix(&prefix)
+                        .map(|field| (field.to_string(), value.clone()))
+                })
+                .collect();
+
+            if fields.get("name").is_none_or(Value::is_null) {
+                return None;
+            }
+
+            let mut pet: PetInfo = serde_json::from_value(Value::Object(fields)).ok()?;
+            pet.slot = slot;
+            Some(pet)
+        })
+        .collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserPetEquipment",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = PetEquipment))
+)]
+pub async fn get_user_pet_equipment(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<PetEquipment>, AppError> {
+    let payload: Map<String, Value> =
+        fetch_character(api_key, "pet-equipment", &user_ocid.ocid, &date.resolve()?).await?;
+
+    let date = payload
+        .get("date")
+        .and_then(Value::as_str)
+        .map(normalize_date)
+        .unwrap_or_default();
+
+    Ok(Json(PetEquipment {
+        date,
+        pets: collect_pets(&payload),
+    }))
+}
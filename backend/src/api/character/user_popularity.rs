@@ -0,0 +1,37 @@
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+
+use super::character::UserOcid;
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct Popularity {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    popularity: i64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserPopularity",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = Popularity))
+)]
+pub async fn get_user_popularity(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<Popularity>, AppError> {
+    fetch_character(api_key, "popularity", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(Json)
+}
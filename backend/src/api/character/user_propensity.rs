@@ -1,38 +1,156 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Serialize, Debug)]
+// 성향 키 → 표시 이름 (응답 순서 기준)
+const DISPLAY_NAMES: [(&str, &str); 6] = [
+    ("charisma", "카리스마"),
+    ("sensibility", "감성"),
+    ("insight", "통찰력"),
+    ("willingness", "의지"),
+    ("handicraft", "손재주"),
+    ("charm", "매력"),
+];
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PropensityLevel {
+    key: &'static str,
+    display_name: &'static str,
+    level: i16,
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct Propensity {
-    charisma_level: i8,
-    sensibility_level: i8,
-    insight_level: i8,
-    willingness_level: i8,
-    handicraft_level: i8,
-    charm_level: i8,
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    charisma_level: i16,
+    sensibility_level: i16,
+    insight_level: i16,
+    willingness_level: i16,
+    handicraft_level: i16,
+    charm_level: i16,
+    // 레벨이 높은 순 (같은 레벨은 응답 순서)
+    #[serde(skip_deserializing)]
+    levels: Vec<PropensityLevel>,
+    #[serde(skip_deserializing)]
+    total_level: i16,
+}
+
+impl Propensity {
+    // 성향 목록과 레벨 합계를 채운다
+    pub fn enrich(mut self) -> Self {
+        let levels = [
+            self.charisma_level,
+            self.sensibility_level,
+            self.insight_level,
+            self.willingness_level,
+            self.handicraft_level,
+            self.charm_level,
+        ];
+
+        self.levels = DISPLAY_NAMES
+            .iter()
+            .zip(levels)
+            .map(|(&(key, display_name), level)| PropensityLevel {
+                key,
+                display_name,
+                level,
+            })
+            .collect();
+        self.levels
+            .sort_by_key(|propensity| Reverse(propensity.level));
+        self.total_level = levels.iter().sum();
+
+        self
+    }
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUserPropensity",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = Propensity))
+)]
 pub async fn get_user_propensity(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<Propensity>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "propensity", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_propensity: Propensity = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_propensity))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<Json<Propensity>, AppError> {
+    fetch_character(api_key, "propensity", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(|propensity: Propensity| Json(propensity.enrich()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn propensity(levels: [i16; 6]) -> Propensity {
+        serde_json::from_value(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "charisma_level": levels[0],
+            "sensibility_level": levels[1],
+            "insight_level": levels[2],
+            "willingness_level": levels[3],
+            "handicraft_level": levels[4],
+            "charm_level": levels[5],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn display_names_match_response_fields() {
+        let fields = serde_json::to_value(propensity([0; 6])).unwrap();
+
+        for (key, display_name) in DISPLAY_NAMES {
+            assert!(fields.get(format!("{key}_level")).is_some(), "{key}");
+            assert!(!display_name.is_empty());
+        }
+        assert_eq!(DISPLAY_NAMES[0], ("charisma", "카리스마"));
+        assert_eq!(DISPLAY_NAMES[5], ("charm", "매력"));
+    }
+
+    #[test]
+    fn levels_are_sorted_descending_with_stable_ties() {
+        let propensity = propensity([30, 100, 55, 100, 0, 55]).enrich();
+        let levels: Vec<_> = propensity
+            .levels
+            .iter()
+            .map(|level| (level.key, level.level))
+            .collect();
+
+        assert_eq!(
+            levels,
+            [
+                ("sensibility", 100),
+                ("willingness", 100),
+                ("insight", 55),
+                ("charm", 55),
+                ("charisma", 30),
+                ("handicraft", 0),
+            ]
+        );
+        assert_eq!(propensity.total_level, 340);
+    }
+
+    #[test]
+    fn total_level_fits_when_every_trait_is_maxed() {
+        let propensity = propensity([100; 6]).enrich();
+
+        assert_eq!(propensity.total_level, 600);
+        assert_eq!(propensity.levels[0].display_name, "카리스마");
     }
 }
@@ -1,70 +1,237 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::{FilterQuery, fetch_character};
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct SetEffectInfoFull {
     set_count: i8,
     set_option: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct SetEffectInfo {
     set_name: String,
     total_set_count: i8,
     set_option_full: Vec<SetEffectInfoFull>,
+    // 다음 단계 세트 옵션 (필터링된 응답에만 포함)
+    #[serde(flatten, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    next: Option<NextSetOption>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct NextSetOption {
+    // 아직 활성화되지 않은 가장 낮은 단계의 옵션 (모두 활성화된 경우 null)
+    next_option: Option<SetEffectInfoFull>,
+    // 다음 옵션까지 더 장착해야 하는 장비 수
+    pieces_to_next: Option<i8>,
+}
+
+impl NextSetOption {
+    fn from_options(total_set_count: i8, options: &[SetEffectInfoFull]) -> Self {
+        let next_option = options
+            .iter()
+            .filter(|option| option.set_count > total_set_count)
+            .min_by_key(|option| option.set_count)
+            .cloned();
+
+        NextSetOption {
+            pieces_to_next: next_option
+                .as_ref()
+                .map(|option| option.set_count - total_set_count),
+            next_option,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct SetEffect {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     set_effect: Vec<SetEffectInfo>,
 }
 
+// 활성화된 세트 옵션만 남기고, 세트마다 다음 단계 옵션을 추가
+// 활성화된 옵션이 없는 세트는 include_inactive 인 경우에만 남긴다
+pub fn filter_active_set_effect(data: SetEffect, include_inactive: bool) -> SetEffect {
+    SetEffect {
+        date: data.date,
+        set_effect: data
+            .set_effect
+            .into_iter()
+            .filter_map(|set_info| {
+                let next = NextSetOption::from_options(
+                    set_info.total_set_count,
+                    &set_info.set_option_full,
+                );
+                let matched_options: Vec<SetEffectInfoFull> = set_info
+                    .set_option_full
+                    .into_iter()
+                    .filter(|option| option.set_count <= set_info.total_set_count)
+                    .collect();
+
+                if matched_options.is_empty() && !include_inactive {
+                    None
+                } else {
+                    Some(SetEffectInfo {
+                        set_name: set_info.set_name,
+                        total_set_count: set_info.total_set_count,
+                        set_option_full: matched_options,
+                        next: Some(next),
+                    })
+                }
+            })
+            .collect(),
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct SetEffectQuery {
+    // true 이면 활성화된 옵션이 없는 세트도 빈 옵션 목록으로 반환
+    #[serde(default)]
+    include_inactive: bool,
+}
+
+// v1: 활성화된 세트 옵션만 반환
+#[utoipa::path(
+    post,
+    path = "/getUserSetEffect",
+    tag = "character",
+    params(DateQuery, SetEffectQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = SetEffect))
+)]
 pub async fn get_user_set_effect(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<SetEffectQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<SetEffect>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "set-effect", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_effect: SetEffect = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        let filtered_data = SetEffect {
-            set_effect: user_effect
-                .set_effect
-                .into_iter()
-                .filter_map(|set_info| {
-                    let matched_options: Vec<SetEffectInfoFull> = set_info
-                        .set_option_full
-                        .into_iter()
-                        .filter(|option| option.set_count <= set_info.total_set_count)
-                        .collect();
-
-                    if matched_options.is_empty() {
-                        None
-                    } else {
-                        Some(SetEffectInfo {
-                            set_name: set_info.set_name,
-                            total_set_count: set_info.total_set_count,
-                            set_option_full: matched_options,
-                        })
-                    }
-                })
-                .collect(),
-        };
-
-        Ok(Json(filtered_data))
+) -> Result<Json<SetEffect>, AppError> {
+    let user_effect: SetEffect =
+        fetch_character(api_key, "set-effect", &user_ocid.ocid, &date.resolve()?).await?;
+
+    Ok(Json(filter_active_set_effect(
+        user_effect,
+        query.include_inactive,
+    )))
+}
+
+// v2: 전체 세트 옵션을 반환하고, ?filter=active 인 경우에만 필터링
+#[utoipa::path(
+    post,
+    path = "/v2/getUserSetEffect",
+    tag = "character",
+    params(DateQuery, FilterQuery, SetEffectQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = SetEffect))
+)]
+pub async fn get_user_set_effect_v2(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<FilterQuery>,
+    Query(set_effect_query): Query<SetEffectQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<SetEffect>, AppError> {
+    let user_effect: SetEffect =
+        fetch_character(api_key, "set-effect", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if query.is_active() {
+        Ok(Json(filter_active_set_effect(
+            user_effect,
+            set_effect_query.include_inactive,
+        )))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Ok(Json(user_effect))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn set_effect(sets: Value) -> SetEffect {
+        serde_json::from_value(json!({ "date": "2024-06-01T00:00+09:00", "set_effect": sets }))
+            .unwrap()
+    }
+
+    fn absolab(total_set_count: i8) -> Value {
+        json!({
+            "set_name": "앱솔랩스 세트",
+            "total_set_count": total_set_count,
+            "set_option_full": [
+                { "set_count": 2, "set_option": "최대 HP : +1500" },
+                { "set_count": 3, "set_option": "올스탯 : +30" },
+                { "set_count": 4, "set_option": "보스 몬스터 공격 시 데미지 : +10%" }
+            ]
+        })
+    }
+
+    fn filtered(total_set_count: i8) -> Value {
+        let data = filter_active_set_effect(set_effect(json!([absolab(total_set_count)])), false);
+        serde_json::to_value(data).unwrap()["set_effect"][0].clone()
+    }
+
+    #[test]
+    fn option_with_equal_set_count_is_active() {
+        let set = filtered(3);
+
+        assert_eq!(set["set_option_full"].as_array().unwrap().len(), 2);
+        assert_eq!(set["set_option_full"][1]["set_count"], 3);
+        assert_eq!(set["next_option"]["set_count"], 4);
+        assert_eq!(set["pieces_to_next"], 1);
+    }
+
+    #[test]
+    fn next_option_is_the_smallest_unachieved_tier() {
+        let options = [4, 2, 3].map(|set_count| SetEffectInfoFull {
+            set_count,
+            set_option: String::new(),
+        });
+        let next = NextSetOption::from_options(1, &options);
+
+        assert_eq!(next.next_option.map(|option| option.set_count), Some(2));
+        assert_eq!(next.pieces_to_next, Some(1));
+    }
+
+    #[test]
+    fn maxed_set_has_no_next_option() {
+        let set = filtered(4);
+
+        assert_eq!(set["set_option_full"].as_array().unwrap().len(), 3);
+        assert_eq!(set["next_option"], Value::Null);
+        assert_eq!(set["pieces_to_next"], Value::Null);
+    }
+
+    #[test]
+    fn inactive_sets_are_kept_only_when_requested() {
+        assert!(
+            filter_active_set_effect(set_effect(json!([absolab(1)])), false)
+                .set_effect
+                .is_empty()
+        );
+
+        let data = filter_active_set_effect(set_effect(json!([absolab(1)])), true);
+        let set = serde_json::to_value(data).unwrap()["set_effect"][0].clone();
+        assert_eq!(set["total_set_count"], 1);
+        assert_eq!(set["set_option_full"], json!([]));
+        assert_eq!(set["pieces_to_next"], 1);
+    }
+
+    #[test]
+    fn unfiltered_response_has_no_next_fields() {
+        let raw = serde_json::to_value(set_effect(json!([absolab(3)]))).unwrap();
+        let set = raw["set_effect"][0].as_object().unwrap();
+
+        assert!(!set.contains_key("next_option"));
+        assert!(!set.contains_key("pieces_to_next"));
     }
 }
@@ -1,39 +1,246 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::normalize::parse_option_value;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct Stat {
     stat_name: String,
     stat_value: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UserStatData {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     final_stat: Vec<Stat>,
 }
 
+// 숫자로 변환한 최종 스탯
+// 퍼센트 값은 "87.5%" → 87.5 처럼 % 없이 반환하고, 알 수 없는 스탯은 other 에 그대로 담는다
+#[derive(Serialize, Debug, Default, ToSchema)]
+pub struct FinalStat {
+    min_stat_attack: Option<i64>,
+    max_stat_attack: Option<i64>,
+    combat_power: Option<i64>,
+    str: Option<i64>,
+    dex: Option<i64>,
+    int: Option<i64>,
+    luk: Option<i64>,
+    hp: Option<i64>,
+    mp: Option<i64>,
+    attack_power: Option<i64>,
+    magic_power: Option<i64>,
+    damage: Option<f64>,
+    boss_damage: Option<f64>,
+    normal_monster_damage: Option<f64>,
+    final_damage: Option<f64>,
+    ignore_defense: Option<f64>,
+    crit_rate: Option<f64>,
+    crit_damage: Option<f64>,
+    cooldown_reduction_seconds: Option<f64>,
+    cooldown_reduction_rate: Option<f64>,
+    buff_duration: Option<f64>,
+    item_drop_rate: Option<f64>,
+    meso_obtain: Option<f64>,
+    stance: Option<f64>,
+    defense: Option<i64>,
+    speed: Option<i64>,
+    jump: Option<i64>,
+    attack_speed: Option<i64>,
+    star_force: Option<i64>,
+    arcane_force: Option<i64>,
+    authentic_force: Option<i64>,
+    other: Vec<Stat>,
+}
+
+impl FinalStat {
+    fn from_stats(stats: &[Stat]) -> Self {
+        let mut final_stat = FinalStat::default();
+
+        for stat in stats {
+            let Some(value) = parse_option_value(&stat.stat_value) else {
+                final_stat.other.push(stat.clone());
+                continue;
+            };
+            let integer = Some(value.round() as i64);
+            let number = Some(value);
+
+            match stat.stat_name.as_str() {
+                "최소 스탯공격력" => final_stat.min_stat_attack = integer,
+                "최대 스탯공격력" => final_stat.max_stat_attack = integer,
+                "전투력" => final_stat.combat_power = integer,
+                "STR" => final_stat.str = integer,
+                "DEX" => final_stat.dex = integer,
+                "INT" => final_stat.int = integer,
+                "LUK" => final_stat.luk = integer,
+                "HP" => final_stat.hp = integer,
+                "MP" => final_stat.mp = integer,
+                "공격력" => final_stat.attack_power = integer,
+                "마력" => final_stat.magic_power = integer,
+                "데미지" => final_stat.damage = number,
+                "보스 몬스터 데미지" => final_stat.boss_damage = number,
+                "일반 몬스터 데미지" => final_stat.normal_monster_damage = number,
+                "최종 데미지" => final_stat.final_damage = number,
+                "방어율 무시" => final_stat.ignore_defense = number,
+                "크리티컬 확률" => final_stat.crit_rate = number,
+                "크리티컬 데미지" => final_stat.crit_damage = number,
+                "재사용 대기시간 감소 (초)" => {
+                    final_stat.cooldown_reduction_seconds = number
+                }
+                "재사용 대기시간 감소 (%)" => final_stat.cooldown_reduction_rate = number,
+                "버프 지속시간" => final_stat.buff_duration = number,
+                "아이템 드롭률" => final_stat.item_drop_rate = number,
+                "메소 획득량" => final_stat.meso_obtain = number,
+                "스탠스" => final_stat.stance = number,
+                "방어력" => final_stat.defense = integer,
+                "이동속도" => final_stat.speed = integer,
+                "점프력" => final_stat.jump = integer,
+                "공격 속도" => final_stat.attack_speed = integer,
+                "스타포스" => final_stat.star_force = integer,
+                "아케인포스" => final_stat.arcane_force = integer,
+                "어센틱포스" => final_stat.authentic_force = integer,
+                _ => final_stat.other.push(stat.clone()),
+            }
+        }
+
+        final_stat
+    }
+}
+
+// ?normalized=true 응답 (원본 목록은 raw 에 유지)
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NormalizedUserStatData {
+    date: String,
+    final_stat: FinalStat,
+    raw: Vec<Stat>,
+}
+
+impl From<UserStatData> for NormalizedUserStatData {
+    fn from(data: UserStatData) -> Self {
+        Self {
+            final_stat: FinalStat::from_stats(&data.final_stat),
+            date: data.date,
+            raw: data.final_stat,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct UserStatQuery {
+    #[serde(default)]
+    normalized: bool,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(untagged)]
+pub enum UserStatResponse {
+    Raw(UserStatData),
+    Normalized(Box<NormalizedUserStatData>),
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserStatInfo",
+    tag = "character",
+    params(DateQuery, UserStatQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserStatResponse))
+)]
 pub async fn get_user_stat_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<UserStatQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UserStatData>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "stat", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_stat_data: UserStatData = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_stat_data))
+) -> Result<Json<UserStatResponse>, AppError> {
+    let user_stat: UserStatData =
+        fetch_character(api_key, "stat", &user_ocid.ocid, &date.resolve()?).await?;
+
+    if query.normalized {
+        Ok(Json(UserStatResponse::Normalized(Box::new(
+            user_stat.into(),
+        ))))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Ok(Json(UserStatResponse::Raw(user_stat)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat(name: &str, value: &str) -> Stat {
+        Stat {
+            stat_name: name.to_string(),
+            stat_value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_integers_with_thousands_separators() {
+        let final_stat = FinalStat::from_stats(&[
+            stat("전투력", "12,345,678"),
+            stat("STR", "4512"),
+            stat("최대 스탯공격력", "1,234,567.6"),
+        ]);
+
+        assert_eq!(final_stat.combat_power, Some(12345678));
+        assert_eq!(final_stat.str, Some(4512));
+        assert_eq!(final_stat.max_stat_attack, Some(1234568));
+        assert!(final_stat.other.is_empty());
+    }
+
+    #[test]
+    fn parses_percentages_and_seconds_without_unit() {
+        let final_stat = FinalStat::from_stats(&[
+            stat("보스 몬스터 데미지", "87.5%"),
+            stat("방어율 무시", "95.12"),
+            stat("재사용 대기시간 감소 (초)", "2"),
+            stat("재사용 대기시간 감소 (%)", "5%"),
+        ]);
+
+        assert_eq!(final_stat.boss_damage, Some(87.5));
+        assert_eq!(final_stat.ignore_defense, Some(95.12));
+        assert_eq!(final_stat.cooldown_reduction_seconds, Some(2.0));
+        assert_eq!(final_stat.cooldown_reduction_rate, Some(5.0));
+    }
+
+    #[test]
+    fn keeps_unknown_and_unparsable_stats_in_other() {
+        let final_stat = FinalStat::from_stats(&[
+            stat("상태이상 내성", "40"),
+            stat("STR", ""),
+            stat("DEX", "-"),
+        ]);
+
+        let names: Vec<_> = final_stat
+            .other
+            .iter()
+            .map(|stat| stat.stat_name.as_str())
+            .collect();
+        assert_eq!(names, ["상태이상 내성", "STR", "DEX"]);
+        assert_eq!(final_stat.str, None);
+    }
+
+    #[test]
+    fn normalized_response_keeps_raw_stats() {
+        let data = UserStatData {
+            date: "2024-06-01".to_string(),
+            final_stat: vec![stat("LUK", "3,000")],
+        };
+
+        let normalized = NormalizedUserStatData::from(data);
+        assert_eq!(normalized.final_stat.luk, Some(3000));
+        assert_eq!(normalized.raw.len(), 1);
+        assert_eq!(normalized.raw[0].stat_value, "3,000");
     }
 }
@@ -0,0 +1,134 @@
+use crate::api::character::request::fetch_character;
+use crate::api::date::DateQuery;
+use crate::api::error::{AppError, Section};
+use crate::api::query::Query;
+use crate::api::request::API;
+
+use super::character::UserOcid;
+use super::user_ability::Ability;
+use super::user_characeter_skill::CharacterLinkSkill;
+use super::user_default_info::UserDefaultData;
+use super::user_dojang::Dojang;
+use super::user_hexa_matrix::HexaMatrix;
+use super::user_hyper_stat_info::UserHyperStatData;
+use super::user_item_equipment::ItemEquipment;
+use super::user_propensity::Propensity;
+use super::user_set_effect::SetEffect;
+use super::user_stat_info::UserStatData;
+use super::user_symbol_equipment::Symbol;
+use super::user_v_matrix::{GroupedVMatrix, VMatrix};
+
+use axum::{Extension, http::StatusCode, response::Json};
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::Asia::Seoul;
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+// 캐릭터 프로필 화면에 필요한 정보를 한 번에 반환
+// 섹션별로 {"ok": ...} 또는 {"error": ...} 형태로 반환한다
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UserSummary {
+    // 조회에 사용한 날짜
+    date: String,
+    fetched_at: DateTime<FixedOffset>,
+    basic: Section<UserDefaultData>,
+    stat: Section<UserStatData>,
+    hyper_stat: Section<UserHyperStatData>,
+    ability: Section<Ability>,
+    item_equipment: Section<ItemEquipment>,
+    set_effect: Section<SetEffect>,
+    symbol_equipment: Section<Symbol>,
+    link_skill: Section<CharacterLinkSkill>,
+    vmatrix: Section<GroupedVMatrix>,
+    hexamatrix: Section<HexaMatrix>,
+    dojang: Section<Dojang>,
+    propensity: Section<Propensity>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserSummary",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UserSummary))
+)]
+pub async fn get_user_summary(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Json(user_ocid): Json<UserOcid>,
+) -> Result<Json<UserSummary>, AppError> {
+    let date = date.resolve()?;
+    let ocid = user_ocid.ocid.as_str();
+
+    // 모든 섹션을 동시에 요청 (가장 느린 요청 하나만큼만 기다린다)
+    // 레벨 제한이 있는 섹션(HEXA 매트릭스 등)이 실패해도 나머지 섹션은 반환한다
+    let (
+        basic,
+        stat,
+        hyper_stat,
+        ability,
+        item_equipment,
+        set_effect,
+        symbol_equipment,
+        link_skill,
+        vmatrix,
+        hexamatrix,
+        dojang,
+        propensity,
+    ) = tokio::join!(
+        fetch_character(api_key.clone(), "basic", ocid, &date),
+        fetch_character(api_key.clone(), "stat", ocid, &date),
+        fetch_character(api_key.clone(), "hyper-stat", ocid, &date),
+        fetch_character(api_key.clone(), "ability", ocid, &date),
+        fetch_character(api_key.clone(), "item-equipment", ocid, &date),
+        fetch_character(api_key.clone(), "set-effect", ocid, &date),
+        fetch_character(api_key.clone(), "symbol-equipment", ocid, &date),
+        fetch_character(api_key.clone(), "link-skill", ocid, &date),
+        fetch_character(api_key.clone(), "vmatrix", ocid, &date),
+        fetch_character(api_key.clone(), "hexamatrix", ocid, &date),
+        fetch_character(api_key.clone(), "dojang", ocid, &date),
+        fetch_character(api_key, "propensity", ocid, &date),
+    );
+
+    let summary = UserSummary {
+        date: date.to_string(),
+        fetched_at: Utc::now().with_timezone(&Seoul).fixed_offset(),
+        basic: basic.map(UserDefaultData::fill_age).into(),
+        stat: stat.into(),
+        hyper_stat: hyper_stat.into(),
+        ability: ability.into(),
+        item_equipment: item_equipment.into(),
+        set_effect: set_effect.into(),
+        symbol_equipment: symbol_equipment.map(Symbol::enrich).into(),
+        link_skill: link_skill.into(),
+        vmatrix: vmatrix.map(|vmatrix: VMatrix| vmatrix.into()).into(),
+        hexamatrix: hexamatrix.into(),
+        dojang: dojang.map(Dojang::enrich).into(),
+        propensity: propensity.map(Propensity::enrich).into(),
+    };
+
+    let fetched = [
+        summary.basic.is_ok(),
+        summary.stat.is_ok(),
+        summary.hyper_stat.is_ok(),
+        summary.ability.is_ok(),
+        summary.item_equipment.is_ok(),
+        summary.set_effect.is_ok(),
+        summary.symbol_equipment.is_ok(),
+        summary.link_skill.is_ok(),
+        summary.vmatrix.is_ok(),
+        summary.hexamatrix.is_ok(),
+        summary.dojang.is_ok(),
+        summary.propensity.is_ok(),
+    ];
+    if !fetched.contains(&true) {
+        return Err(AppError::Upstream {
+            status: StatusCode::BAD_GATEWAY,
+            message: "Failed to fetch every summary section".to_string(),
+        });
+    }
+
+    Ok(Json(summary))
+}
@@ -1,15 +1,75 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::compact::{CompactQuery, MaybeCompact};
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
+
+// 심볼 최대 레벨
+const ARCANE_MAX_LEVEL: i8 = 20;
+const AUTHENTIC_MAX_LEVEL: i8 = 11;
+
+// 지역별 일일 퀘스트로 얻는 심볼 성장치 (days_to_max 추정에 사용)
+const DAILY_GROWTH: [(&str, i32); 13] = [
+    ("소멸의 여로", 20),
+    ("츄츄 아일랜드", 20),
+    ("레헬른", 20),
+    ("아르카나", 20),
+    ("모라스", 20),
+    ("에스페라", 20),
+    ("세르니움", 20),
+    ("아르크스", 10),
+    ("오디움", 10),
+    ("도원경", 10),
+    ("아르테리아", 10),
+    ("카르시온", 10),
+    ("탈라하트", 10),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SymbolKind {
+    Arcane,
+    Authentic,
+}
+
+impl SymbolKind {
+    // 심볼 이름 앞부분으로 구분 ("아케인심볼 : 소멸의 여로", "그랜드 어센틱심볼 : 탈라하트")
+    fn from_name(name: &str) -> Option<Self> {
+        if name.contains("어센틱심볼") {
+            Some(SymbolKind::Authentic)
+        } else if name.contains("아케인심볼") {
+            Some(SymbolKind::Arcane)
+        } else {
+            None
+        }
+    }
+
+    fn max_level(self) -> i8 {
+        match self {
+            SymbolKind::Arcane => ARCANE_MAX_LEVEL,
+            SymbolKind::Authentic => AUTHENTIC_MAX_LEVEL,
+        }
+    }
+
+    // level 에서 다음 레벨로 올리는 데 필요한 성장치
+    fn require_growth(self, level: i32) -> i32 {
+        match self {
+            SymbolKind::Arcane => level * level + 11,
+            SymbolKind::Authentic => 9 * level * level + 20 * level,
+        }
+    }
+}
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct SymbolInfo {
     symbol_name: String,
     symbol_icon: String,
@@ -28,29 +88,237 @@ pub struct SymbolInfo {
     symbol_exp_rate: String,
     symbol_growth_count: i32,
     symbol_require_growth_count: i32,
+    // 다음 레벨까지 남은 성장치 (최대 레벨이면 0)
+    #[serde(skip_deserializing)]
+    remaining_growth: i32,
+    // 일일 퀘스트만으로 최대 레벨까지 걸리는 예상 일수 (알 수 없는 지역은 null)
+    #[serde(skip_deserializing)]
+    days_to_max: Option<i32>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl SymbolInfo {
+    // "아케인심볼 : 소멸의 여로" → "소멸의 여로"
+    fn region(&self) -> &str {
+        self.symbol_name
+            .split_once(':')
+            .map_or(self.symbol_name.as_str(), |(_, region)| region.trim())
+    }
+
+    // "+1,320" 형태의 포스 값 (숫자가 아니면 0)
+    fn force(&self) -> i64 {
+        self.symbol_force
+            .trim()
+            .trim_start_matches('+')
+            .replace(',', "")
+            .parse()
+            .unwrap_or(0)
+    }
+
+    // 남은 성장치 / 예상 일수 계산
+    // 최대 레벨 심볼은 symbol_require_growth_count 값과 관계없이 0 으로 처리한다
+    fn fill_growth(&mut self, kind: SymbolKind) {
+        if self.symbol_level >= kind.max_level() {
+            self.remaining_growth = 0;
+            self.days_to_max = Some(0);
+            return;
+        }
+
+        self.remaining_growth =
+            (self.symbol_require_growth_count - self.symbol_growth_count).max(0);
+
+        let growth_to_max = self.remaining_growth
+            + (i32::from(self.symbol_level) + 1..i32::from(kind.max_level()))
+                .map(|level| kind.require_growth(level))
+                .sum::<i32>();
+
+        self.days_to_max = DAILY_GROWTH
+            .iter()
+            .find(|(region, _)| *region == self.region())
+            .map(|(_, per_day)| (growth_to_max + per_day - 1) / per_day);
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct Symbol {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     symbol: Vec<SymbolInfo>,
+    // 아케인 / 어센틱 심볼로 나눈 목록
+    #[serde(skip_deserializing)]
+    arcane: Vec<SymbolInfo>,
+    #[serde(skip_deserializing)]
+    authentic: Vec<SymbolInfo>,
+    #[serde(skip_deserializing)]
+    total_arcane_force: i64,
+    #[serde(skip_deserializing)]
+    total_authentic_force: i64,
 }
 
+impl Symbol {
+    // 심볼별 남은 성장치와 종류별 목록 / 포스 합계를 채운다
+    pub fn enrich(mut self) -> Self {
+        for symbol in self.symbol.iter_mut() {
+            let Some(kind) = SymbolKind::from_name(&symbol.symbol_name) else {
+                continue;
+            };
+            symbol.fill_growth(kind);
+
+            match kind {
+                SymbolKind::Arcane => {
+                    self.total_arcane_force += symbol.force();
+                    self.arcane.push(symbol.clone());
+                }
+                SymbolKind::Authentic => {
+                    self.total_authentic_force += symbol.force();
+                    self.authentic.push(symbol.clone());
+                }
+            }
+        }
+
+        self
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserSymbolEquipment",
+    tag = "character",
+    params(DateQuery, CompactQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = Symbol))
+)]
 pub async fn get_user_symbol_equipment(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
+    Query(query): Query<CompactQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<Symbol>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "symbol-equipment", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_symbol: Symbol = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_symbol))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<MaybeCompact<Symbol>, AppError> {
+    let user_symbol: Symbol = fetch_character(
+        api_key,
+        "symbol-equipment",
+        &user_ocid.ocid,
+        &date.resolve()?,
+    )
+    .await?;
+
+    Ok(MaybeCompact {
+        data: user_symbol.enrich(),
+        compact: query.compact,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn symbol(name: &str, force: &str, level: i8, growth: i32, require: i32) -> SymbolInfo {
+        serde_json::from_value(json!({
+            "symbol_name": name,
+            "symbol_icon": "",
+            "symbol_force": force,
+            "symbol_level": level,
+            "symbol_str": "0",
+            "symbol_dex": "0",
+            "symbol_int": "0",
+            "symbol_luk": "0",
+            "symbol_hp": "0",
+            "symbol_drop_rate": null,
+            "symbol_meso_rate": null,
+            "symbol_exp_rate": null,
+            "symbol_growth_count": growth,
+            "symbol_require_growth_count": require,
+        }))
+        .unwrap()
+    }
+
+    fn filled(mut symbol: SymbolInfo) -> (i32, Option<i32>) {
+        let kind = SymbolKind::from_name(&symbol.symbol_name).unwrap();
+        symbol.fill_growth(kind);
+        (symbol.remaining_growth, symbol.days_to_max)
+    }
+
+    #[test]
+    fn kind_is_detected_from_name_prefix() {
+        assert_eq!(
+            SymbolKind::from_name("아케인심볼 : 소멸의 여로"),
+            Some(SymbolKind::Arcane)
+        );
+        assert_eq!(
+            SymbolKind::from_name("그랜드 어센틱심볼 : 탈라하트"),
+            Some(SymbolKind::Authentic)
+        );
+        assert_eq!(SymbolKind::from_name("심볼"), None);
+    }
+
+    #[test]
+    fn force_parses_signed_numbers_with_separators() {
+        assert_eq!(symbol("", "+1,320", 1, 0, 0).force(), 1320);
+        assert_eq!(symbol("", "110", 1, 0, 0).force(), 110);
+        assert_eq!(symbol("", "", 1, 0, 0).force(), 0);
+    }
+
+    #[test]
+    fn growth_to_max_includes_remaining_levels() {
+        // 1 → 20 레벨: 11 + (2..=19 레벨의 l² + 11) = 2678, 하루 20 → 134일
+        assert_eq!(
+            filled(symbol("아케인심볼 : 소멸의 여로", "+30", 1, 1, 12)),
+            (11, Some(134))
+        );
+        assert_eq!(
+            filled(symbol("아케인심볼 : 에스페라", "+210", 19, 300, 372)),
+            (72, Some(4))
+        );
+        assert_eq!(
+            filled(symbol("어센틱심볼 : 탈라하트", "+100", 10, 100, 1100)),
+            (1000, Some(100))
+        );
+    }
+
+    #[test]
+    fn max_level_and_odd_counts_never_go_negative() {
+        assert_eq!(
+            filled(symbol("아케인심볼 : 레헬른", "+220", 20, 2679, 0)),
+            (0, Some(0))
+        );
+        assert_eq!(
+            filled(symbol("어센틱심볼 : 세르니움", "+110", 11, 0, 0)),
+            (0, Some(0))
+        );
+        assert_eq!(
+            filled(symbol("아케인심볼 : 모라스", "+100", 5, 50, 36)).0,
+            0
+        );
+    }
+
+    #[test]
+    fn unknown_region_has_no_estimate() {
+        assert_eq!(
+            filled(symbol("아케인심볼 : 새 지역", "+30", 19, 0, 372)),
+            (372, None)
+        );
+    }
+
+    #[test]
+    fn enrich_splits_symbols_and_sums_force() {
+        let data = Symbol {
+            date: String::new(),
+            symbol: vec![
+                symbol("아케인심볼 : 소멸의 여로", "+220", 20, 0, 0),
+                symbol("아케인심볼 : 츄츄 아일랜드", "+1,100", 10, 0, 111),
+                symbol("어센틱심볼 : 세르니움", "+110", 11, 0, 0),
+            ],
+            arcane: Vec::new(),
+            authentic: Vec::new(),
+            total_arcane_force: 0,
+            total_authentic_force: 0,
+        }
+        .enrich();
+
+        assert_eq!(data.arcane.len(), 2);
+        assert_eq!(data.authentic.len(), 1);
+        assert_eq!(data.total_arcane_force, 1320);
+        assert_eq!(data.total_authentic_force, 110);
+        assert_eq!(data.symbol[1].remaining_growth, 111);
     }
 }
@@ -1,15 +1,21 @@
-use crate::api::character::request::request_parser;
+use crate::api::character::request::fetch_character;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
 
 use super::character::UserOcid;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, serde_as};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use utoipa::ToSchema;
 
 #[serde_as]
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct VMatrixInfo {
     slot_id: String,
     slot_level: i8,
@@ -26,28 +32,255 @@ pub struct VMatrixInfo {
     v_core_type: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
 pub struct VMatrix {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     character_v_core_equipment: Vec<VMatrixInfo>,
     character_v_matrix_remain_slot_upgrade_point: i8,
 }
 
+#[derive(Serialize, Debug, ToSchema)]
+pub struct VCore {
+    slot_id: String,
+    slot_level: i8,
+    v_core_name: String,
+    v_core_level: i8,
+}
+
+// 강화 코어 (강화되는 스킬 3개 포함)
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EnhancementCore {
+    slot_id: String,
+    slot_level: i8,
+    v_core_name: String,
+    v_core_level: i8,
+    skills: Vec<String>,
+}
+
+// 스킬별 강화 레벨 합계 (장착한 모든 강화 코어 기준)
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EnhancementSkill {
+    skill_name: String,
+    total_level: i32,
+    core_count: u32,
+}
+
+// 코어 종류별로 나눈 V 매트릭스
+#[derive(Serialize, Debug, ToSchema)]
+pub struct GroupedVMatrix {
+    date: String,
+    character_v_matrix_remain_slot_upgrade_point: i8,
+    skill_cores: Vec<VCore>,
+    enhancement_cores: Vec<EnhancementCore>,
+    special_cores: Vec<VCore>,
+    // 코어를 장착하지 않은 슬롯 수
+    empty_slot_count: u32,
+    // 강화 레벨이 높은 순
+    enhancement_skills: Vec<EnhancementSkill>,
+    // 넥슨 API 응답의 슬롯 목록
+    raw: Vec<VMatrixInfo>,
+}
+
+impl From<VMatrix> for GroupedVMatrix {
+    fn from(data: VMatrix) -> Self {
+        let mut grouped = GroupedVMatrix {
+            date: data.date,
+            character_v_matrix_remain_slot_upgrade_point: data
+                .character_v_matrix_remain_slot_upgrade_point,
+            skill_cores: Vec::new(),
+            enhancement_cores: Vec::new(),
+            special_cores: Vec::new(),
+            empty_slot_count: 0,
+            enhancement_skills: Vec::new(),
+            raw: Vec::new(),
+        };
+        let mut skill_levels: BTreeMap<String, (i32, u32)> = BTreeMap::new();
+
+        for info in &data.character_v_core_equipment {
+            // 빈 슬롯은 코어 이름이 null (DefaultOnNull 로 빈 문자열)
+            if info.v_core_name.trim().is_empty() {
+                grouped.empty_slot_count += 1;
+                continue;
+            }
+
+            let core = VCore {
+                slot_id: info.slot_id.clone(),
+                slot_level: info.slot_level,
+                v_core_name: info.v_core_name.clone(),
+                v_core_level: info.v_core_level,
+            };
+
+            match info.v_core_type.as_str() {
+                "Enhancement" => {
+                    let skills: Vec<String> = [
+                        &info.v_core_skill_1,
+                        &info.v_core_skill_2,
+                        &info.v_core_skill_3,
+                    ]
+                    .into_iter()
+                    .filter(|skill| !skill.trim().is_empty())
+                    .cloned()
+                    .collect();
+
+                    for skill in &skills {
+                        let (total_level, core_count) =
+                            skill_levels.entry(skill.clone()).or_default();
+                        *total_level += i32::from(info.v_core_level);
+                        *core_count += 1;
+                    }
+
+                    grouped.enhancement_cores.push(EnhancementCore {
+                        slot_id: core.slot_id,
+                        slot_level: core.slot_level,
+                        v_core_name: core.v_core_name,
+                        v_core_level: core.v_core_level,
+                        skills,
+                    });
+                }
+                "Special" => grouped.special_cores.push(core),
+                _ => grouped.skill_cores.push(core),
+            }
+        }
+
+        grouped.enhancement_skills = skill_levels
+            .into_iter()
+            .map(|(skill_name, (total_level, core_count))| EnhancementSkill {
+                skill_name,
+                total_level,
+                core_count,
+            })
+            .collect();
+        grouped
+            .enhancement_skills
+            .sort_by_key(|skill| Reverse(skill.total_level));
+        grouped.raw = data.character_v_core_equipment;
+
+        grouped
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUserVMatrix",
+    tag = "character",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = GroupedVMatrix))
+)]
 pub async fn get_user_v_matrix(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<VMatrix>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "vmatrix", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_v_matrix: VMatrix = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_v_matrix))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<Json<GroupedVMatrix>, AppError> {
+    let v_matrix: VMatrix =
+        fetch_character(api_key, "vmatrix", &user_ocid.ocid, &date.resolve()?).await?;
+
+    Ok(Json(v_matrix.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn slot(
+        id: &str,
+        name: Option<&str>,
+        level: i8,
+        kind: Option<&str>,
+        skills: [&str; 3],
+    ) -> Value {
+        let skill = |index: usize| (!skills[index].is_empty()).then_some(skills[index]);
+
+        json!({
+            "slot_id": id,
+            "slot_level": 0,
+            "v_core_name": name,
+            "v_core_level": level,
+            "v_core_skill_1": skill(0),
+            "v_core_skill_2": skill(1),
+            "v_core_skill_3": skill(2),
+            "v_core_type": kind,
+        })
+    }
+
+    fn fixture() -> VMatrix {
+        serde_json::from_value(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "character_v_matrix_remain_slot_upgrade_point": 3,
+            "character_v_core_equipment": [
+                slot("0", Some("레이지 업라이징"), 25, Some("Skill"), ["레이지 업라이징", "", ""]),
+                slot("1", Some("레이징 블로우 강화"), 50, Some("Enhancement"), ["레이징 블로우", "인사이징", "어드밴스드 콤보"]),
+                // 같은 스킬 조합을 다른 순서로 장착한 강화 코어
+                slot("2", Some("인사이징 강화"), 40, Some("Enhancement"), ["인사이징", "레이징 블로우", "어드밴스드 콤보"]),
+                slot("3", Some("패닉 강화"), 30, Some("Enhancement"), ["패닉", "레이징 블로우", ""]),
+                slot("4", Some("블리스"), 1, Some("Special"), ["블리스", "", ""]),
+                // 빈 슬롯 / 잠긴 슬롯
+                slot("5", None, 0, None, ["", "", ""]),
+                slot("6", Some(" "), 0, Some(""), ["", "", ""]),
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn groups_cores_by_type_and_counts_empty_slots() {
+        let grouped = GroupedVMatrix::from(fixture());
+
+        assert_eq!(grouped.skill_cores.len(), 1);
+        assert_eq!(grouped.skill_cores[0].v_core_name, "레이지 업라이징");
+        assert_eq!(grouped.enhancement_cores.len(), 3);
+        assert_eq!(grouped.special_cores.len(), 1);
+        assert_eq!(grouped.empty_slot_count, 2);
+        assert_eq!(grouped.character_v_matrix_remain_slot_upgrade_point, 3);
+    }
+
+    #[test]
+    fn enhancement_cores_list_boosted_skills_without_blanks() {
+        let grouped = GroupedVMatrix::from(fixture());
+
+        assert_eq!(
+            grouped.enhancement_cores[0].skills,
+            ["레이징 블로우", "인사이징", "어드밴스드 콤보"]
+        );
+        assert_eq!(
+            grouped.enhancement_cores[2].skills,
+            ["패닉", "레이징 블로우"]
+        );
+    }
+
+    #[test]
+    fn enhancement_levels_are_summed_per_skill() {
+        let grouped = GroupedVMatrix::from(fixture());
+        let skills: Vec<_> = grouped
+            .enhancement_skills
+            .iter()
+            .map(|skill| {
+                (
+                    skill.skill_name.as_str(),
+                    skill.total_level,
+                    skill.core_count,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            skills,
+            [
+                ("레이징 블로우", 120, 3),
+                ("어드밴스드 콤보", 90, 2),
+                ("인사이징", 90, 2),
+                ("패닉", 30, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn raw_keeps_every_slot() {
+        let grouped = GroupedVMatrix::from(fixture());
+
+        assert_eq!(grouped.raw.len(), 7);
+        assert_eq!(grouped.raw[5].v_core_name, "");
     }
 }
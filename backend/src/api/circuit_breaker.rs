@@ -0,0 +1,246 @@
+use crate::api::error::AppError;
+
+use serde::Serialize;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
+
+// 연속 실패 횟수가 이 값에 도달하면 차단
+const FAILURE_THRESHOLD: u32 = 5;
+// 차단 후 다시 시도하기까지 대기 시간
+const COOL_DOWN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    // 대기 시간이 지나 시험 요청 하나만 보내는 상태
+    HalfOpen { probing: bool },
+}
+
+// 헬스 체크에 노출하는 차단기 상태
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+// 넥슨 API 전체에 대한 서킷 브레이커
+// 점검 중처럼 모든 요청이 실패하는 동안에는 업스트림 호출 없이 바로 503으로 응답한다
+pub struct CircuitBreaker {
+    state: Mutex<State>,
+    threshold: u32,
+    cool_down: Duration,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(FAILURE_THRESHOLD, COOL_DOWN)
+    }
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, cool_down: Duration) -> Self {
+        Self {
+            state: Mutex::new(State::Closed { failures: 0 }),
+            threshold: threshold.max(1),
+            cool_down,
+        }
+    }
+
+    // 요청을 보내도 되는지 확인
+    // 반환된 허가로 요청 하나 (재시도 포함) 의 결과를 한 번만 기록한다
+    pub fn check(&self) -> Result<BreakerPermit<'_>, AppError> {
+        let mut state = self.lock();
+
+        let probe = match *state {
+            State::Closed { .. } => false,
+            State::Open { until } => {
+                let now = Instant::now();
+                if now < until {
+                    return Err(AppError::CircuitOpen {
+                        retry_after: (until - now).as_secs().max(1),
+                    });
+                }
+
+                // 대기 시간이 지나면 이 요청을 시험 요청으로 사용
+                *state = State::HalfOpen { probing: true };
+                true
+            }
+            State::HalfOpen { probing: true } => {
+                return Err(AppError::CircuitOpen { retry_after: 1 });
+            }
+            State::HalfOpen { probing: false } => {
+                *state = State::HalfOpen { probing: true };
+                true
+            }
+        };
+
+        Ok(BreakerPermit {
+            breaker: self,
+            probe,
+            recorded: false,
+        })
+    }
+
+    fn record_success(&self) {
+        *self.lock() = State::Closed { failures: 0 };
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.lock();
+
+        *state = match *state {
+            State::Closed { failures } if failures + 1 < self.threshold => State::Closed {
+                failures: failures + 1,
+            },
+            _ => State::Open {
+                until: Instant::now() + self.cool_down,
+            },
+        };
+    }
+
+    // 결과 없이 끝난 시험 요청 (클라이언트 연결 종료 등)
+    // 바로 다음 요청이 다시 시험 요청이 될 수 있도록 대기 시간이 끝난 차단 상태로 되돌린다
+    fn release_probe(&self) {
+        let mut state = self.lock();
+        if *state == (State::HalfOpen { probing: true }) {
+            *state = State::Open {
+                until: Instant::now(),
+            };
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn state(&self) -> BreakerState {
+        let state = self.lock();
+
+        match *state {
+            State::Closed { .. } => BreakerState::Closed,
+            State::Open { until } if Instant::now() < until => BreakerState::Open,
+            State::Open { .. } | State::HalfOpen { .. } => BreakerState::HalfOpen,
+        }
+    }
+}
+
+// 요청 하나에 대한 차단기 허가
+// 결과를 기록하지 않고 버려진 시험 요청은 drop 에서 시험 상태를 해제한다
+pub struct BreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    recorded: bool,
+}
+
+impl BreakerPermit<'_> {
+    pub fn success(mut self) {
+        self.recorded = true;
+        self.breaker.record_success();
+    }
+
+    pub fn failure(mut self) {
+        self.recorded = true;
+        self.breaker.record_failure();
+    }
+}
+
+impl Drop for BreakerPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.release_probe();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fail(breaker: &CircuitBreaker) {
+        breaker.check().unwrap().failure();
+    }
+
+    #[test]
+    fn opens_after_threshold_failures() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+
+        fail(&breaker);
+        fail(&breaker);
+        assert_eq!(breaker.state(), BreakerState::Closed);
+
+        fail(&breaker);
+        assert_eq!(breaker.state(), BreakerState::Open);
+        assert!(matches!(
+            breaker.check(),
+            Err(AppError::CircuitOpen { retry_after }) if retry_after > 1
+        ));
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        fail(&breaker);
+        breaker.check().unwrap().success();
+        fail(&breaker);
+        assert_eq!(breaker.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn half_open_allows_single_probe_then_closes() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+
+        fail(&breaker);
+        assert_eq!(breaker.state(), BreakerState::HalfOpen);
+
+        let probe = breaker.check().unwrap();
+        assert!(matches!(
+            breaker.check(),
+            Err(AppError::CircuitOpen { retry_after: 1 })
+        ));
+
+        probe.success();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+        assert!(breaker.check().is_ok());
+    }
+
+    #[test]
+    fn failed_probe_reopens() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(60));
+        *breaker.lock() = State::Open {
+            until: Instant::now(),
+        };
+
+        breaker.check().unwrap().failure();
+        assert_eq!(breaker.state(), BreakerState::Open);
+    }
+
+    #[test]
+    fn dropped_probe_releases_half_open() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        fail(&breaker);
+
+        drop(breaker.check().unwrap());
+
+        // 다음 요청이 다시 시험 요청이 된다
+        let probe = breaker.check().unwrap();
+        probe.success();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn dropped_permit_in_closed_state_changes_nothing() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        fail(&breaker);
+
+        drop(breaker.check().unwrap());
+        fail(&breaker);
+        assert_eq!(breaker.state(), BreakerState::Open);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::api::cache::{ResponseCache, send_cached};
+use crate::api::circuit_breaker::CircuitBreaker;
+use crate::api::config::Config;
+use crate::api::date::QueryDate;
+use crate::api::error::AppError;
+use crate::api::key_pool::KeyPool;
+use crate::api::region::{Region, current_region};
+use crate::api::upstream::{parse_json, ready_response, upstream_error};
+
+use reqwest::Client;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+// 넥슨 API의 "유효하지 않은 식별자" 오류 코드 (존재하지 않는 닉네임)
+const INVALID_IDENTIFIER: &str = "OPENAPI00003";
+
+#[derive(Deserialize)]
+struct OcidResponse {
+    ocid: String,
+}
+
+// 넥슨 Open API 클라이언트
+// API 키 선택, 호출 한도, 재시도, 서킷 브레이커, 응답 캐시를 거쳐 넥슨 API를 호출한다
+pub struct NexonClient {
+    // 넥슨 API 키 목록 (요청마다 돌아가며 사용, 키마다 초당 호출 한도 적용)
+    pub keys: KeyPool,
+    // 넥슨 API 주소 (테스트용 모의 서버로 바꿀 수 있다)
+    pub base_url: String,
+    // 기본 지역 (x-melog-region 헤더로 요청마다 바꿀 수 있다)
+    pub region: Region,
+    // 넥슨 API 공용 클라이언트 (커넥션 재사용, x-nxopen-api-key 헤더는 요청마다 추가)
+    pub client: Client,
+    pub breaker: CircuitBreaker,
+    pub cache: ResponseCache,
+    // 일시적인 오류 (연결 실패, 타임아웃, 5xx) 재시도 횟수와 첫 대기 시간 (설정의 [retry], 테스트에서 바꿀 수 있다)
+    pub max_retries: u32,
+    pub base_delay: Duration,
+}
+
+impl NexonClient {
+    // 생성자
+    // API 키가 헤더 값으로 사용할 수 없는 문자열이면 실패
+    pub fn new(config: &Config) -> Result<Self, Box<dyn std::error::Error>> {
+        let client = Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.timeout)
+            .pool_max_idle_per_host(32)
+            .pool_idle_timeout(Duration::from_secs(90))
+            .build()?;
+
+        Ok(Self {
+            keys: KeyPool::new(&config.api_keys, config.rate_limit)?,
+            base_url: config.nexon_base_url.clone(),
+            region: config.region,
+            client,
+            breaker: CircuitBreaker::default(),
+            cache: ResponseCache::new(config.cache_ttl, config.cache_max_entries),
+            max_retries: config.retry.max_retries,
+            base_delay: config.retry.base_delay,
+        })
+    }
+
+    // 현재 요청의 지역 기준 넥슨 API 경로의 전체 URL
+    // ("character/basic" → "{base_url}/maplestory/v1/character/basic", MSEA 는 maplestorysea/v1)
+    pub fn url(&self, path: &str) -> String {
+        format!(
+            "{}/{}/{}",
+            self.base_url,
+            current_region().api_prefix(),
+            path
+        )
+    }
+
+    // 닉네임으로 ocid 조회
+    pub async fn get_ocid(&self, nick_name: &str) -> Result<String, AppError> {
+        // 닉네임은 쿼리 파라미터로 인코딩
+        let request = self
+            .client
+            .get(self.url("id"))
+            .query(&[("character_name", nick_name)]);
+
+        // 캐시 키: (닉네임, 경로, 빈 날짜), 존재하지 않는 닉네임 (오류 응답) 은 저장하지 않는다
+        let key = (nick_name.to_string(), "id".to_string(), String::new());
+        let response = send_cached(self, key, request).await?;
+
+        if response.status().is_success() {
+            let body: OcidResponse = parse_json(response).await?;
+
+            Ok(body.ocid)
+        } else {
+            // 존재하지 않는 닉네임 (OPENAPI00003) 만 404, 키 거부 / 파라미터 오류 등은 그대로 변환
+            match upstream_error(response).await {
+                AppError::Nexon { name, .. } if name == INVALID_IDENTIFIER => {
+                    Err(AppError::OcidNotFound)
+                }
+                error => Err(error),
+            }
+        }
+    }
+
+    // ocid / 날짜 기준 조회 후 응답을 T로 변환 ("character/basic", "user/union" 등)
+    // 기본 날짜로 조회했는데 데이터가 아직 준비되지 않은 경우에만 전날 데이터로 다시 조회한다
+    pub async fn get_character<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        ocid: &str,
+        date: &QueryDate,
+    ) -> Result<T, AppError> {
+        // 빈 ocid를 넥슨 API에 보내면 알기 어려운 400 오류가 반환되므로 미리 거절
+        if ocid.trim().is_empty() {
+            return Err(AppError::OcidNotFound);
+        }
+
+        let mut response = self.send_character_request(path, ocid, date).await?;
+        // 직접 지정한 날짜는 다른 날짜로 바꾸지 않고 넥슨 API 오류를 그대로 반환
+        if let Some(previous) = date.fallback() {
+            response = match ready_response(response).await? {
+                Some(response) => response,
+                None => self.send_character_request(path, ocid, &previous).await?,
+            };
+        }
+
+        // 응답 결과 확인
+        if response.status().is_success() {
+            parse_json(response).await
+        } else {
+            Err(upstream_error(response).await)
+        }
+    }
+
+    async fn send_character_request(
+        &self,
+        path: &str,
+        ocid: &str,
+        date: &str,
+    ) -> Result<reqwest::Response, AppError> {
+        let request = self
+            .client
+            .get(self.url(path))
+            .query(&[("ocid", ocid), ("date", date)]);
+
+        // 캐시 키: (ocid, 경로, 날짜)
+        let key = (ocid.to_string(), path.to_string(), date.to_string());
+
+        send_cached(self, key, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(api_key: &str) -> Result<NexonClient, Box<dyn std::error::Error>> {
+        let mut config = Config::load_from([], Some("test-api-key".to_string())).unwrap();
+        // 설정을 거치지 않고 만든 키 목록도 생성자에서 확인한다
+        config.api_keys = vec![api_key.to_string()];
+        config.nexon_base_url = "http://127.0.0.1:1".to_string();
+        NexonClient::new(&config)
+    }
+
+    #[test]
+    fn url_uses_base_url_and_region_prefix() {
+        let client = client("test-api-key").unwrap();
+
+        assert_eq!(
+            client.url("character/basic"),
+            "http://127.0.0.1:1/maplestory/v1/character/basic"
+        );
+    }
+
+    #[test]
+    fn retry_settings_come_from_config() {
+        let mut config = Config::load_from([], Some("test-api-key".to_string())).unwrap();
+        config.retry.max_retries = 0;
+        config.retry.base_delay = Duration::from_millis(5);
+        let client = NexonClient::new(&config).unwrap();
+
+        assert_eq!(client.max_retries, 0);
+        assert_eq!(client.base_delay, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn rejects_key_that_is_not_a_header_value() {
+        assert!(client("bad\nkey").is_err());
+    }
+
+    #[tokio::test]
+    async fn empty_ocid_is_rejected_without_request() {
+        // 연결할 수 없는 주소이므로 요청을 보냈다면 Request 오류가 된다
+        let client = client("test-api-key").unwrap();
+        let result = client
+            .get_character::<serde_json::Value>("character/basic", " ", &QueryDate::latest())
+            .await;
+
+        assert!(matches!(result, Err(AppError::OcidNotFound)));
+    }
+}
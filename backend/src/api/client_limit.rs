@@ -0,0 +1,324 @@
+use crate::api::demo::PUBLIC_PATHS;
+use crate::api::error::AppError;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use dashmap::DashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// 클라이언트 식별 헤더 (프론트엔드가 탭마다 생성, 요청하는 쪽에서 정하는 값이므로 접속 IP 와 함께 사용)
+const CLIENT_HEADER: &str = "uuid";
+const WINDOW: Duration = Duration::from_secs(60);
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    // 경과 시간만큼 토큰을 채운 뒤 하나를 사용
+    // 토큰이 없으면 다음 토큰까지 남은 시간을 반환한다
+    fn take(&mut self, capacity: f64, now: Instant) -> Result<(), Duration> {
+        let per_second = capacity / WINDOW.as_secs_f64();
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_second).min(capacity);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        Err(Duration::from_secs_f64((1.0 - self.tokens) / per_second))
+    }
+
+    // 마지막 요청 이후 토큰이 가득 찰 만큼 지난 버킷 (지워도 결과가 같다)
+    fn is_idle(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.updated_at) >= WINDOW
+    }
+}
+
+// 키(클라이언트, IP 등)별 분당 요청 수 제한
+pub struct ClientLimiter {
+    buckets: DashMap<String, Bucket>,
+    per_minute: f64,
+    max_clients: usize,
+}
+
+impl ClientLimiter {
+    pub fn new(per_minute: u32, max_clients: usize) -> Self {
+        Self {
+            buckets: DashMap::new(),
+            per_minute: f64::from(per_minute.max(1)),
+            max_clients: max_clients.max(1),
+        }
+    }
+
+    pub(crate) fn check(&self, client: &str) -> Result<(), AppError> {
+        let now = Instant::now();
+
+        if !self.buckets.contains_key(client) && self.buckets.len() >= self.max_clients {
+            self.evict(now);
+        }
+
+        let mut bucket = self
+            .buckets
+            .entry(client.to_string())
+            .or_insert_with(|| Bucket {
+                tokens: self.per_minute,
+                updated_at: now,
+            });
+
+        bucket
+            .take(self.per_minute, now)
+            .map_err(|wait| AppError::RateLimited {
+                retry_after: wait.as_secs().max(1),
+            })
+    }
+
+    // 토큰이 다시 가득 찬 버킷을 지우고, 그래도 가득 차 있으면 가장 오래된 버킷을 제거
+    fn evict(&self, now: Instant) {
+        self.buckets.retain(|_, bucket| !bucket.is_idle(now));
+
+        if self.buckets.len() < self.max_clients {
+            return;
+        }
+
+        let oldest = self
+            .buckets
+            .iter()
+            .min_by_key(|bucket| bucket.updated_at)
+            .map(|bucket| bucket.key().clone());
+        if let Some(oldest) = oldest {
+            self.buckets.remove(&oldest);
+        }
+    }
+}
+
+// 클라이언트별 / IP별 분당 요청 수 제한
+// 한 클라이언트가 넥슨 API 호출 한도를 모두 사용하지 못하도록 한다
+// uuid 헤더는 요청마다 바꿀 수 있으므로 (IP, uuid) 단위 한도와 별도로 IP 전체 한도를 둔다
+// (한도를 0 으로 설정한 쪽은 제한하지 않음)
+pub struct ClientLimits {
+    clients: Option<ClientLimiter>,
+    ips: Option<ClientLimiter>,
+}
+
+impl ClientLimits {
+    pub fn new(per_client: u32, per_ip: u32, max_clients: usize) -> Self {
+        Self {
+            clients: (per_client > 0).then(|| ClientLimiter::new(per_client, max_clients)),
+            ips: (per_ip > 0).then(|| ClientLimiter::new(per_ip, max_clients)),
+        }
+    }
+
+    // 클라이언트 한도를 먼저 확인해, 한 탭이 보낸 초과 요청이 같은 IP 의 한도를 쓰지 않도록 한다
+    fn check(&self, client: &ClientId) -> Result<(), AppError> {
+        if let Some(clients) = &self.clients {
+            clients.check(&client.key())?;
+        }
+
+        match &self.ips {
+            Some(ips) => ips.check(&client.ip.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+// 요청한 클라이언트 (접속 IP, uuid 헤더)
+#[derive(Debug, PartialEq)]
+struct ClientId {
+    ip: IpAddr,
+    uuid: Option<String>,
+}
+
+impl ClientId {
+    fn key(&self) -> String {
+        match &self.uuid {
+            Some(uuid) => format!("{}/{uuid}", self.ip),
+            None => self.ip.to_string(),
+        }
+    }
+}
+
+// 요청한 클라이언트 식별 (접속 IP 를 알 수 없으면 제한하지 않는다)
+fn client_id(request: &Request) -> Option<ClientId> {
+    let ConnectInfo(addr) = request.extensions().get::<ConnectInfo<SocketAddr>>()?;
+    let uuid = request
+        .headers()
+        .get(CLIENT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|uuid| !uuid.is_empty())
+        .map(str::to_string);
+
+    Some(ClientId {
+        ip: addr.ip(),
+        uuid,
+    })
+}
+
+// 클라이언트별 요청 수 제한 미들웨어
+// 헬스 체크 / 메트릭 / 문서 경로는 제한하지 않는다
+pub async fn client_limit(
+    State(limits): State<Arc<ClientLimits>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if PUBLIC_PATHS.contains(&request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    if let Some(client) = client_id(&request)
+        && let Err(error) = limits.check(&client)
+    {
+        return error.into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::{Router, middleware, routing::get};
+    use tower::ServiceExt;
+
+    #[test]
+    fn bucket_refills_over_the_window() {
+        let now = Instant::now();
+        let mut bucket = Bucket {
+            tokens: 2.0,
+            updated_at: now,
+        };
+
+        assert!(bucket.take(2.0, now).is_ok());
+        assert!(bucket.take(2.0, now).is_ok());
+        // 분당 2회이므로 다음 토큰까지 30초
+        assert_eq!(bucket.take(2.0, now), Err(Duration::from_secs(30)));
+        assert!(bucket.take(2.0, now + Duration::from_secs(30)).is_ok());
+        assert!(!bucket.is_idle(now + Duration::from_secs(59)));
+        assert!(bucket.is_idle(now + Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn limits_each_client_separately() {
+        let limiter = ClientLimiter::new(2, 10);
+
+        assert!(limiter.check("a").is_ok());
+        assert!(limiter.check("a").is_ok());
+        assert!(matches!(
+            limiter.check("a"),
+            // 호출 사이에 흐른 시간만큼 30초보다 조금 짧을 수 있다
+            Err(AppError::RateLimited {
+                retry_after: 29..=30
+            })
+        ));
+        assert!(limiter.check("b").is_ok());
+    }
+
+    #[test]
+    fn evicts_oldest_client_when_full() {
+        let limiter = ClientLimiter::new(1, 2);
+
+        limiter.check("a").unwrap();
+        limiter.check("b").unwrap();
+        limiter.check("c").unwrap();
+
+        assert_eq!(limiter.buckets.len(), 2);
+        assert!(!limiter.buckets.contains_key("a"));
+        // 제거된 클라이언트는 새 버킷으로 다시 시작한다
+        assert!(limiter.check("a").is_ok());
+    }
+
+    fn request(ip: &str, uuid: Option<&str>) -> Request {
+        let addr: SocketAddr = format!("{ip}:4000").parse().unwrap();
+        let mut request = Request::get("/getOcid");
+        if let Some(uuid) = uuid {
+            request = request.header(CLIENT_HEADER, uuid);
+        }
+        let mut request = request.body(Body::empty()).unwrap();
+        request.extensions_mut().insert(ConnectInfo(addr));
+        request
+    }
+
+    #[test]
+    fn identifies_client_by_ip_and_uuid() {
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+
+        let client = client_id(&request("10.0.0.1", Some(" tab-1 "))).unwrap();
+        assert_eq!(
+            client,
+            ClientId {
+                ip,
+                uuid: Some("tab-1".to_string())
+            }
+        );
+        assert_eq!(client.key(), "10.0.0.1/tab-1");
+        assert_eq!(
+            client_id(&request("10.0.0.1", Some(" "))).unwrap().key(),
+            "10.0.0.1"
+        );
+        assert_eq!(
+            client_id(&request("10.0.0.1", None)).unwrap().key(),
+            "10.0.0.1"
+        );
+        // 접속 IP 없이 uuid 만으로는 식별하지 않는다
+        let mut request = request("10.0.0.1", Some("tab-1"));
+        request.extensions_mut().remove::<ConnectInfo<SocketAddr>>();
+        assert!(client_id(&request).is_none());
+    }
+
+    #[tokio::test]
+    async fn rotating_uuids_from_one_ip_are_limited() {
+        let limits = Arc::new(ClientLimits::new(2, 3, 100));
+        let app = Router::new()
+            .route("/getOcid", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(limits, client_limit));
+        let status = |ip: &'static str, uuid: String| {
+            let app = app.clone();
+            async move {
+                app.oneshot(request(ip, Some(&uuid)))
+                    .await
+                    .unwrap()
+                    .status()
+            }
+        };
+
+        // uuid 마다 클라이언트 한도 (2회) 안이지만 같은 IP 에서 4번째 요청은 IP 한도 (3회) 를 넘는다
+        for n in 0..3 {
+            assert_eq!(status("10.0.0.1", format!("tab-{n}")).await, StatusCode::OK);
+        }
+        assert_eq!(
+            status("10.0.0.1", "tab-3".to_string()).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // 다른 IP 는 영향을 받지 않는다
+        assert_eq!(
+            status("10.0.0.2", "tab-0".to_string()).await,
+            StatusCode::OK
+        );
+    }
+
+    #[test]
+    fn client_limit_is_checked_before_ip_limit() {
+        let limits = ClientLimits::new(1, 2, 100);
+        let client = |uuid: &str| ClientId {
+            ip: "10.0.0.1".parse().unwrap(),
+            uuid: Some(uuid.to_string()),
+        };
+
+        limits.check(&client("a")).unwrap();
+        // 클라이언트 한도에서 거절된 요청은 IP 한도를 쓰지 않는다
+        assert!(limits.check(&client("a")).is_err());
+        assert!(limits.check(&client("b")).is_ok());
+        assert!(limits.check(&client("c")).is_err());
+    }
+}
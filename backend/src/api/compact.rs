@@ -0,0 +1,212 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use utoipa::IntoParams;
+
+// compact 응답 스키마 버전
+// 컬럼 순서는 필드 이름의 사전순으로 고정되며, 순서나 구성이 바뀌면 버전을 올린다
+pub const COMPACT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct CompactQuery {
+    #[serde(default)]
+    pub compact: bool,
+}
+
+// 객체 배열을 columns + rows 형태로 표현한 테이블
+#[derive(Serialize, Debug)]
+pub struct CompactTable {
+    columns: Vec<String>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl CompactTable {
+    // 모든 원소가 같은 키를 가진 객체인 배열만 테이블로 변환
+    // 원소마다 키 구성이 다르면 (선택 필드가 일부 원소에만 있는 경우 등) 빈 값과 null 을 구분할 수 없으므로
+    // 테이블로 바꾸지 않고 원래 배열을 그대로 반환한다
+    fn from_array(items: &[Value]) -> Option<Self> {
+        let first = items.first()?.as_object()?;
+        let columns: Vec<String> = first.keys().cloned().collect();
+
+        let mut rows = Vec::with_capacity(items.len());
+        for item in items {
+            let object = item.as_object()?;
+            if object.len() != columns.len() {
+                return None;
+            }
+
+            let row = columns
+                .iter()
+                .map(|column| object.get(column).cloned())
+                .collect::<Option<Vec<Value>>>()?;
+            rows.push(row);
+        }
+
+        Some(Self { columns, rows })
+    }
+}
+
+// 최상위 필드 중 객체 배열인 값을 CompactTable로 치환
+pub fn to_compact<T: Serialize>(data: &T) -> Result<Value, serde_json::Error> {
+    let value = serde_json::to_value(data)?;
+
+    let Value::Object(fields) = value else {
+        return Ok(value);
+    };
+
+    let mut compacted = Map::new();
+    compacted.insert(
+        "schema_version".to_string(),
+        Value::from(COMPACT_SCHEMA_VERSION),
+    );
+
+    for (key, field) in fields {
+        let field = match field {
+            Value::Array(items) => match CompactTable::from_array(&items) {
+                Some(table) => serde_json::to_value(table)?,
+                None => Value::Array(items),
+            },
+            other => other,
+        };
+        compacted.insert(key, field);
+    }
+
+    Ok(Value::Object(compacted))
+}
+
+// compact 여부에 따라 일반 JSON 또는 compact JSON으로 응답
+pub struct MaybeCompact<T> {
+    pub data: T,
+    pub compact: bool,
+}
+
+impl<T: Serialize> IntoResponse for MaybeCompact<T> {
+    fn into_response(self) -> Response {
+        if !self.compact {
+            return Json(self.data).into_response();
+        }
+
+        match to_compact(&self.data) {
+            Ok(value) => Json(value).into_response(),
+            Err(_) => Json(self.data).into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::character::user_item_equipment::ItemEquipment;
+    use crate::api::character::user_symbol_equipment::Symbol;
+    use serde_json::json;
+
+    // compact 테이블을 다시 객체 배열로 복원
+    fn expand(table: &Value) -> Value {
+        let columns = table["columns"].as_array().unwrap();
+        let rows = table["rows"].as_array().unwrap();
+
+        rows.iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.as_str().unwrap().to_string())
+                    .zip(row.as_array().unwrap().iter().cloned())
+                    .collect::<Map<String, Value>>()
+            })
+            .map(Value::Object)
+            .collect()
+    }
+
+    // compact 응답을 원래 JSON 으로 복원 (테이블이 아닌 필드는 그대로)
+    fn restore(compact: &Value) -> Value {
+        compact
+            .as_object()
+            .unwrap()
+            .iter()
+            .filter(|(key, _)| *key != "schema_version")
+            .map(|(key, field)| {
+                let field = match field.get("columns") {
+                    Some(_) => expand(field),
+                    None => field.clone(),
+                };
+                (key.clone(), field)
+            })
+            .collect::<Map<String, Value>>()
+            .into()
+    }
+
+    // 실제 응답 구조체를 compact 로 변환한 뒤 복원하면 일반 JSON 응답과 같아야 한다
+    fn assert_round_trip<T: Serialize>(data: &T, tables: &[&str]) -> Value {
+        let compact = to_compact(data).unwrap();
+
+        assert_eq!(compact["schema_version"], COMPACT_SCHEMA_VERSION);
+        for table in tables {
+            assert!(compact[*table]["columns"].is_array(), "{table}");
+        }
+        assert_eq!(restore(&compact), serde_json::to_value(data).unwrap());
+
+        compact
+    }
+
+    #[test]
+    fn round_trips_item_equipment_response() {
+        let equipment: ItemEquipment =
+            serde_json::from_str(include_str!("../../tests/fixtures/item_equipment.json")).unwrap();
+
+        let compact = assert_round_trip(&equipment, &["item_equipment"]);
+
+        assert_eq!(
+            compact["item_equipment"]["rows"].as_array().unwrap().len(),
+            24
+        );
+        // 컬럼은 필드 이름의 사전순
+        let columns = compact["item_equipment"]["columns"].as_array().unwrap();
+        assert!(
+            columns
+                .windows(2)
+                .all(|pair| pair[0].as_str() < pair[1].as_str())
+        );
+        // 프리셋 / 드래곤 / 메카닉 장비가 없는 응답은 필드가 생략된다
+        assert!(compact.get("item_equipment_preset_1").is_none());
+    }
+
+    #[test]
+    fn round_trips_symbol_response() {
+        let symbol: Symbol =
+            serde_json::from_str(include_str!("../../tests/fixtures/symbol_equipment.json"))
+                .unwrap();
+
+        let compact = assert_round_trip(&symbol.enrich(), &["symbol", "arcane", "authentic"]);
+
+        assert_eq!(compact["symbol"]["rows"].as_array().unwrap().len(), 9);
+        assert_eq!(compact["arcane"]["rows"].as_array().unwrap().len(), 6);
+        assert_eq!(compact["total_authentic_force"], 220);
+    }
+
+    #[test]
+    fn keeps_arrays_with_different_keys() {
+        let data = json!({
+            "item": [
+                { "name": "a", "level": 1 },
+                { "name": "b" }
+            ],
+            "other": [
+                { "name": "a", "level": 1 },
+                { "name": "b", "grade": "레어" }
+            ],
+            "empty": [],
+            "tags": ["a", "b"]
+        });
+
+        let compact = to_compact(&data).unwrap();
+
+        assert_eq!(compact["item"], data["item"]);
+        assert_eq!(compact["other"], data["other"]);
+        assert_eq!(compact["empty"], json!([]));
+        // 객체가 아닌 배열은 그대로
+        assert_eq!(compact["tags"], data["tags"]);
+    }
+}
@@ -0,0 +1,78 @@
+use tower_http::compression::{
+    CompressionLayer,
+    predicate::{NotForContentType, Predicate, SizeAbove},
+};
+
+// Accept-Encoding 에 따라 gzip / brotli 압축 (min_size 바이트 이하의 응답은 그대로)
+pub fn compression_layer(min_size: u16) -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new().compress_when(
+        SizeAbove::new(min_size)
+            .and(NotForContentType::GRPC)
+            .and(NotForContentType::IMAGES)
+            .and(NotForContentType::SSE),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{Body, to_bytes};
+    use axum::http::{Request, Response, header};
+    use axum::{Json, Router, routing::get};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/large",
+                get(|| async { Json(json!({ "items": vec!["아이템 옵션"; 500] })) }),
+            )
+            .route("/small", get(|| async { Json(json!({ "status": "ok" })) }))
+            .route(
+                "/image",
+                get(|| async { ([(header::CONTENT_TYPE, "image/png")], vec![0u8; 4096]) }),
+            )
+            .layer(compression_layer(1024))
+    }
+
+    async fn request(uri: &str, encoding: &str) -> Response<Body> {
+        let request = Request::get(uri)
+            .header(header::ACCEPT_ENCODING, encoding)
+            .body(Body::empty())
+            .unwrap();
+        app().oneshot(request).await.unwrap()
+    }
+
+    fn content_encoding(response: &Response<Body>) -> Option<&str> {
+        response
+            .headers()
+            .get(header::CONTENT_ENCODING)
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[tokio::test]
+    async fn compresses_large_responses_with_negotiated_encoding() {
+        for encoding in ["gzip", "br"] {
+            let response = request("/large", encoding).await;
+            assert_eq!(content_encoding(&response), Some(encoding));
+
+            let compressed = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let plain = to_bytes(request("/large", "identity").await.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert!(compressed.len() < plain.len() / 4);
+        }
+    }
+
+    #[tokio::test]
+    async fn leaves_small_and_image_responses_uncompressed() {
+        assert_eq!(content_encoding(&request("/small", "gzip").await), None);
+        assert_eq!(content_encoding(&request("/image", "gzip").await), None);
+    }
+
+    #[tokio::test]
+    async fn leaves_responses_uncompressed_without_accept_encoding() {
+        assert_eq!(content_encoding(&request("/large", "identity").await), None);
+    }
+}
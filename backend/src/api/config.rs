@@ -0,0 +1,744 @@
+use crate::api::key_pool::mask_key;
+use crate::api::region::Region;
+
+use http::HeaderValue;
+use reqwest::Url;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const CONFIG_PATH_ENV: &str = "MELOG_CONFIG";
+const ENV_PREFIX: &str = "MELOG_";
+
+// 설정 파일 / 환경 변수에서 사용할 수 있는 키 (환경 변수는 MELOG_ + 대문자, "." 은 "_")
+// "retry.max_retries" 처럼 점이 있는 키는 설정 파일의 [retry] 테이블에 쓴다
+const KEYS: [&str; 24] = [
+    "api_key",
+    "bind",
+    "cors_origins",
+    "nexon_base_url",
+    "region",
+    "connect_timeout",
+    "timeout",
+    "rate_limit",
+    "retry.max_retries",
+    "retry.base_delay",
+    "client_rate_limit",
+    "client_rate_limit_per_ip",
+    "client_rate_limit_max_clients",
+    "cache_ttl",
+    "cache_max_entries",
+    "image_cache_max_bytes",
+    "image_cache_max_variants",
+    "shutdown_timeout",
+    "compression_min_size",
+    "demo_characters",
+    "demo_ocids",
+    "demo_rate_limit",
+    "demo_cassette",
+    "log_format",
+];
+
+const DEFAULT_BIND: &str = "0.0.0.0:3000";
+const DEFAULT_CORS_ORIGIN: &str = "http://localhost:5173";
+const DEFAULT_BASE_URL: &str = "https://open.api.nexon.com";
+// 넥슨 API 연결 / 전체 (연결 + 응답) 타임아웃 기본값 (초)
+const DEFAULT_CONNECT_TIMEOUT: u64 = 5;
+const DEFAULT_TIMEOUT: u64 = 10;
+// 개발 단계 API 키의 초당 호출 한도
+const DEFAULT_RATE_LIMIT: u32 = 5;
+// 일시적인 오류 (연결 실패, 타임아웃, 5xx) 재시도 횟수와 첫 대기 시간 (밀리초)
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_RETRY_BASE_DELAY: u64 = 200;
+// 클라이언트별 / IP별 분당 요청 수 (0 이면 제한하지 않음)와 추적하는 최대 클라이언트 수
+const DEFAULT_CLIENT_RATE_LIMIT: u32 = 120;
+const DEFAULT_CLIENT_RATE_LIMIT_PER_IP: u32 = 600;
+const DEFAULT_CLIENT_MAX: usize = 10_000;
+const DEFAULT_CACHE_TTL: u64 = 30 * 60;
+const DEFAULT_CACHE_MAX_ENTRIES: usize = 10_000;
+// 캐릭터 이미지 캐시의 최대 크기 (바이트)
+const DEFAULT_IMAGE_CACHE_MAX_BYTES: usize = 32 * 1024 * 1024;
+// 캐릭터 하나당 캐시하는 이미지 옵션 조합 수
+const DEFAULT_IMAGE_CACHE_MAX_VARIANTS: usize = 16;
+// 데모 모드에서 전체 클라이언트가 함께 사용하는 분당 요청 수 (0 이면 제한하지 않음)
+const DEFAULT_DEMO_RATE_LIMIT: u32 = 60;
+// 종료 신호 이후 처리 중인 요청을 기다리는 시간 (초)
+const DEFAULT_SHUTDOWN_TIMEOUT: u64 = 15;
+// 이 크기(바이트)보다 큰 응답만 압축
+const DEFAULT_COMPRESSION_MIN_SIZE: u16 = 1024;
+
+// 서버 설정
+// 환경 변수(MELOG_*)가 설정 파일(MELOG_CONFIG 경로의 TOML)보다 우선한다
+pub struct Config {
+    // 넥슨 API 키 목록 (쉼표로 구분하거나 설정 파일에서 배열로 지정)
+    pub api_keys: Vec<String>,
+    pub bind: SocketAddr,
+    pub cors_origins: Vec<HeaderValue>,
+    // 넥슨 API 주소 (테스트용 모의 서버로 바꿀 수 있다)
+    pub nexon_base_url: String,
+    // 기본 넥슨 API 지역 (kms / msea)
+    pub region: Region,
+    pub connect_timeout: Duration,
+    pub timeout: Duration,
+    // 넥슨 API 초당 호출 수
+    pub rate_limit: u32,
+    pub retry: RetryConfig,
+    // 클라이언트(IP + uuid 헤더)별 분당 요청 수 (0 이면 제한하지 않음)
+    pub client_rate_limit: u32,
+    // IP별 분당 요청 수 (uuid 를 바꿔 가며 보내는 요청도 제한, 0 이면 IP 단위로는 제한하지 않음)
+    pub client_rate_limit_per_ip: u32,
+    pub client_rate_limit_max_clients: usize,
+    pub cache_ttl: Duration,
+    pub cache_max_entries: usize,
+    // 캐릭터 이미지 캐시에 저장하는 이미지 크기 합계 (바이트)
+    pub image_cache_max_bytes: usize,
+    // 캐릭터 하나당 캐시하는 이미지 옵션 조합 (변형) 수
+    pub image_cache_max_variants: usize,
+    pub shutdown_timeout: Duration,
+    // 이 크기(바이트)보다 큰 응답만 gzip / brotli 로 압축
+    pub compression_min_size: u16,
+    // 데모 모드 (닉네임 / ocid / 카세트 중 하나라도 지정하면 활성화)
+    // 허용할 닉네임과, 재시작 후에도 바로 허용할 ocid
+    pub demo_characters: Vec<String>,
+    pub demo_ocids: Vec<String>,
+    // 데모 모드의 전체 분당 요청 수 (0 이면 제한하지 않음)
+    pub demo_rate_limit: u32,
+    // 넥슨 API 대신 응답을 재생할 카세트 파일 (JSON)
+    pub demo_cassette: Option<PathBuf>,
+    // 로그를 한 줄 JSON 형식으로 출력 (log_format = "json")
+    pub log_json: bool,
+}
+
+// 넥슨 API 재시도 설정 ([retry] 테이블)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    // 첫 요청 이후 최대 재시도 횟수 (0 이면 재시도하지 않음)
+    pub max_retries: u32,
+    // 첫 재시도 대기 시간 (재시도마다 두 배)
+    pub base_delay: Duration,
+}
+
+// 설정 오류 목록 (첫 번째 오류에서 멈추지 않고 모두 모은다)
+#[derive(Debug)]
+pub struct ConfigError(Vec<String>);
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "설정 오류 {}건:", self.0.len())?;
+        for error in &self.0 {
+            write!(f, "\n  - {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// 설정 값을 읽는 곳 (환경 변수 → 설정 파일 순서)
+struct Source {
+    env: HashMap<String, String>,
+    file: toml::Table,
+    errors: Vec<String>,
+}
+
+impl Source {
+    // vars 중 MELOG_ 로 시작하는 환경 변수만 사용
+    fn load(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        let env: HashMap<String, String> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+            .collect();
+
+        let source = match env.get(CONFIG_PATH_ENV) {
+            None => Self::from_file("", ""),
+            Some(path) => match std::fs::read_to_string(path) {
+                Ok(text) => Self::from_file(path, &text),
+                Err(error) => {
+                    let mut source = Self::from_file(path, "");
+                    source.errors.push(format!(
+                        "{CONFIG_PATH_ENV} ({path}): 파일을 읽을 수 없습니다: {error}"
+                    ));
+                    source
+                }
+            },
+        };
+
+        Source { env, ..source }
+    }
+
+    // 설정 파일 내용 (TOML) 확인
+    fn from_file(path: &str, text: &str) -> Self {
+        let mut source = Source {
+            env: HashMap::new(),
+            file: toml::Table::new(),
+            errors: Vec::new(),
+        };
+
+        match text.parse::<toml::Table>() {
+            Ok(file) => source.file = file,
+            Err(error) => source.errors.push(format!(
+                "{CONFIG_PATH_ENV} ({path}): TOML 형식 오류: {error}"
+            )),
+        }
+
+        let mut unknown = Vec::new();
+        for (key, value) in &source.file {
+            match value {
+                toml::Value::Table(table) => unknown.extend(
+                    table
+                        .keys()
+                        .map(|inner| format!("{key}.{inner}"))
+                        .filter(|key| !KEYS.contains(&key.as_str())),
+                ),
+                _ if !KEYS.contains(&key.as_str()) => unknown.push(key.clone()),
+                _ => {}
+            }
+        }
+        for key in unknown {
+            source
+                .errors
+                .push(format!("{path}: 알 수 없는 설정 키 `{key}`"));
+        }
+
+        source
+    }
+
+    fn env_name(key: &str) -> String {
+        format!("{ENV_PREFIX}{}", key.to_uppercase().replace('.', "_"))
+    }
+
+    // 오류 메시지에 표시할 이름 ("bind (MELOG_BIND)")
+    fn label(key: &str) -> String {
+        format!("{key} ({})", Self::env_name(key))
+    }
+
+    fn raw(&self, key: &str) -> Option<String> {
+        self.env
+            .get(&Self::env_name(key))
+            .cloned()
+            .or_else(|| self.file_value(key).map(toml_to_string))
+    }
+
+    // 설정 파일의 값 ("retry.max_retries" 는 [retry] 테이블의 max_retries)
+    fn file_value(&self, key: &str) -> Option<&toml::Value> {
+        match key.split_once('.') {
+            Some((table, inner)) => self.file.get(table)?.as_table()?.get(inner),
+            None => self.file.get(key),
+        }
+    }
+
+    // 값이 없으면 기본값, 변환에 실패하면 오류를 기록하고 기본값
+    fn get<T>(
+        &mut self,
+        key: &str,
+        default: T,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) -> T {
+        let Some(value) = self.raw(key) else {
+            return default;
+        };
+
+        match parse(value.trim()) {
+            Ok(value) => value,
+            Err(reason) => {
+                self.errors
+                    .push(format!("{} = {value:?}: {reason}", Self::label(key)));
+                default
+            }
+        }
+    }
+}
+
+// 설정 파일 값을 환경 변수와 같은 문자열 형식으로 변환 (배열은 쉼표로 연결)
+fn toml_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(value) => value.clone(),
+        toml::Value::Array(values) => values
+            .iter()
+            .map(toml_to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+        value => value.to_string(),
+    }
+}
+
+// "15", "15s", "500ms", "2m" 형식의 시간 (단위가 없으면 초)
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (number, unit) = value
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or((value, ""), |index| value.split_at(index));
+
+    let number: u64 = number
+        .parse()
+        .map_err(|_| "시간 형식이 아닙니다 (예: 15, 15s, 500ms, 2m)".to_string())?;
+
+    match unit.trim() {
+        "" | "s" => Ok(Duration::from_secs(number)),
+        "ms" => Ok(Duration::from_millis(number)),
+        "m" => number
+            .checked_mul(60)
+            .map(Duration::from_secs)
+            .ok_or_else(|| "시간 값이 너무 큽니다".to_string()),
+        unit => Err(format!("지원하지 않는 시간 단위 `{unit}` (s, ms, m)")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| "0 이상의 정수가 아닙니다".to_string())
+}
+
+// 쉼표로 구분한 목록 (빈 항목 제외)
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+impl Config {
+    // 설정 불러오기
+    // API 키는 명령행 인자가 있으면 그 값을 우선 사용한다 (cargo run <api_key>[,<api_key>...])
+    pub fn load(cli_api_key: Option<String>) -> Result<Self, ConfigError> {
+        Self::load_from(std::env::vars(), cli_api_key)
+    }
+
+    // 프로세스 환경 변수 대신 주어진 목록에서 읽는다 (테스트 등)
+    pub fn load_from(
+        vars: impl IntoIterator<Item = (String, String)>,
+        cli_api_key: Option<String>,
+    ) -> Result<Self, ConfigError> {
+        Self::from_source(Source::load(vars), cli_api_key)
+    }
+
+    fn from_source(mut source: Source, cli_api_key: Option<String>) -> Result<Self, ConfigError> {
+        let api_keys = match cli_api_key {
+            Some(value) => parse_list(&value),
+            None => source.get("api_key", Vec::new(), |value| Ok(parse_list(value))),
+        };
+        if api_keys.is_empty() {
+            source.errors.push(format!(
+                "{}: API 키가 없습니다 (명령행 인자, 환경 변수 또는 설정 파일의 api_key)",
+                Source::label("api_key")
+            ));
+        }
+        for (index, key) in api_keys.iter().enumerate() {
+            if HeaderValue::from_str(key).is_err() {
+                source.errors.push(format!(
+                    "{}: {}번째 키에 헤더 값으로 사용할 수 없는 문자가 포함되어 있습니다",
+                    Source::label("api_key"),
+                    index + 1
+                ));
+            }
+        }
+
+        let default_bind = DEFAULT_BIND.parse().expect("valid default bind address");
+        let bind = source.get("bind", default_bind, |value| {
+            value
+                .parse()
+                .map_err(|_| "주소 형식이 아닙니다 (예: 0.0.0.0:3000)".to_string())
+        });
+
+        let cors_origins = source.get(
+            "cors_origins",
+            vec![HeaderValue::from_static(DEFAULT_CORS_ORIGIN)],
+            |value| {
+                parse_list(value)
+                    .into_iter()
+                    .map(|origin| {
+                        HeaderValue::from_str(&origin)
+                            .map_err(|_| format!("올바르지 않은 origin `{origin}`"))
+                    })
+                    .collect()
+            },
+        );
+
+        let nexon_base_url = source.get("nexon_base_url", DEFAULT_BASE_URL.to_string(), |value| {
+            Url::parse(value)
+                .map(|_| value.trim_end_matches('/').to_string())
+                .map_err(|error| format!("URL 형식이 아닙니다: {error}"))
+        });
+
+        let region = source.get("region", Region::default(), |value| {
+            Region::parse(value).ok_or_else(|| "kms 또는 msea 만 사용할 수 있습니다".to_string())
+        });
+
+        let connect_timeout = source.get(
+            "connect_timeout",
+            Duration::from_secs(DEFAULT_CONNECT_TIMEOUT),
+            parse_duration,
+        );
+        let timeout = source.get(
+            "timeout",
+            Duration::from_secs(DEFAULT_TIMEOUT),
+            parse_duration,
+        );
+        let rate_limit = source.get("rate_limit", DEFAULT_RATE_LIMIT, parse_number);
+        let retry = RetryConfig {
+            max_retries: source.get("retry.max_retries", DEFAULT_MAX_RETRIES, parse_number),
+            base_delay: source.get(
+                "retry.base_delay",
+                Duration::from_millis(DEFAULT_RETRY_BASE_DELAY),
+                parse_duration,
+            ),
+        };
+        let client_rate_limit =
+            source.get("client_rate_limit", DEFAULT_CLIENT_RATE_LIMIT, parse_number);
+        let client_rate_limit_per_ip = source.get(
+            "client_rate_limit_per_ip",
+            DEFAULT_CLIENT_RATE_LIMIT_PER_IP,
+            parse_number,
+        );
+        let client_rate_limit_max_clients = source.get(
+            "client_rate_limit_max_clients",
+            DEFAULT_CLIENT_MAX,
+            parse_number,
+        );
+        let cache_ttl = source.get(
+            "cache_ttl",
+            Duration::from_secs(DEFAULT_CACHE_TTL),
+            parse_duration,
+        );
+        let cache_max_entries =
+            source.get("cache_max_entries", DEFAULT_CACHE_MAX_ENTRIES, parse_number);
+        let image_cache_max_bytes = source.get(
+            "image_cache_max_bytes",
+            DEFAULT_IMAGE_CACHE_MAX_BYTES,
+            parse_number,
+        );
+        let image_cache_max_variants = source.get(
+            "image_cache_max_variants",
+            DEFAULT_IMAGE_CACHE_MAX_VARIANTS,
+            parse_number,
+        );
+        let shutdown_timeout = source.get(
+            "shutdown_timeout",
+            Duration::from_secs(DEFAULT_SHUTDOWN_TIMEOUT),
+            parse_duration,
+        );
+        let compression_min_size = source.get(
+            "compression_min_size",
+            DEFAULT_COMPRESSION_MIN_SIZE,
+            parse_number,
+        );
+        let demo_characters =
+            source.get("demo_characters", Vec::new(), |value| Ok(parse_list(value)));
+        let demo_ocids = source.get("demo_ocids", Vec::new(), |value| Ok(parse_list(value)));
+        let demo_rate_limit = source.get("demo_rate_limit", DEFAULT_DEMO_RATE_LIMIT, parse_number);
+        let demo_cassette = source.get("demo_cassette", None, |value| {
+            Ok((!value.is_empty()).then(|| PathBuf::from(value)))
+        });
+
+        let log_json = source.get("log_format", false, |value| match value {
+            "json" => Ok(true),
+            "text" => Ok(false),
+            _ => Err("json 또는 text 만 사용할 수 있습니다".to_string()),
+        });
+
+        if !source.errors.is_empty() {
+            return Err(ConfigError(source.errors));
+        }
+
+        Ok(Self {
+            api_keys,
+            bind,
+            cors_origins,
+            nexon_base_url,
+            region,
+            connect_timeout,
+            timeout,
+            rate_limit,
+            retry,
+            client_rate_limit,
+            client_rate_limit_per_ip,
+            client_rate_limit_max_clients,
+            cache_ttl,
+            cache_max_entries,
+            image_cache_max_bytes,
+            image_cache_max_variants,
+            shutdown_timeout,
+            compression_min_size,
+            demo_characters,
+            demo_ocids,
+            demo_rate_limit,
+            demo_cassette,
+            log_json,
+        })
+    }
+}
+
+// 로그에 남기는 설정 (API 키는 마지막 4자리만 표시)
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let masked_keys: Vec<String> = self.api_keys.iter().map(|key| mask_key(key)).collect();
+
+        f.debug_struct("Config")
+            .field("api_keys", &masked_keys)
+            .field("bind", &self.bind)
+            .field("cors_origins", &self.cors_origins)
+            .field("nexon_base_url", &self.nexon_base_url)
+            .field("region", &self.region)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("timeout", &self.timeout)
+            .field("rate_limit", &self.rate_limit)
+            .field("retry", &self.retry)
+            .field("client_rate_limit", &self.client_rate_limit)
+            .field("client_rate_limit_per_ip", &self.client_rate_limit_per_ip)
+            .field(
+                "client_rate_limit_max_clients",
+                &self.client_rate_limit_max_clients,
+            )
+            .field("cache_ttl", &self.cache_ttl)
+            .field("cache_max_entries", &self.cache_max_entries)
+            .field("image_cache_max_bytes", &self.image_cache_max_bytes)
+            .field("image_cache_max_variants", &self.image_cache_max_variants)
+            .field("shutdown_timeout", &self.shutdown_timeout)
+            .field("compression_min_size", &self.compression_min_size)
+            .field("demo_characters", &self.demo_characters)
+            .field("demo_ocids", &self.demo_ocids)
+            .field("demo_rate_limit", &self.demo_rate_limit)
+            .field("demo_cassette", &self.demo_cassette)
+            .field("log_json", &self.log_json)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_file(text: &str) -> Result<Config, ConfigError> {
+        Config::from_source(Source::from_file("melog.toml", text), None)
+    }
+
+    fn errors(text: &str) -> Vec<String> {
+        match from_file(text) {
+            Err(ConfigError(errors)) => errors,
+            Ok(config) => panic!("expected errors: {config:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_duration_accepts_units() {
+        assert_eq!(parse_duration("15"), Ok(Duration::from_secs(15)));
+        assert_eq!(parse_duration("15s"), Ok(Duration::from_secs(15)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("1h").unwrap_err().contains("`h`"));
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("-1").is_err());
+        // 분 단위를 초로 바꿀 때 넘치는 값은 오류
+        assert!(
+            parse_duration("18446744073709551615m")
+                .unwrap_err()
+                .contains("너무 큽니다")
+        );
+    }
+
+    #[test]
+    fn parse_list_skips_empty_items() {
+        assert_eq!(parse_list(" a, b ,,c "), ["a", "b", "c"]);
+        assert!(parse_list(" , ").is_empty());
+    }
+
+    #[test]
+    fn reads_values_from_file() {
+        let config = from_file(
+            r#"
+            api_key = ["key-1", "key-2"]
+            bind = "127.0.0.1:8080"
+            region = "MSEA"
+            timeout = "500ms"
+            rate_limit = 10
+            nexon_base_url = "http://localhost:9000/"
+            demo_characters = ["데모"]
+            log_format = "json"
+
+            [retry]
+            max_retries = 0
+            base_delay = "50ms"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.api_keys, ["key-1", "key-2"]);
+        assert_eq!(config.bind, "127.0.0.1:8080".parse().unwrap());
+        assert_eq!(config.region, Region::Msea);
+        assert_eq!(config.timeout, Duration::from_millis(500));
+        assert_eq!(config.rate_limit, 10);
+        assert_eq!(
+            config.retry,
+            RetryConfig {
+                max_retries: 0,
+                base_delay: Duration::from_millis(50),
+            }
+        );
+        assert_eq!(config.nexon_base_url, "http://localhost:9000");
+        assert_eq!(config.demo_characters, ["데모"]);
+        assert!(config.log_json);
+        // 지정하지 않은 값은 기본값
+        assert_eq!(config.cache_ttl, Duration::from_secs(DEFAULT_CACHE_TTL));
+        assert_eq!(config.demo_cassette, None);
+    }
+
+    // 설정 파일 + 환경 변수 (프로세스 환경 변수는 읽지 않는다)
+    fn with_env(text: &str, vars: &[(&str, &str)]) -> Result<Config, ConfigError> {
+        let env = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        let source = Source {
+            env,
+            ..Source::from_file("melog.toml", text)
+        };
+        Config::from_source(source, None)
+    }
+
+    #[test]
+    fn environment_overrides_file() {
+        let config = with_env(
+            r#"
+            api_key = "file-key"
+            timeout = "500ms"
+
+            [retry]
+            max_retries = 1
+            "#,
+            &[
+                ("MELOG_TIMEOUT", "2m"),
+                ("MELOG_RETRY_MAX_RETRIES", "5"),
+                ("MELOG_API_KEY", "env-1,env-2"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(config.api_keys, ["env-1", "env-2"]);
+        assert_eq!(config.timeout, Duration::from_secs(120));
+        assert_eq!(config.retry.max_retries, 5);
+    }
+
+    #[test]
+    fn overflowing_duration_is_a_config_error() {
+        let Err(ConfigError(errors)) = with_env(
+            r#"api_key = "key""#,
+            &[("MELOG_CACHE_TTL", "18446744073709551615m")],
+        ) else {
+            panic!("overflowing duration accepted");
+        };
+
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].starts_with("cache_ttl (MELOG_CACHE_TTL)"));
+    }
+
+    #[test]
+    fn load_from_reads_only_given_variables() {
+        let config = Config::load_from(
+            [
+                ("MELOG_API_KEY".to_string(), "env-key".to_string()),
+                ("MELOG_RATE_LIMIT".to_string(), "7".to_string()),
+                ("RATE_LIMIT".to_string(), "x".to_string()),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(config.api_keys, ["env-key"]);
+        assert_eq!(config.rate_limit, 7);
+    }
+
+    #[test]
+    fn command_line_key_overrides_file() {
+        let source = Source::from_file("melog.toml", r#"api_key = "file-key""#);
+        let config = Config::from_source(source, Some("cli-1,cli-2".to_string())).unwrap();
+
+        assert_eq!(config.api_keys, ["cli-1", "cli-2"]);
+    }
+
+    #[test]
+    fn collects_every_error() {
+        let errors = errors(
+            r#"
+            bind = "localhost"
+            timeout = "1h"
+            rate_limit = -1
+            region = "gms"
+            unknown_key = 1
+            "#,
+        );
+
+        assert_eq!(errors.len(), 6, "{errors:#?}");
+        assert!(errors.iter().any(|error| error.contains("`unknown_key`")));
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.starts_with("api_key (MELOG_API_KEY)"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.starts_with("bind (MELOG_BIND)"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.starts_with("timeout (MELOG_TIMEOUT)"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.starts_with("rate_limit (MELOG_RATE_LIMIT)"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.starts_with("region (MELOG_REGION)"))
+        );
+    }
+
+    #[test]
+    fn reports_malformed_toml() {
+        let errors = errors("api_key = ");
+
+        assert!(errors[0].contains("TOML 형식 오류"), "{errors:?}");
+    }
+
+    #[test]
+    fn retry_defaults_and_nested_key_errors() {
+        let config = from_file(r#"api_key = "key""#).unwrap();
+        assert_eq!(config.retry.max_retries, DEFAULT_MAX_RETRIES);
+        assert_eq!(
+            config.retry.base_delay,
+            Duration::from_millis(DEFAULT_RETRY_BASE_DELAY)
+        );
+
+        let errors = errors(
+            r#"
+            api_key = "key"
+            retry = { base_delay = "1h", jitter = true }
+            "#,
+        );
+
+        assert_eq!(errors.len(), 2, "{errors:#?}");
+        assert!(errors.iter().any(|error| error.contains("`retry.jitter`")));
+        assert!(
+            errors
+                .iter()
+                .any(|error| error.starts_with("retry.base_delay (MELOG_RETRY_BASE_DELAY)"))
+        );
+    }
+
+    #[test]
+    fn debug_output_masks_api_keys() {
+        let config = from_file(r#"api_key = "secret-key-1234""#).unwrap();
+        let debug = format!("{config:?}");
+
+        assert!(!debug.contains("secret-key"));
+        assert!(debug.contains("1234"));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::api::error::AppError;
+#[cfg(test)]
+use crate::api::region::Region;
+use crate::api::region::current_region;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike, Utc,
+    Weekday,
+};
+use chrono_tz::{Asia::Seoul, Tz};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use std::ops::Deref;
+use utoipa::IntoParams;
+
+// "2023-12-21T00:00+09:00" 형태의 날짜를 "2023-12-21" 로 정규화
+pub fn normalize_date(date: &str) -> String {
+    date.get(..10).unwrap_or(date).to_string()
+}
+
+// 응답의 date 필드 역직렬화 (null 인 경우 빈 문자열)
+pub fn deserialize_date<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let date: Option<String> = Option::deserialize(deserializer)?;
+
+    Ok(date.as_deref().map(normalize_date).unwrap_or_default())
+}
+
+// "2023-12-21T10:00+09:00" 형태의 일시 파싱 (초 단위가 없는 경우 포함)
+pub fn parse_datetime(datetime: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(datetime)
+        .or_else(|_| DateTime::parse_from_str(datetime, "%Y-%m-%dT%H:%M%:z"))
+        .ok()
+}
+
+// 응답의 일시 필드 역직렬화
+pub fn deserialize_datetime<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let datetime = String::deserialize(deserializer)?;
+
+    parse_datetime(&datetime)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid datetime: {datetime}")))
+}
+
+// 주간 콘텐츠 초기화 시각 (한국 시간 목요일 00시)
+// now 이전의 가장 최근 초기화 시각을 반환한다
+pub fn weekly_reset(now: DateTime<Tz>) -> DateTime<Tz> {
+    let today = now.date_naive();
+    let days_since_reset =
+        (today.weekday().num_days_from_monday() + 7 - Weekday::Thu.num_days_from_monday()) % 7;
+    let reset_date = today - Duration::days(i64::from(days_since_reset));
+
+    Seoul
+        .from_local_datetime(&reset_date.and_time(NaiveTime::MIN))
+        .single()
+        .unwrap_or(now)
+}
+
+// 날짜를 지정하지 않은 경우 조회할 날짜 (현재 지역 시간 기준 어제)
+// 전날 데이터가 반영되기 전(KMS 01시 이전)이면 하루 더 이전 날짜를 조회한다
+pub fn default_date() -> String {
+    let rules = current_region().date_rules();
+
+    date_before(
+        Utc::now().with_timezone(&rules.timezone),
+        rules.data_ready_hour,
+    )
+    .format("%Y-%m-%d")
+    .to_string()
+}
+
+// UTC에서 하루를 뺀 뒤 변환하면 자정 부근에서 날짜가 어긋나므로 지역 시간으로 먼저 변환한다
+fn date_before(now: DateTime<Tz>, data_ready_hour: u32) -> NaiveDate {
+    let yesterday = now.date_naive() - Duration::days(1);
+
+    if now.hour() < data_ready_hour {
+        yesterday - Duration::days(1)
+    } else {
+        yesterday
+    }
+}
+
+// 하루 전 날짜 (형식이 잘못된 경우 그대로 반환)
+pub fn previous_date(date: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|date| (date - Duration::days(1)).format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| date.to_string())
+}
+
+// 사용자가 지정한 날짜 확인 (YYYY-MM-DD, 지역별 첫 날짜 ~ latest)
+fn check_date(
+    date: &str,
+    latest: NaiveDate,
+    latest_error: &'static str,
+) -> Result<String, AppError> {
+    let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| AppError::Unprocessable("date must be in YYYY-MM-DD format"))?;
+
+    let rules = current_region().date_rules();
+    let first = NaiveDate::parse_from_str(rules.first_date, "%Y-%m-%d").unwrap_or(NaiveDate::MIN);
+    if parsed < first {
+        return Err(AppError::Unprocessable(rules.first_date_error));
+    }
+
+    if parsed > latest {
+        return Err(AppError::Unprocessable(latest_error));
+    }
+
+    Ok(parsed.format("%Y-%m-%d").to_string())
+}
+
+// 지역 시간 기준 오늘
+fn today() -> NaiveDate {
+    Utc::now()
+        .with_timezone(&current_region().date_rules().timezone)
+        .date_naive()
+}
+
+// 조회 날짜 확인 (캐릭터 / 유니온 / 랭킹)
+// 당일 데이터는 다음 날 반영되므로 오늘 이후 날짜는 거절한다
+pub fn validate_date(date: &str) -> Result<String, AppError> {
+    validate_date_at(date, today())
+}
+
+fn validate_date_at(date: &str, today: NaiveDate) -> Result<String, AppError> {
+    check_date(
+        date,
+        today - Duration::days(1),
+        "date must be earlier than today",
+    )
+}
+
+// 히스토리 조회 날짜 확인 (당일 기록까지 조회할 수 있다)
+pub fn validate_history_date(date: &str) -> Result<String, AppError> {
+    check_date(date, today(), "date must not be in the future")
+}
+
+// 조회 날짜
+// 직접 지정한 날짜는 그대로 조회하고,
+// 기본 날짜는 데이터가 아직 준비되지 않은 경우 (OPENAPI00009) 하루 전 날짜로 다시 조회한다
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryDate {
+    date: String,
+    defaulted: bool,
+}
+
+impl QueryDate {
+    // 직접 지정한 날짜 (확인하지 않음, 사용자 입력은 validate_date / DateQuery 를 거친다)
+    pub fn explicit(date: &str) -> Self {
+        Self {
+            date: date.to_string(),
+            defaulted: false,
+        }
+    }
+
+    // 현재 지역 기준 가장 최근 날짜 (default_date)
+    pub fn latest() -> Self {
+        Self {
+            date: default_date(),
+            defaulted: true,
+        }
+    }
+
+    // 지정한 날짜가 있으면 확인하고, 없으면 기본 날짜
+    pub fn resolve(date: Option<&str>) -> Result<Self, AppError> {
+        match date {
+            Some(date) => validate_date(date).map(|date| Self::explicit(&date)),
+            None => Ok(Self::latest()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.date
+    }
+
+    // 데이터가 준비되지 않은 경우 다시 조회할 날짜 (기본 날짜인 경우에만)
+    pub fn fallback(&self) -> Option<String> {
+        self.defaulted.then(|| previous_date(&self.date))
+    }
+}
+
+impl Deref for QueryDate {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.date
+    }
+}
+
+impl fmt::Display for QueryDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.date)
+    }
+}
+
+// 조회 날짜 옵션 (?date=YYYY-MM-DD)
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct DateQuery {
+    #[serde(default)]
+    date: Option<String>,
+}
+
+impl DateQuery {
+    // 지정된 날짜를 확인하고, 없으면 기본 날짜를 사용
+    pub fn resolve(&self) -> Result<QueryDate, AppError> {
+        QueryDate::resolve(self.date.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::Asia::Singapore;
+
+    fn kst(datetime: &str) -> DateTime<Tz> {
+        DateTime::parse_from_rfc3339(datetime)
+            .unwrap()
+            .with_timezone(&Seoul)
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn date_before_waits_for_data_ready_hour() {
+        // KMS: 한국 시간 01시 이후 전날 데이터 조회
+        assert_eq!(
+            date_before(kst("2024-06-02T00:59:59+09:00"), 1),
+            date("2024-05-31")
+        );
+        assert_eq!(
+            date_before(kst("2024-06-02T01:00:00+09:00"), 1),
+            date("2024-06-01")
+        );
+    }
+
+    #[test]
+    fn date_before_uses_local_date_not_utc() {
+        // UTC 6월 1일 16시 = 한국 시간 6월 2일 01시
+        assert_eq!(
+            date_before(kst("2024-06-01T16:00:00Z"), 1),
+            date("2024-06-01")
+        );
+        // UTC 6월 1일 15시 30분 = 한국 시간 6월 2일 00시 30분 (아직 반영 전)
+        assert_eq!(
+            date_before(kst("2024-06-01T15:30:00Z"), 1),
+            date("2024-05-31")
+        );
+    }
+
+    #[test]
+    fn date_before_msea_uses_singapore_time() {
+        let sgt = |datetime: &str| {
+            DateTime::parse_from_rfc3339(datetime)
+                .unwrap()
+                .with_timezone(&Singapore)
+        };
+        let rules = Region::Msea.date_rules();
+
+        assert_eq!(
+            date_before(sgt("2025-06-02T01:59:00+08:00"), rules.data_ready_hour),
+            date("2025-05-31")
+        );
+        assert_eq!(
+            date_before(sgt("2025-06-02T02:00:00+08:00"), rules.data_ready_hour),
+            date("2025-06-01")
+        );
+    }
+
+    #[test]
+    fn validate_date_rejects_today_and_future() {
+        let today = date("2024-06-02");
+
+        assert_eq!(validate_date_at("2024-06-01", today).unwrap(), "2024-06-01");
+        assert!(matches!(
+            validate_date_at("2024-06-02", today),
+            Err(AppError::Unprocessable("date must be earlier than today"))
+        ));
+        assert!(validate_date_at("2024-06-03", today).is_err());
+    }
+
+    #[test]
+    fn validate_date_checks_format_and_first_date() {
+        let today = date("2024-06-02");
+
+        assert!(matches!(
+            validate_date_at("2024-6-1x", today),
+            Err(AppError::Unprocessable("date must be in YYYY-MM-DD format"))
+        ));
+        assert!(matches!(
+            validate_date_at("2023-12-20", today),
+            Err(AppError::Unprocessable(message)) if message.contains("2023-12-21")
+        ));
+        assert_eq!(validate_date_at("2023-12-21", today).unwrap(), "2023-12-21");
+    }
+
+    #[test]
+    fn only_default_date_falls_back() {
+        assert_eq!(QueryDate::explicit("2024-06-02").fallback(), None);
+
+        let latest = QueryDate::latest();
+        assert_eq!(latest.fallback(), Some(previous_date(&latest)));
+    }
+
+    #[test]
+    fn previous_date_crosses_month_and_year() {
+        assert_eq!(previous_date("2024-03-01"), "2024-02-29");
+        assert_eq!(previous_date("2024-01-01"), "2023-12-31");
+        assert_eq!(previous_date("invalid"), "invalid");
+    }
+
+    #[test]
+    fn weekly_reset_is_thursday_midnight_kst() {
+        let reset = weekly_reset(kst("2024-06-05T23:59:00+09:00"));
+        assert_eq!(reset, kst("2024-05-30T00:00:00+09:00"));
+
+        let reset = weekly_reset(kst("2024-06-06T00:00:00+09:00"));
+        assert_eq!(reset, kst("2024-06-06T00:00:00+09:00"));
+    }
+}
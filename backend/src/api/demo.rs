@@ -0,0 +1,269 @@
+use crate::api::client_limit::ClientLimiter;
+use crate::api::config::Config;
+use crate::api::error::AppError;
+
+use axum::{
+    Json,
+    body::{Body, to_bytes},
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use dashmap::DashSet;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const BODY_LIMIT: usize = 64 * 1024;
+// 데모 모드에서도 허용하는 경로 (넥슨 API를 호출하지 않거나 캐릭터와 무관한 경로)
+pub const PUBLIC_PATHS: [&str; 4] = [
+    "/health",
+    "/metrics",
+    "/api-docs/openapi.json",
+    "/swagger-ui",
+];
+// API 키 소유자 계정으로 조회하는 경로 (캐릭터 목록, 사용 기록)
+// 데모 모드에서는 키 소유자의 정보가 노출되지 않도록 항상 거절한다
+const ACCOUNT_PATHS: [&str; 4] = [
+    "/getCharacterList",
+    "/getCubeHistory",
+    "/getStarforceHistory",
+    "/getPotentialHistory",
+];
+// 데모 모드 전체 요청 수 제한에 사용하는 클라이언트 이름
+const DEMO_CLIENT: &str = "demo";
+
+const FORBIDDEN_MESSAGE: &str = "Demo mode only serves allowed characters. See backend/Readme.md to run melog with your own API key.";
+
+// 카세트 항목 (경로와 요청 본문이 같은 요청에 기록된 응답을 반환)
+#[derive(Deserialize, Debug)]
+struct CassetteEntry {
+    path: String,
+    // 지정한 경우에만 쿼리 문자열까지 비교 ("date=2024-06-01")
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    body: Option<Value>,
+    #[serde(default = "default_status")]
+    status: u16,
+    response: Value,
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+impl CassetteEntry {
+    fn matches(&self, path: &str, query: Option<&str>, body: Option<&Value>) -> bool {
+        self.path == path
+            && self
+                .query
+                .as_deref()
+                .is_none_or(|expected| Some(expected) == query)
+            && self.body.as_ref() == body
+    }
+}
+
+// 데모 모드 설정
+// 허용된 캐릭터 닉네임과, 설정된 ocid 또는 허용된 닉네임으로 조회된 ocid만 요청할 수 있다
+// 카세트를 지정하면 넥슨 API를 호출하지 않고 카세트에 기록된 응답만 반환한다
+pub struct DemoMode {
+    characters: HashSet<String>,
+    ocids: DashSet<String>,
+    cassette: Option<Vec<CassetteEntry>>,
+    limiter: Option<ClientLimiter>,
+}
+
+impl DemoMode {
+    // 닉네임 / ocid / 카세트 중 하나라도 지정된 경우에만 활성화
+    // (MELOG_DEMO_CHARACTERS=닉네임1,닉네임2, MELOG_DEMO_OCIDS, MELOG_DEMO_CASSETTE)
+    // 카세트 파일을 읽을 수 없거나 형식이 잘못된 경우 실패
+    pub fn new(config: &Config) -> Result<Option<Self>, String> {
+        if config.demo_characters.is_empty()
+            && config.demo_ocids.is_empty()
+            && config.demo_cassette.is_none()
+        {
+            return Ok(None);
+        }
+
+        let cassette = match &config.demo_cassette {
+            Some(path) => {
+                let text = std::fs::read_to_string(path).map_err(|error| {
+                    format!(
+                        "카세트 파일을 읽을 수 없습니다 ({}): {error}",
+                        path.display()
+                    )
+                })?;
+                let entries: Vec<CassetteEntry> = serde_json::from_str(&text).map_err(|error| {
+                    format!("카세트 형식이 잘못되었습니다 ({}): {error}", path.display())
+                })?;
+                Some(entries)
+            }
+            None => None,
+        };
+
+        Ok(Some(Self {
+            characters: config.demo_characters.iter().cloned().collect(),
+            ocids: config.demo_ocids.iter().cloned().collect(),
+            cassette,
+            limiter: (config.demo_rate_limit > 0)
+                .then(|| ClientLimiter::new(config.demo_rate_limit, 1)),
+        }))
+    }
+
+    // 캐릭터를 지정하지 않은 요청 (공지사항, 랭킹 전체 조회 등) 은 허용
+    fn is_allowed(&self, body: &Value) -> bool {
+        if let Some(nick_name) = body.get("nickName").and_then(Value::as_str) {
+            return self.characters.contains(nick_name);
+        }
+
+        // 스킬 조회처럼 ocid가 중첩된 요청도 확인
+        let ocid = body
+            .get("ocid")
+            .or_else(|| body.get("user_ocid").and_then(|inner| inner.get("ocid")))
+            .and_then(Value::as_str);
+
+        ocid.is_none_or(|ocid| self.ocids.contains(ocid))
+    }
+
+    fn replay(&self, path: &str, query: Option<&str>, body: Option<&Value>) -> Option<Response> {
+        let entry = self
+            .cassette
+            .as_ref()?
+            .iter()
+            .find(|entry| entry.matches(path, query, body))?;
+        let status = StatusCode::from_u16(entry.status).unwrap_or(StatusCode::OK);
+
+        Some((status, Json(entry.response.clone())).into_response())
+    }
+}
+
+fn forbidden() -> Response {
+    AppError::Forbidden(FORBIDDEN_MESSAGE).into_response()
+}
+
+// 업스트림 요청 전에 허용 목록 / 요청 수를 확인하는 미들웨어
+pub async fn demo_guard(
+    State(demo): State<Arc<DemoMode>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path().to_string();
+    if PUBLIC_PATHS.contains(&path.as_str()) {
+        return next.run(request).await;
+    }
+
+    if let Some(limiter) = &demo.limiter
+        && let Err(error) = limiter.check(DEMO_CLIENT)
+    {
+        return error.into_response();
+    }
+
+    let (parts, body) = request.into_parts();
+
+    let Ok(bytes) = to_bytes(body, BODY_LIMIT).await else {
+        return forbidden();
+    };
+    let json = serde_json::from_slice::<Value>(&bytes).ok();
+
+    if demo.cassette.is_some() {
+        return demo
+            .replay(&path, parts.uri.query(), json.as_ref())
+            .unwrap_or_else(forbidden);
+    }
+
+    if ACCOUNT_PATHS.contains(&path.as_str()) {
+        return forbidden();
+    }
+
+    if !json.as_ref().is_none_or(|body| demo.is_allowed(body)) {
+        return forbidden();
+    }
+
+    let is_ocid_lookup = path == "/getOcid";
+    let response = next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await;
+
+    if !is_ocid_lookup || !response.status().is_success() {
+        return response;
+    }
+
+    // 허용된 닉네임으로 조회된 ocid를 기록
+    let (parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, BODY_LIMIT).await else {
+        return StatusCode::BAD_GATEWAY.into_response();
+    };
+
+    if let Some(ocid) = serde_json::from_slice::<Value>(&bytes)
+        .ok()
+        .and_then(|body| body.get("ocid").and_then(Value::as_str).map(str::to_string))
+    {
+        demo.ocids.insert(ocid);
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn demo(characters: &[&str], ocids: &[&str]) -> DemoMode {
+        DemoMode {
+            characters: characters.iter().map(|name| name.to_string()).collect(),
+            ocids: ocids.iter().map(|ocid| ocid.to_string()).collect(),
+            cassette: None,
+            limiter: None,
+        }
+    }
+
+    #[test]
+    fn allows_listed_names_and_ocids() {
+        let demo = demo(&["데모"], &["abc"]);
+
+        assert!(demo.is_allowed(&json!({ "nickName": "데모" })));
+        assert!(!demo.is_allowed(&json!({ "nickName": "다른캐릭터" })));
+        assert!(demo.is_allowed(&json!({ "ocid": "abc" })));
+        assert!(!demo.is_allowed(&json!({ "ocid": "xyz" })));
+        assert!(demo.is_allowed(&json!({ "user_ocid": { "ocid": "abc" }, "level": "6" })));
+        assert!(!demo.is_allowed(&json!({ "user_ocid": { "ocid": "xyz" }, "level": "6" })));
+    }
+
+    #[test]
+    fn allows_requests_without_character() {
+        let demo = demo(&["데모"], &[]);
+
+        assert!(demo.is_allowed(&json!({ "world_name": "스카니아", "page": 1 })));
+        // 랭킹 조회에 ocid를 지정한 경우는 확인
+        assert!(!demo.is_allowed(&json!({ "world_name": "스카니아", "ocid": "xyz" })));
+    }
+
+    #[test]
+    fn cassette_matches_path_body_and_optional_query() {
+        let entry: CassetteEntry = serde_json::from_value(json!({
+            "path": "/getUserInfo",
+            "query": "date=2024-06-01",
+            "body": { "ocid": "abc" },
+            "response": { "character_name": "데모" }
+        }))
+        .unwrap();
+        let body = json!({ "ocid": "abc" });
+
+        assert_eq!(entry.status, 200);
+        assert!(entry.matches("/getUserInfo", Some("date=2024-06-01"), Some(&body)));
+        assert!(!entry.matches("/getUserInfo", None, Some(&body)));
+        assert!(!entry.matches("/getUserInfo", Some("date=2024-06-01"), None));
+        assert!(!entry.matches("/getUserStatInfo", Some("date=2024-06-01"), Some(&body)));
+
+        let any_query: CassetteEntry = serde_json::from_value(json!({
+            "path": "/getNotice",
+            "response": { "notice": [] }
+        }))
+        .unwrap();
+        assert!(any_query.matches("/getNotice", Some("page=2"), None));
+    }
+}
@@ -0,0 +1,287 @@
+use crate::api::region::Region;
+
+#[cfg(feature = "server")]
+use axum::{
+    Json,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use http::StatusCode;
+use serde::Serialize;
+use std::fmt;
+#[cfg(feature = "server")]
+use tracing::{error, warn};
+use utoipa::ToSchema;
+
+// 핸들러 공통 오류
+#[derive(Debug)]
+pub enum AppError {
+    // 잘못된 요청 파라미터
+    InvalidInput(&'static str),
+    // 쿼리 문자열을 해석할 수 없는 경우 (?min_star=abc 등)
+    InvalidQuery(String),
+    // 형식은 맞지만 지원하지 않는 값
+    Unprocessable(&'static str),
+    // 허용되지 않은 요청 (데모 모드에서 허용 목록에 없는 캐릭터 등)
+    Forbidden(&'static str),
+    // 현재 지역에서 제공하지 않는 넥슨 API (KMS 전용 공지사항 등)
+    NotImplemented { endpoint: String, region: Region },
+    // 닉네임에 해당하는 캐릭터가 없는 경우
+    OcidNotFound,
+    // 요청한 리소스가 없는 경우 (공지사항 등)
+    NotFound(&'static str),
+    // 넥슨 API가 실패 응답을 반환한 경우
+    Upstream { status: StatusCode, message: String },
+    // 넥슨 API가 오류 코드(OPENAPI000XX)와 함께 실패 응답을 반환한 경우
+    Nexon { name: String, message: String },
+    // 넥슨 API 요청 자체가 실패한 경우 (연결 실패 등)
+    Request { endpoint: String },
+    // 넥슨 API 응답이 제한 시간 안에 오지 않은 경우
+    Timeout { endpoint: String },
+    // 넥슨 API 응답이 구조체와 맞지 않는 경우
+    Deserialize { endpoint: String, reason: String },
+    // 넥슨 API 호출 한도 초과 (초 단위 재시도 대기 시간)
+    RateLimited { retry_after: u64 },
+    // 연속 실패로 넥슨 API 호출을 차단한 상태 (초 단위 재시도 대기 시간)
+    CircuitOpen { retry_after: u64 },
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ErrorBody {
+    error: &'static str,
+    message: String,
+    // 넥슨 API 오류 코드 (넥슨 API 오류인 경우에만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nexon_error: Option<String>,
+}
+
+impl AppError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::InvalidInput(_) | AppError::InvalidQuery(_) => StatusCode::BAD_REQUEST,
+            AppError::Unprocessable(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,
+            AppError::OcidNotFound | AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            // 넥슨 API의 4xx는 전달된 파라미터 문제이므로 400, 점검 중인 경우 503
+            AppError::Upstream { status, .. } if status.is_client_error() => {
+                StatusCode::BAD_REQUEST
+            }
+            AppError::Upstream { status, .. } if *status == StatusCode::SERVICE_UNAVAILABLE => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            AppError::Upstream { .. } | AppError::Request { .. } | AppError::Deserialize { .. } => {
+                StatusCode::BAD_GATEWAY
+            }
+            AppError::Nexon { name, .. } => match name.as_str() {
+                "OPENAPI00003" => StatusCode::NOT_FOUND,
+                "OPENAPI00004" => StatusCode::BAD_REQUEST,
+                "OPENAPI00009" => StatusCode::SERVICE_UNAVAILABLE,
+                _ => StatusCode::BAD_GATEWAY,
+            },
+            AppError::Timeout { .. } => StatusCode::GATEWAY_TIMEOUT,
+            AppError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            AppError::CircuitOpen { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::InvalidInput(_) | AppError::InvalidQuery(_) => "invalid_input",
+            AppError::Unprocessable(_) => "unprocessable",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotImplemented { .. } => "not_implemented",
+            AppError::OcidNotFound => "ocid_not_found",
+            AppError::NotFound(_) => "not_found",
+            AppError::Upstream { .. } => "upstream_error",
+            AppError::Request { .. } => "upstream_unreachable",
+            AppError::Deserialize { .. } => "upstream_invalid_response",
+            AppError::Nexon { name, .. } => match name.as_str() {
+                "OPENAPI00003" => "invalid_identifier",
+                "OPENAPI00004" => "invalid_parameter",
+                "OPENAPI00009" => "upstream_unavailable",
+                _ => "upstream_error",
+            },
+            AppError::Timeout { .. } => "upstream_timeout",
+            AppError::RateLimited { .. } => "rate_limited",
+            AppError::CircuitOpen { .. } => "upstream_unavailable",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            AppError::InvalidInput(message)
+            | AppError::Unprocessable(message)
+            | AppError::Forbidden(message)
+            | AppError::NotFound(message) => message.to_string(),
+            AppError::InvalidQuery(message) => message.clone(),
+            AppError::OcidNotFound => "Character not found".to_string(),
+            AppError::NotImplemented { endpoint, region } => {
+                format!("{endpoint} is not available in region {}", region.as_str())
+            }
+            AppError::Upstream { message, .. } | AppError::Nexon { message, .. } => message.clone(),
+            AppError::Request { endpoint } => format!("Failed to send request to {endpoint}"),
+            AppError::Timeout { endpoint } => format!("Request to {endpoint} timed out"),
+            AppError::Deserialize { endpoint, reason } => {
+                format!("Unexpected response from {endpoint} ({reason})")
+            }
+            AppError::RateLimited { retry_after } => {
+                format!("Too many requests, retry after {retry_after} seconds")
+            }
+            AppError::CircuitOpen { retry_after } => {
+                format!("Upstream unavailable, retry after {retry_after} seconds")
+            }
+        }
+    }
+}
+
+// 라이브러리로 사용할 때 ? 로 다른 오류와 함께 전달할 수 있도록
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message(), self.code())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+// 여러 조회를 묶어 반환할 때 섹션별 결과
+// 일부 섹션이 실패해도 나머지는 그대로 응답한다
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Section<T> {
+    Ok(T),
+    Error { code: &'static str, message: String },
+}
+
+impl<T> Section<T> {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Section::Ok(_))
+    }
+}
+
+impl<T> From<Result<T, AppError>> for Section<T> {
+    fn from(result: Result<T, AppError>) -> Self {
+        match result {
+            Ok(value) => Section::Ok(value),
+            Err(error) => Section::Error {
+                code: error.code(),
+                message: error.message(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let body = ErrorBody {
+            error: self.code(),
+            message: self.message(),
+            nexon_error: match &self {
+                AppError::Nexon { name, .. } => Some(name.clone()),
+                _ => None,
+            },
+        };
+
+        let status = self.status();
+        let nexon_error = body.nexon_error.as_deref();
+        if status.is_server_error() {
+            error!(
+                status = status.as_u16(),
+                code = body.error,
+                nexon_error,
+                reason = body.message,
+                "request failed"
+            );
+        } else if status.is_client_error() {
+            warn!(
+                status = status.as_u16(),
+                code = body.error,
+                nexon_error,
+                reason = body.message,
+                "request rejected"
+            );
+        }
+
+        let mut response = (status, Json(body)).into_response();
+        if let AppError::RateLimited { retry_after } | AppError::CircuitOpen { retry_after } = self
+        {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, retry_after.into());
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_keeps_error_code_and_message() {
+        let ok: Section<u8> = Ok(1).into();
+        let failed: Section<u8> = Err(AppError::Nexon {
+            name: "OPENAPI00004".to_string(),
+            message: "Please input valid parameter".to_string(),
+        })
+        .into();
+
+        assert!(ok.is_ok());
+        assert!(!failed.is_ok());
+        assert_eq!(
+            serde_json::to_value(&failed).unwrap(),
+            serde_json::json!({
+                "error": { "code": "invalid_parameter", "message": "Please input valid parameter" }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&ok).unwrap(),
+            serde_json::json!({ "ok": 1 })
+        );
+    }
+
+    #[test]
+    fn nexon_errors_map_to_status_and_code() {
+        let nexon = |name: &str| AppError::Nexon {
+            name: name.to_string(),
+            message: String::new(),
+        };
+
+        for (name, status, code) in [
+            ("OPENAPI00003", StatusCode::NOT_FOUND, "invalid_identifier"),
+            ("OPENAPI00004", StatusCode::BAD_REQUEST, "invalid_parameter"),
+            (
+                "OPENAPI00009",
+                StatusCode::SERVICE_UNAVAILABLE,
+                "upstream_unavailable",
+            ),
+            ("OPENAPI00001", StatusCode::BAD_GATEWAY, "upstream_error"),
+        ] {
+            assert_eq!(nexon(name).status(), status, "{name}");
+            assert_eq!(nexon(name).code(), code, "{name}");
+        }
+    }
+
+    #[test]
+    fn upstream_client_errors_become_bad_request() {
+        let upstream = |status| AppError::Upstream {
+            status,
+            message: String::new(),
+        };
+
+        assert_eq!(
+            upstream(StatusCode::NOT_FOUND).status(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            upstream(StatusCode::SERVICE_UNAVAILABLE).status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(
+            upstream(StatusCode::INTERNAL_SERVER_ERROR).status(),
+            StatusCode::BAD_GATEWAY
+        );
+    }
+}
@@ -1,55 +1,55 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, send, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
-use reqwest::{Client, header};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct GuildOcid {
     pub oguild_id: String,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct Character {
+pub struct Guild {
     guild_name: String,
-    wolrd_name: String,
+    #[serde(alias = "wolrdName")]
+    world_name: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getGuildOcid",
+    tag = "guild",
+    request_body = Guild,
+    responses((status = 200, body = GuildOcid))
+)]
 pub async fn get_guild_ocid(
     Extension(api_key): Extension<Arc<API>>,
-    Json(guild): Json<Character>,
-) -> Result<Json<GuildOcid>, (StatusCode, &'static str)> {
-    let client = Client::new();
-
+    Json(guild): Json<Guild>,
+) -> Result<Json<GuildOcid>, AppError> {
     // 요청할 API의 URL
-    let url = format!(
-        "https://open.api.nexon.com/maplestory/v1/guild/id?guild_name={}&world_name={}",
-        guild.guild_name, guild.wolrd_name
-    );
-
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
+    let url = api_key.url("guild/id");
 
     // POST 요청 보내기
-    let response = client
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
+    let response = send(
+        &api_key,
+        api_key.client.get(url).query(&[
+            ("guild_name", guild.guild_name.as_str()),
+            ("world_name", guild.world_name.as_str()),
+        ]),
+    )
+    .await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let userocid: GuildOcid = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let userocid: GuildOcid = parse_json(response).await?;
 
         Ok(Json(userocid))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
@@ -1,70 +1,80 @@
+use crate::api::date::{default_date, deserialize_date};
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, send, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
 use super::guild::GuildOcid;
 
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-use reqwest::{Client, header};
-
-#[derive(Serialize, Deserialize, Debug)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct GuildSkillInfo {
     skill_name: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     skill_description: String,
     skill_level: u8,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     skill_effect: String,
     skill_icon: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct GuildDefaultData {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     guild_name: String,
+    world_name: String,
     guild_level: u8,
     guild_fame: u32,
     guild_point: u64,
     guild_master_name: String,
     guild_member_count: u8,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     guild_member: Vec<String>,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     guild_skill: Vec<GuildSkillInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    guild_noblesse_skill: Vec<GuildSkillInfo>,
+    #[serde(default)]
+    guild_mark: Option<String>,
+    #[serde(default)]
+    guild_mark_custom: Option<String>, // base64 이미지
 }
 
+#[utoipa::path(
+    post,
+    path = "/getGuildInfo",
+    tag = "guild",
+    request_body = GuildOcid,
+    responses((status = 200, body = GuildDefaultData))
+)]
 pub async fn get_guild_default_info(
     Extension(api_key): Extension<Arc<API>>,
     Json(guild_ocid): Json<GuildOcid>,
-) -> Result<Json<GuildDefaultData>, (StatusCode, &'static str)> {
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
-
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+) -> Result<Json<GuildDefaultData>, AppError> {
+    let now_time = default_date();
 
-    let url = format!(
-        "https://open.api.nexon.com/maplestory/v1/guild/basic?oguild_id={}&date={}",
-        guild_ocid.oguild_id, now_time
-    );
+    let request = api_key
+        .client
+        .get(api_key.url("guild/basic"))
+        .query(&[("oguild_id", guild_ocid.oguild_id), ("date", now_time)]);
 
     // POST 요청 보내기
-    let response = Client::new()
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
+    let response = send(&api_key, request).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let guild_data: GuildDefaultData = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let guild_data: GuildDefaultData = parse_json(response).await?;
 
         Ok(Json(guild_data))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
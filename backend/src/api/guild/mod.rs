@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 pub mod guild;
 pub mod guild_default_info;
@@ -0,0 +1,122 @@
+use crate::api::circuit_breaker::BreakerState;
+use crate::api::error::AppError;
+use crate::api::key_pool::API_KEY_HEADER;
+use crate::api::query::Query;
+use crate::api::region::{Region, current_region};
+use crate::api::request::API;
+use crate::api::upstream::upstream_error;
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+use utoipa::{IntoParams, ToSchema};
+
+// 넥슨 API 키 오류 코드 (유효하지 않은 API 키)
+const INVALID_API_KEY: &str = "OPENAPI00005";
+
+#[derive(Deserialize, Debug, Default, IntoParams)]
+pub struct HealthQuery {
+    // true 인 경우 넥슨 API를 한 번 호출해 연결 상태를 확인
+    #[serde(default)]
+    deep: bool,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UpstreamHealth {
+    // 넥슨 API에 연결할 수 있는지
+    reachable: bool,
+    // API 키가 허용되었는지 (응답으로 판단할 수 없으면 null)
+    key_accepted: Option<bool>,
+    // 넥슨 API 응답 상태 코드 (연결 실패 시 null)
+    status: Option<u16>,
+    latency_ms: u64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct Health {
+    version: &'static str,
+    uptime_secs: u64,
+    // 현재 요청의 넥슨 API 지역
+    region: Region,
+    // 캐시된 넥슨 API 응답 수
+    cached_responses: usize,
+    cached_images: usize,
+    circuit_breaker: BreakerState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upstream: Option<UpstreamHealth>,
+}
+
+// 넥슨 API 연결 확인 (공지사항 목록 조회)
+// 재시도 / 서킷 브레이커를 거치지 않고 한 번만 호출한다
+async fn probe_upstream(api_key: &API) -> UpstreamHealth {
+    let key = api_key.keys.select();
+    key.limiter.acquire().await;
+
+    let started_at = Instant::now();
+    let result = api_key
+        .client
+        .get(api_key.url("notice"))
+        .header(API_KEY_HEADER, key.header())
+        .send()
+        .await;
+    let latency_ms = started_at.elapsed().as_millis() as u64;
+
+    let Ok(response) = result else {
+        return UpstreamHealth {
+            reachable: false,
+            key_accepted: None,
+            status: None,
+            latency_ms,
+        };
+    };
+
+    let status = response.status();
+    let key_accepted = if status.is_success() {
+        Some(true)
+    } else if status == reqwest::StatusCode::FORBIDDEN {
+        Some(false)
+    } else {
+        match upstream_error(response).await {
+            AppError::Nexon { name, .. } if name == INVALID_API_KEY => Some(false),
+            _ => None,
+        }
+    };
+
+    UpstreamHealth {
+        reachable: true,
+        key_accepted,
+        status: Some(status.as_u16()),
+        latency_ms,
+    }
+}
+
+// 로드 밸런서 / 모니터링용 헬스 체크
+// 기본 호출은 넥슨 API 호출 한도를 사용하지 않는다
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    params(HealthQuery),
+    responses((status = 200, body = Health))
+)]
+pub async fn get_health(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<HealthQuery>,
+) -> Json<Health> {
+    let upstream = if query.deep {
+        Some(probe_upstream(&api_key).await)
+    } else {
+        None
+    };
+
+    Json(Health {
+        version: env!("CARGO_PKG_VERSION"),
+        uptime_secs: api_key.started_at.elapsed().as_secs(),
+        region: current_region(),
+        cached_responses: api_key.cache.len(),
+        cached_images: api_key.image_cache.len(),
+        circuit_breaker: api_key.breaker.state(),
+        upstream,
+    })
+}
@@ -0,0 +1,79 @@
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use super::request::{HistoryQuery, request_parser};
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct PotentialOption {
+    value: String,
+    grade: String,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CubeHistoryInfo {
+    id: String,
+    character_name: String,
+    date_create: String,
+    cube_type: String,
+    item_upgrade_result: String,
+    miracle_time_flag: String,
+    item_equipment_part: String,
+    item_level: u16,
+    target_item: String,
+    potential_option_grade: String,
+    additional_potential_option_grade: String,
+    upgrade_guarantee: bool,
+    upgrade_guarantee_count: u16,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    before_potential_option: Vec<PotentialOption>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    before_additional_potential_option: Vec<PotentialOption>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    after_potential_option: Vec<PotentialOption>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    after_additional_potential_option: Vec<PotentialOption>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CubeHistory {
+    count: u32,
+    cube_history: Vec<CubeHistoryInfo>,
+    next_cursor: Option<String>, // 마지막 페이지인 경우 null
+}
+
+#[utoipa::path(
+    get,
+    path = "/getCubeHistory",
+    tag = "history",
+    params(HistoryQuery),
+    responses((status = 200, body = CubeHistory))
+)]
+pub async fn get_cube_history(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<CubeHistory>, AppError> {
+    // GET 요청 보내기
+    let response = request_parser(api_key, "cube", &query).await?;
+
+    // 응답 결과 확인
+    if !response.status().is_success() {
+        return Err(upstream_error(response).await);
+    }
+
+    let cube_history: CubeHistory = parse_json(response).await?;
+
+    Ok(Json(cube_history))
+}
@@ -0,0 +1,98 @@
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use super::get_cube_history::PotentialOption;
+use super::request::{HistoryQuery, request_parser};
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct PotentialHistoryInfo {
+    id: String,
+    character_name: String,
+    date_create: String,
+    potential_type: String,
+    item_upgrade_result: String,
+    miracle_time_flag: String,
+    item_equipment_part: String,
+    item_level: u16,
+    target_item: String,
+    potential_option_grade: String,
+    additional_potential_option_grade: String,
+    upgrade_guarantee: bool,
+    upgrade_guarantee_count: u16,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    before_potential_option: Vec<PotentialOption>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    before_additional_potential_option: Vec<PotentialOption>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    after_potential_option: Vec<PotentialOption>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    after_additional_potential_option: Vec<PotentialOption>,
+}
+
+// 현재 페이지 기준 요약
+#[derive(Serialize, Debug, Default, ToSchema)]
+pub struct PotentialSummary {
+    total: u32,
+    grade_up: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct PotentialHistory {
+    count: u32,
+    potential_history: Vec<PotentialHistoryInfo>,
+    next_cursor: Option<String>, // 마지막 페이지인 경우 null
+    #[serde(skip_deserializing)]
+    summary: PotentialSummary,
+}
+
+impl PotentialHistory {
+    fn summarize(&mut self) {
+        self.summary = PotentialSummary {
+            total: self.potential_history.len() as u32,
+            grade_up: self
+                .potential_history
+                .iter()
+                .filter(|history| history.item_upgrade_result == "등급 상승")
+                .count() as u32,
+        };
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/getPotentialHistory",
+    tag = "history",
+    params(HistoryQuery),
+    responses((status = 200, body = PotentialHistory))
+)]
+pub async fn get_potential_history(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<PotentialHistory>, AppError> {
+    // GET 요청 보내기
+    let response = request_parser(api_key, "potential", &query).await?;
+
+    // 응답 결과 확인
+    if !response.status().is_success() {
+        return Err(upstream_error(response).await);
+    }
+
+    let mut potential_history: PotentialHistory = parse_json(response).await?;
+
+    potential_history.summarize();
+
+    Ok(Json(potential_history))
+}
@@ -0,0 +1,115 @@
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
+
+use axum::{Extension, response::Json};
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use super::request::{HistoryQuery, request_parser};
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct StarforceEventInfo {
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    success_rate: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    cost_discount_rate: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    plus_value: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    starforce_event_range: String,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct StarforceHistoryInfo {
+    id: String,
+    item_upgrade_result: String,
+    before_starforce_count: u8,
+    after_starforce_count: u8,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    starcatch_result: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    superior_item_flag: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    destroy_defence: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    chance_time: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    event_field_flag: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    upgrade_item: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    protect_shield: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    bonus_stat_upgrade: String,
+    character_name: String,
+    world_name: String,
+    target_item: String,
+    date_create: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    starforce_event_list: Vec<StarforceEventInfo>, // 이벤트가 없는 경우 null
+}
+
+// 현재 페이지 기준 요약
+#[derive(Serialize, Debug, Default, ToSchema)]
+pub struct StarforceSummary {
+    total: u32,
+    success: u32,
+    destroy: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct StarforceHistory {
+    count: u32,
+    starforce_history: Vec<StarforceHistoryInfo>,
+    next_cursor: Option<String>, // 마지막 페이지인 경우 null
+    #[serde(skip_deserializing)]
+    summary: StarforceSummary,
+}
+
+impl StarforceHistory {
+    fn summarize(&mut self) {
+        let mut summary = StarforceSummary::default();
+        for history in &self.starforce_history {
+            summary.total += 1;
+            match history.item_upgrade_result.as_str() {
+                "성공" => summary.success += 1,
+                "파괴" => summary.destroy += 1,
+                _ => {}
+            }
+        }
+        self.summary = summary;
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/getStarforceHistory",
+    tag = "history",
+    params(HistoryQuery),
+    responses((status = 200, body = StarforceHistory))
+)]
+pub async fn get_starforce_history(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<StarforceHistory>, AppError> {
+    // GET 요청 보내기
+    let response = request_parser(api_key, "starforce", &query).await?;
+
+    // 응답 결과 확인
+    if !response.status().is_success() {
+        return Err(upstream_error(response).await);
+    }
+
+    let mut starforce_history: StarforceHistory = parse_json(response).await?;
+
+    starforce_history.summarize();
+
+    Ok(Json(starforce_history))
+}
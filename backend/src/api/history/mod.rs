@@ -0,0 +1,4 @@
+pub mod get_cube_history;
+pub mod get_potential_history;
+pub mod get_starforce_history;
+pub mod request;
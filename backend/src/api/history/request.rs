@@ -0,0 +1,60 @@
+use crate::api::date::validate_history_date;
+use crate::api::error::AppError;
+use crate::api::request::API;
+use crate::api::upstream::send;
+
+use chrono::Utc;
+use chrono_tz::Asia::Seoul;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+
+const MIN_COUNT: u16 = 10;
+const MAX_COUNT: u16 = 1000;
+
+fn default_count() -> u16 {
+    MIN_COUNT
+}
+
+// 히스토리 API 공통 페이지네이션 파라미터
+// cursor가 있으면 cursor로, 없으면 date로 조회한다
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct HistoryQuery {
+    #[serde(default = "default_count")]
+    count: u16,
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+// API 키 소유자 계정 기준으로 조회 (ocid 불필요)
+pub async fn request_parser(
+    api_key: Arc<API>,
+    kind: &str,
+    query: &HistoryQuery,
+) -> Result<reqwest::Response, AppError> {
+    if !(MIN_COUNT..=MAX_COUNT).contains(&query.count) {
+        return Err(AppError::InvalidInput("count must be between 10 and 1000"));
+    }
+
+    // 요청할 API의 URL
+    let url = api_key.url(&format!("history/{kind}"));
+
+    let mut params = vec![("count", query.count.to_string())];
+    match (&query.cursor, &query.date) {
+        (Some(cursor), _) => params.push(("cursor", cursor.clone())),
+        (None, Some(date)) => params.push(("date", validate_history_date(date)?)),
+        // 둘 다 없는 경우 오늘 (한국 시간) 기록 조회
+        (None, None) => params.push((
+            "date",
+            Utc::now()
+                .with_timezone(&Seoul)
+                .format("%Y-%m-%d")
+                .to_string(),
+        )),
+    }
+
+    // GET 요청 보내기
+    send(&api_key, api_key.client.get(url).query(&params)).await
+}
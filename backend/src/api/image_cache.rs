@@ -0,0 +1,297 @@
+use axum::body::Bytes;
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// (ocid, 이미지 옵션 쿼리, 조회 날짜)
+type ImageKey = (String, String, String);
+
+// 캐시한 이미지와 넥슨 응답의 Content-Type
+#[derive(Clone)]
+pub struct CachedImage {
+    pub content_type: String,
+    pub body: Bytes,
+}
+
+struct ImageEntry {
+    image: CachedImage,
+    stored_at: Instant,
+}
+
+// 캐릭터 이미지 캐시
+// 항목 수 대신 저장한 이미지 크기 합계로 제한하고,
+// 한 캐릭터의 이미지 옵션 조합(변형) 수도 제한해 한 캐릭터가 캐시를 모두 차지하지 못하게 한다
+pub struct ImageCache {
+    entries: DashMap<ImageKey, ImageEntry>,
+    // ocid → 저장한 변형 (이미지 옵션 쿼리, 조회 날짜), 오래된 순서
+    variants: DashMap<String, VecDeque<(String, String)>>,
+    ttl: Duration,
+    max_bytes: usize,
+    max_variants: usize,
+    total_bytes: AtomicUsize,
+}
+
+impl ImageCache {
+    pub fn new(ttl: Duration, max_bytes: usize, max_variants: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            variants: DashMap::new(),
+            ttl,
+            max_bytes,
+            max_variants: max_variants.max(1),
+            total_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn get(&self, key: &ImageKey) -> Option<CachedImage> {
+        let entry = self.entries.get(key)?;
+
+        if entry.stored_at.elapsed() > self.ttl {
+            drop(entry);
+            self.remove(key);
+            return None;
+        }
+
+        Some(entry.image.clone())
+    }
+
+    // 캐시 전체 크기보다 큰 이미지는 저장하지 않는다
+    pub fn insert(&self, key: ImageKey, image: CachedImage) {
+        let size = image.body.len();
+        if size > self.max_bytes {
+            return;
+        }
+
+        self.remove(&key);
+        self.evict(size);
+        self.add_variant(&key);
+
+        self.total_bytes.fetch_add(size, Ordering::Relaxed);
+        let replaced = self.entries.insert(
+            key,
+            ImageEntry {
+                image,
+                stored_at: Instant::now(),
+            },
+        );
+
+        // 같은 이미지를 동시에 받아 온 다른 요청이 먼저 저장한 경우 그 크기를 뺀다
+        if let Some(replaced) = replaced {
+            self.total_bytes
+                .fetch_sub(replaced.image.body.len(), Ordering::Relaxed);
+        }
+    }
+
+    fn remove(&self, key: &ImageKey) {
+        if let Some((_, entry)) = self.entries.remove(key) {
+            self.total_bytes
+                .fetch_sub(entry.image.body.len(), Ordering::Relaxed);
+        }
+
+        let (ocid, params, date) = key;
+        self.variants.remove_if_mut(ocid, |_, variants| {
+            variants.retain(|(p, d)| p != params || d != date);
+            variants.is_empty()
+        });
+    }
+
+    // 캐릭터의 변형 목록에 추가하고, 변형 수 제한을 넘으면 그 캐릭터의 가장 오래된 변형을 제거
+    fn add_variant(&self, key: &ImageKey) {
+        let (ocid, params, date) = key;
+        let variant = (params.clone(), date.clone());
+
+        let oldest = {
+            let mut variants = self.variants.entry(ocid.clone()).or_default();
+            if !variants.contains(&variant) {
+                variants.push_back(variant);
+            }
+            if variants.len() > self.max_variants {
+                variants.pop_front()
+            } else {
+                None
+            }
+        };
+
+        // 변형 목록의 잠금을 푼 뒤 제거 (remove 가 같은 목록을 다시 잠근다)
+        if let Some((params, date)) = oldest {
+            self.remove(&(ocid.clone(), params, date));
+        }
+    }
+
+    // 만료된 항목을 지우고, 새 이미지가 들어갈 공간이 생길 때까지 가장 오래된 항목을 제거
+    fn evict(&self, incoming: usize) {
+        if self.total_bytes.load(Ordering::Relaxed) + incoming <= self.max_bytes {
+            return;
+        }
+
+        let expired: Vec<ImageKey> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.stored_at.elapsed() > self.ttl)
+            .map(|entry| entry.key().clone())
+            .collect();
+        for key in &expired {
+            self.remove(key);
+        }
+
+        while self.total_bytes.load(Ordering::Relaxed) + incoming > self.max_bytes {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|entry| entry.stored_at)
+                .map(|entry| entry.key().clone());
+            match oldest {
+                Some(oldest) => self.remove(&oldest),
+                None => break,
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(ocid: &str) -> ImageKey {
+        (ocid.to_string(), String::new(), "2024-06-01".to_string())
+    }
+
+    fn image(size: usize) -> CachedImage {
+        CachedImage {
+            content_type: "image/png".to_string(),
+            body: Bytes::from(vec![0; size]),
+        }
+    }
+
+    #[test]
+    fn insert_same_key_keeps_byte_count() {
+        let cache = ImageCache::new(Duration::from_secs(60), 100, 10);
+
+        cache.insert(key("a"), image(10));
+        cache.insert(key("a"), image(30));
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.total_bytes.load(Ordering::Relaxed), 30);
+        assert_eq!(cache.get(&key("a")).unwrap().body.len(), 30);
+    }
+
+    #[test]
+    fn concurrent_inserts_of_same_key_keep_byte_count() {
+        let cache = ImageCache::new(Duration::from_secs(60), 1000, 10);
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        cache.insert(key("a"), image(10));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.total_bytes.load(Ordering::Relaxed), 10);
+    }
+
+    #[test]
+    fn evicts_oldest_when_full() {
+        let cache = ImageCache::new(Duration::from_secs(60), 25, 10);
+
+        cache.insert(key("a"), image(10));
+        cache.insert(key("b"), image(10));
+        cache.insert(key("c"), image(10));
+
+        assert!(cache.get(&key("a")).is_none());
+        assert!(cache.get(&key("c")).is_some());
+        assert_eq!(cache.total_bytes.load(Ordering::Relaxed), 20);
+    }
+
+    #[test]
+    fn caches_each_variant_of_a_character() {
+        let cache = ImageCache::new(Duration::from_secs(60), 100, 3);
+        let variant = |params: &str| {
+            (
+                "a".to_string(),
+                params.to_string(),
+                "2024-06-01".to_string(),
+            )
+        };
+
+        cache.insert(variant("action=A00"), image(10));
+        cache.insert(variant("action=A05"), image(20));
+
+        assert_eq!(cache.get(&variant("action=A00")).unwrap().body.len(), 10);
+        assert_eq!(cache.get(&variant("action=A05")).unwrap().body.len(), 20);
+        assert!(cache.get(&variant("action=A10")).is_none());
+    }
+
+    #[test]
+    fn evicts_oldest_variant_of_a_character_over_the_cap() {
+        let cache = ImageCache::new(Duration::from_secs(60), 1000, 2);
+        let variant = |ocid: &str, params: &str| {
+            (
+                ocid.to_string(),
+                params.to_string(),
+                "2024-06-01".to_string(),
+            )
+        };
+
+        cache.insert(variant("a", "action=A00"), image(10));
+        cache.insert(variant("b", "action=A00"), image(10));
+        cache.insert(variant("a", "action=A05"), image(10));
+        // 같은 변형을 다시 저장해도 변형 수는 늘지 않는다
+        cache.insert(variant("a", "action=A05"), image(10));
+        assert_eq!(cache.len(), 3);
+
+        cache.insert(variant("a", "action=A10"), image(10));
+
+        assert!(cache.get(&variant("a", "action=A00")).is_none());
+        assert!(cache.get(&variant("a", "action=A05")).is_some());
+        assert!(cache.get(&variant("a", "action=A10")).is_some());
+        // 다른 캐릭터의 이미지는 제한에 포함되지 않는다
+        assert!(cache.get(&variant("b", "action=A00")).is_some());
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.variants.get("a").unwrap().len(), 2);
+        assert_eq!(cache.total_bytes.load(Ordering::Relaxed), 30);
+    }
+
+    #[test]
+    fn removed_entries_leave_no_variants() {
+        let cache = ImageCache::new(Duration::ZERO, 100, 2);
+
+        cache.insert(key("a"), image(10));
+        std::thread::sleep(Duration::from_millis(2));
+        assert!(cache.get(&key("a")).is_none());
+
+        assert!(cache.variants.is_empty());
+    }
+
+    #[test]
+    fn skips_images_larger_than_cache() {
+        let cache = ImageCache::new(Duration::from_secs(60), 5, 10);
+
+        cache.insert(key("a"), image(10));
+
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn expired_entries_are_not_returned() {
+        let cache = ImageCache::new(Duration::ZERO, 100, 10);
+
+        cache.insert(key("a"), image(10));
+        std::thread::sleep(Duration::from_millis(2));
+
+        assert!(cache.get(&key("a")).is_none());
+        assert_eq!(cache.total_bytes.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::api::rate_limit::RateLimiter;
+
+use reqwest::header::{HeaderValue, InvalidHeaderValue};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+pub const API_KEY_HEADER: &str = "x-nxopen-api-key";
+
+// 키가 거부된 경우 (403) 다시 사용하기까지 대기 시간
+const REJECTED_BENCH: Duration = Duration::from_secs(60);
+
+// 로그 / 메트릭에 표시할 API 키 (마지막 4자리만 표시)
+pub fn mask_key(key: &str) -> String {
+    let visible = key.len().saturating_sub(4);
+    match key.get(visible..) {
+        Some(tail) if visible >= 8 => format!("****{tail}"),
+        _ => "****".to_string(),
+    }
+}
+
+pub struct ApiKey {
+    header: HeaderValue,
+    // 로그 / 메트릭에 표시할 이름 ("순번:****마지막 4자리")
+    pub label: String,
+    // 키마다 초당 호출 한도가 따로 적용된다
+    pub limiter: RateLimiter,
+    benched_until: Mutex<Option<Instant>>,
+}
+
+impl ApiKey {
+    pub fn header(&self) -> HeaderValue {
+        self.header.clone()
+    }
+
+    fn benched_until(&self) -> Option<Instant> {
+        let benched_until = self
+            .benched_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        benched_until.filter(|until| *until > Instant::now())
+    }
+
+    // 호출 한도를 넘긴 키를 retry_after 동안 제외
+    pub fn bench_rate_limited(&self, retry_after: Duration) {
+        self.bench(retry_after);
+    }
+
+    // 거부된 키 (403) 를 일정 시간 제외
+    pub fn bench_rejected(&self) {
+        self.bench(REJECTED_BENCH);
+    }
+
+    fn bench(&self, duration: Duration) {
+        let mut benched_until = self
+            .benched_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        *benched_until = Some(Instant::now() + duration);
+    }
+}
+
+// 여러 넥슨 API 키를 돌아가며 사용 (라운드 로빈)
+// 429 / 403 응답을 받은 키는 잠시 제외한다
+pub struct KeyPool {
+    keys: Vec<ApiKey>,
+    next: AtomicUsize,
+}
+
+impl KeyPool {
+    // 키가 헤더 값으로 사용할 수 없는 문자열이면 실패
+    pub fn new(keys: &[String], per_second: u32) -> Result<Self, InvalidHeaderValue> {
+        let keys = keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let mut header = HeaderValue::from_str(key)?;
+                header.set_sensitive(true);
+
+                Ok(ApiKey {
+                    header,
+                    label: format!("{}:{}", index + 1, mask_key(key)),
+                    limiter: RateLimiter::new(per_second),
+                    benched_until: Mutex::new(None),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            keys,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    // 다음 차례의 키 선택
+    // 모든 키가 제외된 상태면 가장 먼저 풀리는 키를 사용한다
+    pub fn select(&self) -> &ApiKey {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        (0..self.keys.len())
+            .map(|offset| &self.keys[(start + offset) % self.keys.len()])
+            .find(|key| key.benched_until().is_none())
+            .or_else(|| self.keys.iter().min_by_key(|key| key.benched_until()))
+            .expect("key pool is not empty")
+    }
+
+    // 제외되지 않은 키가 있는지 (다른 키로 바로 재시도할 수 있는지)
+    pub fn has_available(&self) -> bool {
+        self.keys.iter().any(|key| key.benched_until().is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(count: usize) -> KeyPool {
+        let keys: Vec<String> = (1..=count)
+            .map(|index| format!("test-key-000{index}"))
+            .collect();
+        KeyPool::new(&keys, 5).unwrap()
+    }
+
+    #[test]
+    fn mask_key_shows_only_last_four_characters() {
+        assert_eq!(mask_key("test-key-0001"), "****0001");
+        // 짧은 키는 전부 가린다
+        assert_eq!(mask_key("short-key"), "****");
+        assert_eq!(mask_key(""), "****");
+    }
+
+    #[test]
+    fn rejects_invalid_header_value() {
+        assert!(KeyPool::new(&["bad\nkey".to_string()], 5).is_err());
+    }
+
+    #[test]
+    fn selects_keys_in_turn() {
+        let pool = pool(3);
+        let labels: Vec<&str> = (0..4).map(|_| pool.select().label.as_str()).collect();
+
+        assert_eq!(
+            labels,
+            ["1:****0001", "2:****0002", "3:****0003", "1:****0001"]
+        );
+        assert!(pool.select().header().is_sensitive());
+    }
+
+    #[test]
+    fn skips_benched_keys() {
+        let pool = pool(2);
+        pool.keys[0].bench_rate_limited(Duration::from_secs(60));
+
+        for _ in 0..3 {
+            assert_eq!(pool.select().label, "2:****0002");
+        }
+        assert!(pool.has_available());
+    }
+
+    #[test]
+    fn uses_key_released_first_when_every_key_is_benched() {
+        let pool = pool(2);
+        pool.keys[0].bench_rejected();
+        pool.keys[1].bench_rate_limited(Duration::from_secs(1));
+
+        assert!(!pool.has_available());
+        assert_eq!(pool.select().label, "2:****0002");
+    }
+
+    #[test]
+    fn bench_expires() {
+        let pool = pool(1);
+        pool.keys[0].bench_rate_limited(Duration::ZERO);
+
+        assert!(pool.has_available());
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(feature = "server")]
+use crate::api::request::API;
+
+#[cfg(feature = "server")]
+use axum::{
+    Extension,
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use metrics::{counter, histogram};
+#[cfg(feature = "server")]
+use metrics_exporter_prometheus::{BuildError, PrometheusBuilder, PrometheusHandle};
+#[cfg(feature = "server")]
+use std::sync::Arc;
+use std::time::Instant;
+
+// 지연 시간 히스토그램 구간 (초)
+#[cfg(feature = "server")]
+const LATENCY_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+// 전역 Prometheus 레코더 설치 (프로세스당 한 번만 가능)
+#[cfg(feature = "server")]
+pub fn install_recorder() -> Result<PrometheusHandle, BuildError> {
+    PrometheusBuilder::new()
+        .set_buckets(&LATENCY_BUCKETS)?
+        .install_recorder()
+}
+
+// 상태 코드 구간 ("2xx", "4xx" 등)
+fn status_class(status: u16) -> String {
+    format!("{}xx", status / 100)
+}
+
+// 넥슨 API 호출 결과 기록 (응답을 받지 못한 경우 status = "error")
+pub fn record_upstream(path: &str, status: Option<u16>, started_at: Instant) {
+    let status = status.map_or_else(|| "error".to_string(), status_class);
+
+    counter!("melog_upstream_requests_total", "path" => path.to_string(), "status" => status)
+        .increment(1);
+    histogram!("melog_upstream_request_duration_seconds", "path" => path.to_string())
+        .record(started_at.elapsed().as_secs_f64());
+}
+
+// API 키별 호출 수 기록 (키는 마지막 4자리만 표시)
+pub fn record_key(key: &str, status: Option<u16>) {
+    let status = status.map_or_else(|| "error".to_string(), status_class);
+
+    counter!("melog_upstream_key_requests_total", "key" => key.to_string(), "status" => status)
+        .increment(1);
+}
+
+// 응답 캐시 조회 결과 기록 ("hit" / "miss")
+pub fn record_cache(path: &str, result: &'static str) {
+    counter!("melog_cache_requests_total", "path" => path.to_string(), "result" => result)
+        .increment(1);
+}
+
+// 라우트별 처리 시간 기록
+// 라우터에 등록되지 않은 경로는 라벨이 늘어나지 않도록 "unmatched"로 묶는다
+#[cfg(feature = "server")]
+pub async fn track_metrics(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", MatchedPath::as_str)
+        .to_string();
+    let method = request.method().to_string();
+    let started_at = Instant::now();
+
+    let response = next.run(request).await;
+    let status = status_class(response.status().as_u16());
+
+    counter!(
+        "melog_http_requests_total",
+        "route" => route.clone(),
+        "method" => method.clone(),
+        "status" => status
+    )
+    .increment(1);
+    histogram!("melog_http_request_duration_seconds", "route" => route, "method" => method)
+        .record(started_at.elapsed().as_secs_f64());
+
+    response
+}
+
+// GET /metrics (Prometheus 텍스트 형식)
+#[cfg(feature = "server")]
+pub async fn get_metrics(Extension(api_key): Extension<Arc<API>>) -> String {
+    api_key.metrics.render()
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    #[test]
+    fn status_class_groups_by_hundreds() {
+        assert_eq!(status_class(200), "2xx");
+        assert_eq!(status_class(404), "4xx");
+        assert_eq!(status_class(503), "5xx");
+    }
+
+    #[test]
+    fn records_upstream_key_and_cache_counters() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            record_upstream("character/basic", Some(200), Instant::now());
+            record_upstream("character/basic", None, Instant::now());
+            record_key("****abcd", Some(429));
+            record_cache("character/basic", "hit");
+        });
+        let rendered = handle.render();
+
+        assert!(
+            rendered.contains(
+                r#"melog_upstream_requests_total{path="character/basic",status="2xx"} 1"#
+            )
+        );
+        assert!(
+            rendered.contains(
+                r#"melog_upstream_requests_total{path="character/basic",status="error"} 1"#
+            )
+        );
+        assert!(
+            rendered
+                .contains(r#"melog_upstream_key_requests_total{key="****abcd",status="4xx"} 1"#)
+        );
+        assert!(
+            rendered
+                .contains(r#"melog_cache_requests_total{path="character/basic",result="hit"} 1"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn track_metrics_labels_by_matched_route() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let app = Router::new()
+            .route("/guild/{id}", get(|| async { "ok" }))
+            .layer(middleware::from_fn(track_metrics));
+        for uri in ["/guild/1", "/guild/2", "/unknown/path"] {
+            let request = Request::get(uri).body(Body::empty()).unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+        let rendered = handle.render();
+
+        assert!(rendered.contains(
+            r#"melog_http_requests_total{route="/guild/{id}",method="GET",status="2xx"} 2"#
+        ));
+        assert!(rendered.contains(
+            r#"melog_http_requests_total{route="unmatched",method="GET",status="4xx"} 1"#
+        ));
+    }
+}
@@ -1,6 +1,50 @@
+// 넥슨 API 클라이언트, 파서, 계산기 (서버 없이 라이브러리로 사용 가능)
+pub mod cache;
+pub mod canonical;
 pub mod character;
+pub mod circuit_breaker;
+pub mod client;
+pub mod config;
+pub mod date;
+pub mod error;
+pub mod key_pool;
+pub mod metrics;
+pub mod rate_limit;
+pub mod region;
+pub mod schema;
+pub mod trace;
+pub mod upstream;
+
+// HTTP 서버 (핸들러, 미들웨어)
+#[cfg(feature = "server")]
+pub mod account;
+#[cfg(feature = "server")]
+pub mod client_limit;
+#[cfg(feature = "server")]
+pub mod compact;
+#[cfg(feature = "server")]
+pub mod compression;
+#[cfg(feature = "server")]
+pub mod demo;
+#[cfg(feature = "server")]
 pub mod guild;
+#[cfg(feature = "server")]
+pub mod health;
+#[cfg(feature = "server")]
+pub mod history;
+#[cfg(feature = "server")]
+pub mod image_cache;
+#[cfg(feature = "server")]
 pub mod notice;
+#[cfg(feature = "server")]
+pub mod openapi;
+#[cfg(feature = "server")]
+pub mod query;
+#[cfg(feature = "server")]
 pub mod ranking;
+#[cfg(feature = "server")]
 pub mod request;
+#[cfg(feature = "server")]
+pub mod shutdown;
+#[cfg(feature = "server")]
 pub mod union;
@@ -0,0 +1,64 @@
+use crate::api::error::AppError;
+use crate::api::request::API;
+
+use axum::{Extension, http::StatusCode, response::Json};
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+use super::get_cash_shop_notice::{CashShopNotice, get_cash_shop_notice};
+use super::get_event_notice::{EvnetNotice, get_event_notice};
+use super::get_notice::{Notice, get_notice};
+use super::get_update_notice::{UpdateNotice, get_update_notice};
+
+// 공지사항 / 업데이트 / 이벤트 / 캐시샵 공지를 한 번에 반환
+// 일부 조회에 실패한 경우 해당 목록만 빠진다
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AllNotice {
+    #[serde(flatten)]
+    notice: Option<Notice>,
+    #[serde(flatten)]
+    update_notice: Option<UpdateNotice>,
+    #[serde(flatten)]
+    event_notice: Option<EvnetNotice>,
+    #[serde(flatten)]
+    cashshop_notice: Option<CashShopNotice>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/getAllNotice",
+    tag = "notice",
+    responses((status = 200, body = AllNotice))
+)]
+pub async fn get_all_notice(
+    Extension(api_key): Extension<Arc<API>>,
+) -> Result<Json<AllNotice>, AppError> {
+    // 네 목록을 동시에 요청
+    let (notice, update_notice, event_notice, cashshop_notice) = tokio::join!(
+        get_notice(Extension(api_key.clone())),
+        get_update_notice(Extension(api_key.clone())),
+        get_event_notice(Extension(api_key.clone())),
+        get_cash_shop_notice(Extension(api_key)),
+    );
+
+    let all_notice = AllNotice {
+        notice: notice.ok().map(|Json(notice)| notice),
+        update_notice: update_notice.ok().map(|Json(notice)| notice),
+        event_notice: event_notice.ok().map(|Json(notice)| notice),
+        cashshop_notice: cashshop_notice.ok().map(|Json(notice)| notice),
+    };
+
+    if all_notice.notice.is_none()
+        && all_notice.update_notice.is_none()
+        && all_notice.event_notice.is_none()
+        && all_notice.cashshop_notice.is_none()
+    {
+        return Err(AppError::Upstream {
+            status: StatusCode::BAD_GATEWAY,
+            message: "Failed to fetch notice".to_string(),
+        });
+    }
+
+    Ok(Json(all_notice))
+}
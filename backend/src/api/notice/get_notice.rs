@@ -1,11 +1,13 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, send, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
-use reqwest::{Client, header};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct NoticeInfo {
     title: String,
     url: String,
@@ -13,40 +15,30 @@ pub struct NoticeInfo {
     date: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct Notice {
     notice: Vec<NoticeInfo>,
 }
 
-pub async fn get_notice(
-    Extension(api_key): Extension<Arc<API>>,
-) -> Result<Json<Notice>, (StatusCode, &'static str)> {
-    let client = Client::new();
-
+#[utoipa::path(
+    get,
+    path = "/getNotice",
+    tag = "notice",
+    responses((status = 200, body = Notice))
+)]
+pub async fn get_notice(Extension(api_key): Extension<Arc<API>>) -> Result<Json<Notice>, AppError> {
     // 요청할 API의 URL
-    let url = format!("https://open.api.nexon.com/maplestory/v1/notice");
-
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
+    let url = api_key.url("notice");
 
     // POST 요청 보내기
-    let response = client
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
+    let response = send(&api_key, api_key.client.get(url)).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let notice: Notice = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let notice: Notice = parse_json(response).await?;
 
         Ok(Json(notice))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
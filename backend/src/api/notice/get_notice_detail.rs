@@ -0,0 +1,150 @@
+use crate::api::date::deserialize_datetime;
+use crate::api::error::AppError;
+use crate::api::query::Query;
+use crate::api::request::API;
+use crate::api::upstream::{parse_json, send};
+
+use axum::{Extension, response::Json};
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::Asia::Seoul;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct NoticeDetailQuery {
+    notice_id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct NoticeDetail {
+    title: String,
+    url: String,
+    contents: String, // HTML
+    date: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct EventNoticeDetail {
+    title: String,
+    url: String,
+    contents: String, // HTML
+    date: String,
+    #[serde(deserialize_with = "deserialize_datetime")]
+    date_event_start: DateTime<FixedOffset>,
+    #[serde(deserialize_with = "deserialize_datetime")]
+    date_event_end: DateTime<FixedOffset>,
+    // 현재 (한국 시간) 진행 중인 이벤트인지 여부
+    #[serde(skip_deserializing)]
+    is_active: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct CashShopNoticeDetail {
+    title: String,
+    url: String,
+    contents: String, // HTML
+    date: String,
+    date_sale_start: Option<String>, // 상시 판매인 경우 null
+    date_sale_end: Option<String>,   // 상시 판매인 경우 null
+    ongoing_flag: String,
+}
+
+async fn fetch_notice_detail<T: DeserializeOwned>(
+    api_key: Arc<API>,
+    kind: &str,
+    notice_id: &str,
+) -> Result<T, AppError> {
+    // 숫자가 아닌 notice_id는 존재하지 않는 공지로 처리
+    let Ok(notice_id) = notice_id.parse::<u32>() else {
+        return Err(AppError::NotFound("Notice not found"));
+    };
+
+    // 요청할 API의 URL
+    let url = api_key.url(&format!("{kind}/detail"));
+
+    // POST 요청 보내기
+    let response = send(
+        &api_key,
+        api_key.client.get(url).query(&[("notice_id", notice_id)]),
+    )
+    .await?;
+
+    // 응답 결과 확인
+    if response.status().is_success() {
+        let notice: T = parse_json(response).await?;
+
+        Ok(notice)
+    } else {
+        // 존재하지 않는 notice_id는 넥슨 API에서 400으로 응답
+        Err(AppError::NotFound("Notice not found"))
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/getNoticeDetail",
+    tag = "notice",
+    params(NoticeDetailQuery),
+    responses((status = 200, body = NoticeDetail))
+)]
+pub async fn get_notice_detail(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<NoticeDetailQuery>,
+) -> Result<Json<NoticeDetail>, AppError> {
+    fetch_notice_detail(api_key, "notice", &query.notice_id)
+        .await
+        .map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/getUpdateNoticeDetail",
+    tag = "notice",
+    params(NoticeDetailQuery),
+    responses((status = 200, body = NoticeDetail))
+)]
+pub async fn get_update_notice_detail(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<NoticeDetailQuery>,
+) -> Result<Json<NoticeDetail>, AppError> {
+    fetch_notice_detail(api_key, "notice-update", &query.notice_id)
+        .await
+        .map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/getEventNoticeDetail",
+    tag = "notice",
+    params(NoticeDetailQuery),
+    responses((status = 200, body = EventNoticeDetail))
+)]
+pub async fn get_event_notice_detail(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<NoticeDetailQuery>,
+) -> Result<Json<EventNoticeDetail>, AppError> {
+    let mut notice: EventNoticeDetail =
+        fetch_notice_detail(api_key, "notice-event", &query.notice_id).await?;
+
+    let now = Utc::now().with_timezone(&Seoul);
+    notice.is_active = notice.date_event_start <= now && now <= notice.date_event_end;
+
+    Ok(Json(notice))
+}
+
+#[utoipa::path(
+    get,
+    path = "/getCashShopNoticeDetail",
+    tag = "notice",
+    params(NoticeDetailQuery),
+    responses((status = 200, body = CashShopNoticeDetail))
+)]
+pub async fn get_cash_shop_notice_detail(
+    Extension(api_key): Extension<Arc<API>>,
+    Query(query): Query<NoticeDetailQuery>,
+) -> Result<Json<CashShopNoticeDetail>, AppError> {
+    fetch_notice_detail(api_key, "notice-cashshop", &query.notice_id)
+        .await
+        .map(Json)
+}
@@ -1,45 +1,39 @@
+use crate::api::error::AppError;
 use crate::api::notice::get_notice::NoticeInfo;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, send, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
-use reqwest::{Client, header};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct UpdateNotice {
     update_notice: Vec<NoticeInfo>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/getUpdateNotice",
+    tag = "notice",
+    responses((status = 200, body = UpdateNotice))
+)]
 pub async fn get_update_notice(
     Extension(api_key): Extension<Arc<API>>,
-) -> Result<Json<UpdateNotice>, (StatusCode, &'static str)> {
-    let client = Client::new();
-
+) -> Result<Json<UpdateNotice>, AppError> {
     // 요청할 API의 URL
-    let url = format!("https://open.api.nexon.com/maplestory/v1/notice-update");
-
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
+    let url = api_key.url("notice-update");
 
     // POST 요청 보내기
-    let response = client
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
+    let response = send(&api_key, api_key.client.get(url)).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let notice: UpdateNotice = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let notice: UpdateNotice = parse_json(response).await?;
 
         Ok(Json(notice))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
@@ -1,4 +1,6 @@
+pub mod get_all_notice;
 pub mod get_cash_shop_notice;
 pub mod get_event_notice;
 pub mod get_notice;
+pub mod get_notice_detail;
 pub mod get_update_notice;
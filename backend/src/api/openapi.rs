@@ -0,0 +1,139 @@
+use crate::api::error::ErrorBody;
+use crate::api::request::ErrorResponse;
+use crate::api::{account, character, guild, health, history, notice, ranking, union};
+
+use axum::response::{Html, Json};
+use utoipa::openapi::{ContentBuilder, Ref, ResponseBuilder};
+use utoipa::{Modify, OpenApi};
+
+// 핸들러 타입에서 생성하는 OpenAPI 문서
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        account::get_character_list::get_character_list,
+        character::character::get_ocid,
+        character::user_ability::get_user_ability,
+        character::user_android_equipment::get_user_android_equipment,
+        character::user_beauty_equipment::get_user_beauty_equipment,
+        character::user_cashitem_equipment::get_user_cash_item_equipment,
+        character::user_characeter_skill::get_user_characeter_link_skill,
+        character::user_characeter_skill::get_user_characeter_skill,
+        character::user_characeter_skill::get_user_character_skill_all,
+        character::user_character_image::get_character_image,
+        character::user_default_info::get_character_image_url,
+        character::user_default_info::get_user_default_info,
+        character::user_digest::get_user_digest,
+        character::user_dojang::get_user_dojang,
+        character::user_hexa_matrix::get_user_hexa_matrix,
+        character::user_hexa_matrix_stat::get_user_hexa_stat_info,
+        character::user_hyper_stat_info::get_user_hyper_stat_info,
+        character::user_hyper_stat_info::get_user_hyper_stat_info_v2,
+        character::user_item_equipment::get_user_item_equipment,
+        character::user_item_equipment::get_user_item_equipment_totals,
+        character::user_pet_equipment::get_user_pet_equipment,
+        character::user_popularity::get_user_popularity,
+        character::user_propensity::get_user_propensity,
+        character::user_set_effect::get_user_set_effect,
+        character::user_set_effect::get_user_set_effect_v2,
+        character::user_stat_info::get_user_stat_info,
+        character::user_summary::get_user_summary,
+        character::user_symbol_equipment::get_user_symbol_equipment,
+        character::user_v_matrix::get_user_v_matrix,
+        guild::guild::get_guild_ocid,
+        guild::guild_default_info::get_guild_default_info,
+        health::get_health,
+        history::get_cube_history::get_cube_history,
+        history::get_potential_history::get_potential_history,
+        history::get_starforce_history::get_starforce_history,
+        notice::get_all_notice::get_all_notice,
+        notice::get_cash_shop_notice::get_cash_shop_notice,
+        notice::get_event_notice::get_event_notice,
+        notice::get_notice::get_notice,
+        notice::get_notice_detail::get_cash_shop_notice_detail,
+        notice::get_notice_detail::get_event_notice_detail,
+        notice::get_notice_detail::get_notice_detail,
+        notice::get_notice_detail::get_update_notice_detail,
+        notice::get_update_notice::get_update_notice,
+        ranking::get_achievement_ranking::get_achievement_ranking,
+        ranking::get_dojang_ranking::get_dojang_ranking,
+        ranking::get_guild_ranking::get_guild_ranking,
+        ranking::get_overall_ranking::get_over_all_ranking,
+        ranking::get_theseed_ranking::get_theseed_ranking,
+        ranking::get_union_ranking::get_union_ranking,
+        union::get_union::get_user_union_info,
+        union::get_union_artifact::get_user_union_artifact_info,
+        union::get_union_champion::get_user_union_champion_info,
+        union::get_union_raider::get_user_union_raider_info,
+    ),
+    components(schemas(ErrorBody, ErrorResponse)),
+    modifiers(&ErrorResponses),
+    tags(
+        (name = "account", description = "계정"),
+        (name = "character", description = "캐릭터 정보"),
+        (name = "guild", description = "길드"),
+        (name = "health", description = "헬스 체크"),
+        (name = "history", description = "큐브 / 스타포스 / 잠재능력 사용 기록"),
+        (name = "notice", description = "공지사항"),
+        (name = "ranking", description = "랭킹"),
+        (name = "union", description = "유니온"),
+    )
+)]
+pub struct ApiDoc;
+
+// 모든 경로에 공통 오류 응답 (4XX / 5XX, ErrorBody) 추가
+struct ErrorResponses;
+
+impl Modify for ErrorResponses {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let error_response = |description: &str| {
+            ResponseBuilder::new()
+                .description(description)
+                .content(
+                    "application/json",
+                    ContentBuilder::new()
+                        .schema(Some(Ref::from_schema_name("ErrorBody")))
+                        .build(),
+                )
+                .build()
+        };
+
+        for item in openapi.paths.paths.values_mut() {
+            for operation in [&mut item.get, &mut item.post].into_iter().flatten() {
+                let responses = &mut operation.responses.responses;
+                responses
+                    .entry("4XX".to_string())
+                    .or_insert_with(|| error_response("잘못된 요청").into());
+                responses
+                    .entry("5XX".to_string())
+                    .or_insert_with(|| error_response("넥슨 API 오류").into());
+            }
+        }
+    }
+}
+
+// GET /api-docs/openapi.json
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+// GET /swagger-ui (정적 파일은 CDN에서 불러온다)
+pub async fn swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI)
+}
+
+const SWAGGER_UI: &str = r##"<!DOCTYPE html>
+<html lang="ko">
+  <head>
+    <meta charset="utf-8" />
+    <title>melog API</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+    <script>
+      window.ui = SwaggerUIBundle({ url: "/api-docs/openapi.json", dom_id: "#swagger-ui" });
+    </script>
+  </body>
+</html>
+"##;
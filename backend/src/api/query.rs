@@ -0,0 +1,64 @@
+use crate::api::error::AppError;
+
+use axum::{extract::FromRequestParts, http::request::Parts};
+use serde::de::DeserializeOwned;
+
+// axum Query 대신 사용하는 쿼리 문자열 추출기
+// 해석에 실패하면 axum 의 텍스트 응답 대신 다른 오류와 같은 JSON 형식 (400 invalid_input) 으로 응답한다
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Query<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for Query<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        axum::extract::Query::<T>::from_request_parts(parts, state)
+            .await
+            .map(|axum::extract::Query(value)| Query(value))
+            .map_err(|rejection| AppError::InvalidQuery(rejection.body_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct StarQuery {
+        min_star: Option<u8>,
+    }
+
+    async fn extract(uri: &str) -> Result<StarQuery, AppError> {
+        let (mut parts, _) = Request::get(uri).body(()).unwrap().into_parts();
+        Query::<StarQuery>::from_request_parts(&mut parts, &())
+            .await
+            .map(|Query(query)| query)
+    }
+
+    #[tokio::test]
+    async fn parses_query_string() {
+        assert_eq!(
+            extract("/items?min_star=17").await.unwrap(),
+            StarQuery { min_star: Some(17) }
+        );
+        assert_eq!(
+            extract("/items").await.unwrap(),
+            StarQuery { min_star: None }
+        );
+    }
+
+    #[tokio::test]
+    async fn rejection_becomes_invalid_input() {
+        let error = extract("/items?min_star=abc").await.unwrap_err();
+
+        assert_eq!(error.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(error.code(), "invalid_input");
+        assert!(error.message().contains("min_star"), "{}", error.message());
+    }
+}
@@ -0,0 +1,23 @@
+use crate::api::date::{QueryDate, deserialize_date};
+use crate::api::error::AppError;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// 캐릭터 랭킹 응답의 공통 필드
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct RankingCharacterInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    ranking: u32,
+    character_name: String,
+    world_name: String,
+    class_name: String,
+    sub_class_name: String,
+}
+
+// 조회 날짜 (지정하지 않은 경우 전날)
+// 지정한 날짜는 캐릭터 조회와 같은 규칙 (validate_date) 으로 확인한다
+pub fn ranking_date(date: Option<&str>) -> Result<String, AppError> {
+    QueryDate::resolve(date).map(|date| date.to_string())
+}
@@ -1,74 +1,82 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-
+use super::common::{RankingCharacterInfo, ranking_date};
 use super::request::request_parser;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = AchievementRankingRequest)]
 pub struct Achievement {
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    world_name: Option<String>,
     #[serde(default)]
     ocid: Option<String>,
     #[serde(default)]
     page: Option<i32>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = AchievementRankingInfo)]
 pub struct RankingInfo {
-    ranking: u32,
+    #[serde(flatten)]
+    character: RankingCharacterInfo,
     trophy_score: u32,
     trophy_grade: String,
-    character_name: String,
-    world_name: String,
-    class_name: String,
-    sub_class_name: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Ranking {
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct AchievementRanking {
+    #[serde(default)]
+    page: i32,
     ranking: Vec<RankingInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getAchievementRanking",
+    tag = "ranking",
+    request_body = Achievement,
+    responses((status = 200, body = AchievementRanking))
+)]
 pub async fn get_achievement_ranking(
     Extension(api_key): Extension<Arc<API>>,
     Json(achievement): Json<Achievement>,
-) -> Result<Json<Ranking>, (StatusCode, &'static str)> {
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+) -> Result<Json<AchievementRanking>, AppError> {
+    let now_time = ranking_date(achievement.date.as_deref())?;
 
-    // 요청할 API의 URL
-    let mut url = format!(
-        "https://open.api.nexon.com/maplestory/v1/ranking/achievement?date={}",
-        now_time,
-    );
+    // 쿼리 파라미터 (값은 reqwest에서 인코딩)
+    let mut params = vec![("date", now_time)];
 
-    {
-        // 값이 존재하는 경우에만 파라미터 추가
-        if let Some(ref ocid_val) = achievement.ocid {
-            url.push_str(&format!("&ocid={ocid_val}"));
-        }
-        if let Some(page) = achievement.page {
-            url.push_str(&format!("&page={page}"));
-        }
+    // 값이 존재하는 경우에만 파라미터 추가
+    if let Some(ref world_name) = achievement.world_name {
+        params.push(("world_name", world_name.clone()));
+    }
+    if let Some(ref ocid) = achievement.ocid {
+        params.push(("ocid", ocid.clone()));
+    }
+    if let Some(page) = achievement.page {
+        params.push(("page", page.to_string()));
     }
 
     // POST 요청 보내기
-    let response = request_parser(api_key, &url).await;
+    let response = request_parser(api_key, "ranking/achievement", &params).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let ranking: Ranking = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let mut ranking: AchievementRanking = parse_json(response).await?;
+
+        ranking.page = achievement.page.unwrap_or(1);
 
         Ok(Json(ranking))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
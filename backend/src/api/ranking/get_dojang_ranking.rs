@@ -1,16 +1,20 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-
+use super::common::{RankingCharacterInfo, ranking_date};
 use super::request::request_parser;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = DojangRankingRequest)]
 pub struct Dojang {
+    #[serde(default)]
+    date: Option<String>,
     #[serde(default)]
     world_name: Option<String>,
     difficulty: i8,
@@ -22,65 +26,68 @@ pub struct Dojang {
     page: Option<i32>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = DojangRankingInfo)]
 pub struct RankingInfo {
-    ranking: u32,
+    #[serde(flatten)]
+    character: RankingCharacterInfo,
     dojang_floor: u8,
     dojang_time_record: u16,
-    character_name: String,
-    world_name: String,
-    class_name: String,
-    sub_class_name: String,
     character_level: u16,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = DojangRanking)]
 pub struct Ranking {
+    #[serde(default)]
+    page: i32,
     ranking: Vec<RankingInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getDojangRanking",
+    tag = "ranking",
+    request_body = Dojang,
+    responses((status = 200, body = Ranking))
+)]
 pub async fn get_dojang_ranking(
     Extension(api_key): Extension<Arc<API>>,
     Json(dojang): Json<Dojang>,
-) -> Result<Json<Ranking>, (StatusCode, &'static str)> {
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+) -> Result<Json<Ranking>, AppError> {
+    let now_time = ranking_date(dojang.date.as_deref())?;
 
-    // 요청할 API의 URL
-    let mut url = format!(
-        "https://open.api.nexon.com/maplestory/v1/ranking/dojang?date={}&difficulty={}",
-        now_time, dojang.difficulty
-    );
+    // 쿼리 파라미터 (값은 reqwest에서 인코딩)
+    let mut params = vec![
+        ("date", now_time),
+        ("difficulty", dojang.difficulty.to_string()),
+    ];
 
-    {
-        // 값이 존재하는 경우에만 파라미터 추가
-        if let Some(ref world_name) = dojang.world_name {
-            url.push_str(&format!("&world_name={world_name}"));
-        }
-        if let Some(ref class) = dojang.class {
-            url.push_str(&format!("&class={class}"));
-        }
-        if let Some(ref ocid_val) = dojang.ocid {
-            url.push_str(&format!("&ocid={ocid_val}"));
-        }
-        if let Some(page) = dojang.page {
-            url.push_str(&format!("&page={page}"));
-        }
+    // 값이 존재하는 경우에만 파라미터 추가
+    if let Some(ref world_name) = dojang.world_name {
+        params.push(("world_name", world_name.clone()));
+    }
+    if let Some(ref class) = dojang.class {
+        params.push(("class", class.clone()));
+    }
+    if let Some(ref ocid) = dojang.ocid {
+        params.push(("ocid", ocid.clone()));
+    }
+    if let Some(page) = dojang.page {
+        params.push(("page", page.to_string()));
     }
 
     // POST 요청 보내기
-    let response = request_parser(api_key, &url).await;
+    let response = request_parser(api_key, "ranking/dojang", &params).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let ranking: Ranking = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let mut ranking: Ranking = parse_json(response).await?;
+
+        ranking.page = dojang.page.unwrap_or(1);
 
         Ok(Json(ranking))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
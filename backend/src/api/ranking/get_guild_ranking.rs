@@ -1,80 +1,93 @@
+use crate::api::date::deserialize_date;
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-
+use super::common::ranking_date;
 use super::request::request_parser;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = GuildRankingRequest)]
 pub struct Guild {
+    #[serde(default)]
+    date: Option<String>,
     #[serde(default)]
     world_name: Option<String>,
-    ranking_type: u8,
+    ranking_type: u8, // 0: 주간 명성치, 1: 플래그 레이스, 2: 지하 수로
     #[serde(default)]
     guild_name: Option<String>,
     #[serde(default)]
     page: Option<i32>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = GuildRankingInfo)]
 pub struct RankingInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     world_name: String,
     guild_name: String,
     guild_level: u8,
-    guild_mark: String,
+    guild_mark: Option<String>,        // 없는 경우 null
+    guild_mark_custom: Option<String>, // 커스텀 마크 (base64), 없는 경우 null
     guild_point: u32,
     ranking: u32,
     guild_master_name: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Ranking {
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct GuildRanking {
+    #[serde(default)]
+    page: i32,
     ranking: Vec<RankingInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getGuildRanking",
+    tag = "ranking",
+    request_body = Guild,
+    responses((status = 200, body = GuildRanking))
+)]
 pub async fn get_guild_ranking(
     Extension(api_key): Extension<Arc<API>>,
     Json(guild): Json<Guild>,
-) -> Result<Json<Ranking>, (StatusCode, &'static str)> {
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+) -> Result<Json<GuildRanking>, AppError> {
+    let now_time = ranking_date(guild.date.as_deref())?;
 
-    // 요청할 API의 URL
-    let mut url = format!(
-        "https://open.api.nexon.com/maplestory/v1/ranking/guild?date={}&ranking_type={}",
-        now_time, guild.ranking_type
-    );
+    // 쿼리 파라미터 (값은 reqwest에서 인코딩)
+    let mut params = vec![
+        ("date", now_time),
+        ("ranking_type", guild.ranking_type.to_string()),
+    ];
 
-    {
-        // 값이 존재하는 경우에만 파라미터 추가
-        if let Some(ref world_name) = guild.world_name {
-            url.push_str(&format!("&world_name={world_name}"));
-        }
-        if let Some(ref guild_name) = guild.guild_name {
-            url.push_str(&format!("&guild_name={guild_name}"));
-        }
-        if let Some(page) = guild.page {
-            url.push_str(&format!("&page={page}"));
-        }
+    // 값이 존재하는 경우에만 파라미터 추가
+    if let Some(ref world_name) = guild.world_name {
+        params.push(("world_name", world_name.clone()));
+    }
+    if let Some(ref guild_name) = guild.guild_name {
+        params.push(("guild_name", guild_name.clone()));
+    }
+    if let Some(page) = guild.page {
+        params.push(("page", page.to_string()));
     }
 
     // POST 요청 보내기
-    let response = request_parser(api_key, &url).await;
+    let response = request_parser(api_key, "ranking/guild", &params).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let ranking: Ranking = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let mut ranking: GuildRanking = parse_json(response).await?;
+
+        ranking.page = guild.page.unwrap_or(1);
 
         Ok(Json(ranking))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
@@ -1,17 +1,21 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
-
+use super::common::{RankingCharacterInfo, ranking_date};
 use super::request::request_parser;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = OverallRankingRequest)]
 pub struct OverAll {
+    #[serde(default)]
+    date: Option<String>,
     #[serde(default)]
     world_name: Option<String>,
     #[serde(default)]
@@ -25,70 +29,71 @@ pub struct OverAll {
 }
 
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = OverallRankingInfo)]
 pub struct RankingInfo {
-    world_name: String,
-    ranking: u32,
-    character_name: String,
+    #[serde(flatten)]
+    character: RankingCharacterInfo,
     character_level: u16,
     character_exp: u64,
-    class_name: String,
-    sub_class_name: String,
     character_popularity: i32,
     #[serde_as(deserialize_as = "DefaultOnNull")]
     character_guildname: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = OverallRanking)]
 pub struct Ranking {
+    #[serde(default)]
+    page: i32,
     ranking: Vec<RankingInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getOverAllRanking",
+    tag = "ranking",
+    request_body = OverAll,
+    responses((status = 200, body = Ranking))
+)]
 pub async fn get_over_all_ranking(
     Extension(api_key): Extension<Arc<API>>,
     Json(over_all): Json<OverAll>,
-) -> Result<Json<Ranking>, (StatusCode, &'static str)> {
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+) -> Result<Json<Ranking>, AppError> {
+    let now_time = ranking_date(over_all.date.as_deref())?;
 
-    // 요청할 API의 URL
-    let mut url = format!(
-        "https://open.api.nexon.com/maplestory/v1/ranking/overall?date={}",
-        now_time
-    );
+    // 쿼리 파라미터 (값은 reqwest에서 인코딩)
+    let mut params = vec![("date", now_time)];
 
-    {
-        // 값이 존재하는 경우에만 파라미터 추가
-        if let Some(ref world_name) = over_all.world_name {
-            url.push_str(&format!("&world_name={world_name}"));
-        }
-        if let Some(world_type) = over_all.world_type {
-            url.push_str(&format!("&world_type={world_type}"));
-        }
-        if let Some(ref class_val) = over_all.class {
-            url.push_str(&format!("&class={class_val}"));
-        }
-        if let Some(ref ocid_val) = over_all.ocid {
-            url.push_str(&format!("&ocid={ocid_val}"));
-        }
-        if let Some(page) = over_all.page {
-            url.push_str(&format!("&page={page}"));
-        }
+    // 값이 존재하는 경우에만 파라미터 추가
+    if let Some(ref world_name) = over_all.world_name {
+        params.push(("world_name", world_name.clone()));
+    }
+    if let Some(world_type) = over_all.world_type {
+        params.push(("world_type", world_type.to_string()));
+    }
+    if let Some(ref class) = over_all.class {
+        params.push(("class", class.clone()));
+    }
+    if let Some(ref ocid) = over_all.ocid {
+        params.push(("ocid", ocid.clone()));
+    }
+    if let Some(page) = over_all.page {
+        params.push(("page", page.to_string()));
     }
 
     // POST 요청 보내기
-    let response = request_parser(api_key, &url).await;
+    let response = request_parser(api_key, "ranking/overall", &params).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let ranking: Ranking = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let mut ranking: Ranking = parse_json(response).await?;
+
+        // 다음 페이지 요청을 위해 조회한 페이지 번호를 함께 반환
+        ranking.page = over_all.page.unwrap_or(1);
 
         Ok(Json(ranking))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
@@ -1,15 +1,17 @@
+use crate::api::date::{default_date, deserialize_date};
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::{parse_json, upstream_error};
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-
-use chrono::{Duration, Utc};
-use chrono_tz::Asia::Seoul;
+use utoipa::ToSchema;
 
 use super::request::request_parser;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = TheseedRankingRequest)]
 pub struct TheSeed {
     #[serde(default)]
     world_name: Option<String>,
@@ -19,8 +21,11 @@ pub struct TheSeed {
     page: Option<i32>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = TheseedRankingInfo)]
 pub struct RankingInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
     ranking: u32,
     theseed_floor: u8,
     theseed_time_record: u16,
@@ -31,50 +36,48 @@ pub struct RankingInfo {
     character_level: u16,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[schema(as = TheseedRanking)]
 pub struct Ranking {
     ranking: Vec<RankingInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getTheseedRanking",
+    tag = "ranking",
+    request_body = TheSeed,
+    responses((status = 200, body = Ranking))
+)]
 pub async fn get_theseed_ranking(
     Extension(api_key): Extension<Arc<API>>,
     Json(the_seed): Json<TheSeed>,
-) -> Result<Json<Ranking>, (StatusCode, &'static str)> {
-    let now_time = (Utc::now() - Duration::days(1))
-        .with_timezone(&Seoul)
-        .format("%Y-%m-%d");
+) -> Result<Json<Ranking>, AppError> {
+    let now_time = default_date();
 
-    // 요청할 API의 URL
-    let mut url = format!(
-        "https://open.api.nexon.com/maplestory/v1/ranking/theseed?date={}",
-        now_time,
-    );
+    // 쿼리 파라미터 (값은 reqwest에서 인코딩)
+    let mut params = vec![("date", now_time)];
 
-    {
-        // 값이 존재하는 경우에만 파라미터 추가
-        if let Some(ref world_name) = the_seed.world_name {
-            url.push_str(&format!("&world_name={world_name}"));
-        }
-        if let Some(ref ocid_val) = the_seed.ocid {
-            url.push_str(&format!("&ocid={ocid_val}"));
-        }
-        if let Some(page) = the_seed.page {
-            url.push_str(&format!("&page={page}"));
-        }
+    // 값이 존재하는 경우에만 파라미터 추가
+    if let Some(ref world_name) = the_seed.world_name {
+        params.push(("world_name", world_name.clone()));
+    }
+    if let Some(ref ocid) = the_seed.ocid {
+        params.push(("ocid", ocid.clone()));
+    }
+    if let Some(page) = the_seed.page {
+        params.push(("page", page.to_string()));
     }
 
     // POST 요청 보내기
-    let response = request_parser(api_key, &url).await;
+    let response = request_parser(api_key, "ranking/theseed", &params).await?;
 
     // 응답 결과 확인
     if response.status().is_success() {
-        let ranking: Ranking = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
+        let ranking: Ranking = parse_json(response).await?;
 
         Ok(Json(ranking))
     } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+        Err(upstream_error(response).await)
     }
 }
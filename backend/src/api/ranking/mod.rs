@@ -1,3 +1,4 @@
+pub mod common;
 pub mod get_achievement_ranking;
 pub mod get_dojang_ranking;
 pub mod get_guild_ranking;
@@ -1,20 +1,18 @@
+use crate::api::error::AppError;
 use crate::api::request::API;
+use crate::api::upstream::send;
 
-use reqwest::{Client, header};
 use std::sync::Arc;
 
-pub async fn request_parser(api_key: Arc<API>, url: &str) -> reqwest::Response {
-    // 요청 헤더 정의
-    let mut headers = header::HeaderMap::new();
-    headers.insert("x-nxopen-api-key", api_key.key.parse().unwrap());
+// 랭킹 조회 ("ranking/overall" 등)
+// 파라미터는 쿼리 문자열로 인코딩되므로 world_name 등에 '&' 가 포함되어도 다른 파라미터가 추가되지 않는다
+pub async fn request_parser(
+    api_key: Arc<API>,
+    path: &str,
+    params: &[(&str, String)],
+) -> Result<reqwest::Response, AppError> {
+    let request = api_key.client.get(api_key.url(path)).query(params);
 
     // POST 요청 보내기
-    let response = Client::new()
-        .get(url)
-        .headers(headers)
-        .send()
-        .await
-        .expect("Failed to send request");
-
-    return response;
+    send(&api_key, request).await
 }
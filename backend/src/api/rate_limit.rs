@@ -0,0 +1,78 @@
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+// 넥슨 API 호출 전에 사용하는 토큰 버킷
+// 초당 호출 한도를 넘지 않도록 요청을 대기시킨다
+pub struct RateLimiter {
+    per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new(per_second: u32) -> Self {
+        let per_second = f64::from(per_second.max(1));
+
+        Self {
+            per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: per_second,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    // 토큰이 생길 때까지 대기한 뒤 하나를 사용
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.per_second).min(self.per_second);
+                bucket.updated_at = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.per_second)
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn waits_after_burst_is_used() {
+        let limiter = RateLimiter::new(5);
+        let started_at = Instant::now();
+
+        // 처음에는 초당 한도만큼 바로 사용할 수 있다
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert!(started_at.elapsed() < Duration::from_millis(100));
+
+        // 그 다음 토큰은 1/5 초 뒤에 생긴다
+        limiter.acquire().await;
+        assert!(started_at.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[test]
+    fn zero_limit_is_treated_as_one_per_second() {
+        assert_eq!(RateLimiter::new(0).per_second, 1.0);
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(feature = "server")]
+use crate::api::{error::AppError, request::API};
+
+#[cfg(feature = "server")]
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono_tz::{Asia, Tz};
+use serde::Serialize;
+#[cfg(feature = "server")]
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+// 요청 단위로 지역을 바꾸는 헤더 (테스트용)
+pub const REGION_HEADER: &str = "x-melog-region";
+
+// KMS 에서만 제공하는 넥슨 API 경로 (접두사 기준)
+const KMS_ONLY_PATHS: [&str; 4] = [
+    "notice",
+    "history/",
+    "character/list",
+    "user/union-champion",
+];
+
+// 넥슨 Open API 지역
+#[derive(Serialize, Debug, Default, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Region {
+    #[default]
+    Kms,
+    Msea,
+}
+
+// 지역별 조회 날짜 규칙
+pub struct DateRules {
+    // 날짜 계산 기준 시간대
+    pub timezone: Tz,
+    // 조회할 수 있는 가장 이른 날짜
+    pub first_date: &'static str,
+    pub first_date_error: &'static str,
+    // 전날 데이터가 반영되는 시각 (기준 시간대)
+    pub data_ready_hour: u32,
+}
+
+impl Region {
+    const ALL: [Region; 2] = [Region::Kms, Region::Msea];
+
+    pub fn parse(region: &str) -> Option<Self> {
+        match region.trim().to_ascii_lowercase().as_str() {
+            "kms" => Some(Region::Kms),
+            "msea" => Some(Region::Msea),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Region::Kms => "kms",
+            Region::Msea => "msea",
+        }
+    }
+
+    // 넥슨 API 경로 접두사
+    pub fn api_prefix(self) -> &'static str {
+        match self {
+            Region::Kms => "maplestory/v1",
+            Region::Msea => "maplestorysea/v1",
+        }
+    }
+
+    // MSEA 는 싱가포르 시간 기준으로 데이터가 반영된다
+    pub fn date_rules(self) -> DateRules {
+        match self {
+            Region::Kms => DateRules {
+                timezone: Asia::Seoul,
+                first_date: "2023-12-21",
+                first_date_error: "date must not be earlier than 2023-12-21",
+                data_ready_hour: 1,
+            },
+            Region::Msea => DateRules {
+                timezone: Asia::Singapore,
+                first_date: "2025-01-01",
+                first_date_error: "date must not be earlier than 2025-01-01",
+                data_ready_hour: 2,
+            },
+        }
+    }
+
+    // 이 지역에서 제공하는 넥슨 API 경로인지 ("notice", "character/basic" 등)
+    pub fn supports(self, path: &str) -> bool {
+        self == Region::Kms || !KMS_ONLY_PATHS.iter().any(|prefix| path.starts_with(prefix))
+    }
+
+    // URL 경로에서 지역 접두사 제거 ("/maplestorysea/v1/id" → "id")
+    pub fn strip_prefix(path: &str) -> &str {
+        Region::ALL
+            .iter()
+            .find_map(|region| {
+                path.strip_prefix('/')
+                    .and_then(|path| path.strip_prefix(region.api_prefix()))
+                    .and_then(|path| path.strip_prefix('/'))
+            })
+            .unwrap_or(path)
+    }
+}
+
+tokio::task_local! {
+    static REGION: Region;
+}
+
+// 현재 요청의 지역 (요청 밖에서는 KMS)
+pub fn current_region() -> Region {
+    REGION.try_with(|region| *region).unwrap_or_default()
+}
+
+// 요청마다 사용할 지역 결정 (x-melog-region 헤더 → 설정의 기본 지역)
+#[cfg(feature = "server")]
+pub async fn region_scope(State(api): State<Arc<API>>, request: Request, next: Next) -> Response {
+    let region = match request.headers().get(REGION_HEADER) {
+        Some(value) => match value.to_str().ok().and_then(Region::parse) {
+            Some(region) => region,
+            None => {
+                return AppError::InvalidInput("x-melog-region must be one of: kms, msea")
+                    .into_response();
+            }
+        },
+        None => api.region,
+    };
+
+    REGION.scope(region, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ignores_case_and_whitespace() {
+        assert_eq!(Region::parse(" MSEA "), Some(Region::Msea));
+        assert_eq!(Region::parse("kms"), Some(Region::Kms));
+        assert_eq!(Region::parse("gms"), None);
+    }
+
+    #[test]
+    fn msea_does_not_support_kms_only_paths() {
+        for path in [
+            "notice",
+            "notice-event/detail",
+            "history/cube",
+            "character/list",
+            "user/union-champion",
+        ] {
+            assert!(Region::Kms.supports(path), "{path}");
+            assert!(!Region::Msea.supports(path), "{path}");
+        }
+
+        for path in ["id", "character/basic", "user/union", "ranking/overall"] {
+            assert!(Region::Msea.supports(path), "{path}");
+        }
+    }
+
+    #[test]
+    fn strip_prefix_removes_either_region() {
+        assert_eq!(
+            Region::strip_prefix("/maplestory/v1/character/basic"),
+            "character/basic"
+        );
+        assert_eq!(Region::strip_prefix("/maplestorysea/v1/id"), "id");
+        assert_eq!(Region::strip_prefix("/other/path"), "/other/path");
+    }
+
+    #[tokio::test]
+    async fn current_region_follows_scope() {
+        assert_eq!(current_region(), Region::Kms);
+        REGION
+            .scope(Region::Msea, async {
+                assert_eq!(current_region(), Region::Msea);
+            })
+            .await;
+    }
+}
@@ -1,20 +1,47 @@
+use crate::api::account::get_character_list::get_character_list;
 use crate::api::character::{
     character::get_ocid, user_ability::get_user_ability,
     user_android_equipment::get_user_android_equipment,
+    user_beauty_equipment::get_user_beauty_equipment,
     user_cashitem_equipment::get_user_cash_item_equipment,
     user_characeter_skill::get_user_characeter_link_skill,
-    user_characeter_skill::get_user_characeter_skill, user_default_info::get_user_default_info,
-    user_dojang::get_user_dojang, user_hexa_matrix::get_user_hexa_matrix,
-    user_hexa_matrix_stat::get_user_hexa_stat_info, user_hyper_stat_info::get_user_hyper_stat_info,
-    user_item_equipment::get_user_item_equipment, user_propensity::get_user_propensity,
-    user_set_effect::get_user_set_effect, user_stat_info::get_user_stat_info,
-    user_symbol_equipment::get_user_symbol_equipment, user_v_matrix::get_user_v_matrix,
+    user_characeter_skill::get_user_characeter_skill,
+    user_characeter_skill::get_user_character_skill_all, user_character_image::get_character_image,
+    user_default_info::get_character_image_url, user_default_info::get_user_default_info,
+    user_digest::get_user_digest, user_dojang::get_user_dojang,
+    user_hexa_matrix::get_user_hexa_matrix, user_hexa_matrix_stat::get_user_hexa_stat_info,
+    user_hyper_stat_info::get_user_hyper_stat_info,
+    user_hyper_stat_info::get_user_hyper_stat_info_v2,
+    user_item_equipment::get_user_item_equipment,
+    user_item_equipment::get_user_item_equipment_totals,
+    user_pet_equipment::get_user_pet_equipment, user_popularity::get_user_popularity,
+    user_propensity::get_user_propensity, user_set_effect::get_user_set_effect,
+    user_set_effect::get_user_set_effect_v2, user_stat_info::get_user_stat_info,
+    user_summary::get_user_summary, user_symbol_equipment::get_user_symbol_equipment,
+    user_v_matrix::get_user_v_matrix,
 };
+use crate::api::client::NexonClient;
+use crate::api::config::Config;
 use crate::api::guild::{guild::get_guild_ocid, guild_default_info::get_guild_default_info};
+use crate::api::health::get_health;
+use crate::api::history::{
+    get_cube_history::get_cube_history, get_potential_history::get_potential_history,
+    get_starforce_history::get_starforce_history,
+};
+use crate::api::image_cache::ImageCache;
+use crate::api::metrics::get_metrics;
 use crate::api::notice::{
-    get_cash_shop_notice::get_cash_shop_notice, get_event_notice::get_event_notice,
-    get_notice::get_notice, get_update_notice::get_update_notice,
+    get_all_notice::get_all_notice,
+    get_cash_shop_notice::get_cash_shop_notice,
+    get_event_notice::get_event_notice,
+    get_notice::get_notice,
+    get_notice_detail::{
+        get_cash_shop_notice_detail, get_event_notice_detail, get_notice_detail,
+        get_update_notice_detail,
+    },
+    get_update_notice::get_update_notice,
 };
+use crate::api::openapi::{openapi_json, swagger_ui};
 use crate::api::ranking::{
     get_achievement_ranking::get_achievement_ranking, get_dojang_ranking::get_dojang_ranking,
     get_guild_ranking::get_guild_ranking, get_overall_ranking::get_over_all_ranking,
@@ -25,22 +52,68 @@ use crate::api::union::{
     get_union_champion::get_user_union_champion_info, get_union_raider::get_user_union_raider_info,
 };
 use axum::{Json, Router, http::StatusCode, response::IntoResponse, routing::get, routing::post};
+use metrics_exporter_prometheus::PrometheusHandle;
 use serde::Serialize;
+use std::ops::Deref;
+use std::time::Instant;
+use utoipa::ToSchema;
+
+// 넥슨 캐릭터 이미지 주소 (character_image 는 이 호스트의 /static 경로)
+const NEXON_IMAGE_ORIGIN: &str = "https://open.api.nexon.com";
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct API {
-    pub key: String,
+    // 넥슨 API 클라이언트 (핸들러에서는 Deref 로 바로 사용)
+    pub nexon: NexonClient,
+    // 캐릭터 이미지 캐시 (POST /getCharacterImage)
+    pub image_cache: ImageCache,
+    // 캐릭터 이미지 전용 클라이언트 (API 키를 붙이지 않고 키 목록 / 호출 한도 / 서킷 브레이커를 거치지 않는다)
+    pub image_client: reqwest::Client,
+    // 캐릭터 이미지를 받아 올 수 있는 주소 (scheme, host, port 가 같은 URL 만 허용)
+    pub image_origin: reqwest::Url,
+    // 서버 시작 시각 (헬스 체크의 uptime)
+    pub started_at: Instant,
+    // Prometheus 메트릭 (GET /metrics)
+    pub metrics: PrometheusHandle,
 }
 
 impl API {
     // 생성자
-    pub fn new(key: String) -> Self {
-        Self { key }
+    // API 키가 헤더 값으로 사용할 수 없는 문자열이면 실패
+    // 전역 메트릭 레코더는 프로세스당 한 번만 설치할 수 있으므로 설치는 호출하는 쪽 (바이너리) 에서 한다
+    pub fn new(
+        config: &Config,
+        metrics: PrometheusHandle,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            nexon: NexonClient::new(config)?,
+            image_cache: ImageCache::new(
+                config.cache_ttl,
+                config.image_cache_max_bytes,
+                config.image_cache_max_variants,
+            ),
+            image_client: reqwest::Client::builder()
+                .connect_timeout(config.connect_timeout)
+                .timeout(config.timeout)
+                .build()?,
+            image_origin: reqwest::Url::parse(NEXON_IMAGE_ORIGIN)?,
+            started_at: Instant::now(),
+            metrics,
+        })
+    }
+}
+
+impl Deref for API {
+    type Target = NexonClient;
+
+    fn deref(&self) -> &NexonClient {
+        &self.nexon
     }
 }
 
-#[derive(Serialize)]
-struct ErrorResponse {
-    message: &'static str,
+#[derive(Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub message: &'static str,
 }
 
 async fn fallback() -> impl IntoResponse {
@@ -54,18 +127,37 @@ async fn fallback() -> impl IntoResponse {
 
 pub fn get_routes() -> Router {
     Router::new()
+        .merge(account_routes())
         .merge(user_routes())
         .merge(guild_route())
+        .merge(history_route())
         .merge(notice_route())
         .merge(union_route())
         .merge(ranking_route())
+        .nest("/v2", user_routes_v2())
+        .merge(docs_route())
+        .route("/health", get(get_health))
+        .route("/metrics", get(get_metrics))
         .fallback(fallback)
 }
 
+// OpenAPI 문서와 Swagger UI
+pub fn docs_route() -> Router {
+    Router::new()
+        .route("/api-docs/openapi.json", get(openapi_json))
+        .route("/swagger-ui", get(swagger_ui))
+}
+
+pub fn account_routes() -> Router {
+    Router::new().route("/getCharacterList", get(get_character_list))
+}
+
 pub fn user_routes() -> Router {
     Router::new()
         .route("/getOcid", post(get_ocid))
         .route("/getUserInfo", post(get_user_default_info))
+        .route("/getCharacterImageUrl", post(get_character_image_url))
+        .route("/getCharacterImage", post(get_character_image))
         .route("/getUserStatInfo", post(get_user_stat_info))
         .route("/getUserHyperStatInfo", post(get_user_hyper_stat_info))
         .route("/getUserPropensity", post(get_user_propensity))
@@ -73,6 +165,10 @@ pub fn user_routes() -> Router {
         .route("/getUserSymbolEquipment", post(get_user_symbol_equipment))
         .route("/getUserSetEffect", post(get_user_set_effect))
         .route("/getUserCharacterSkill", post(get_user_characeter_skill))
+        .route(
+            "/getUserCharacterSkillAll",
+            post(get_user_character_skill_all),
+        )
         .route(
             "/getUserCharacterLinkSkill",
             post(get_user_characeter_link_skill),
@@ -81,12 +177,28 @@ pub fn user_routes() -> Router {
         .route("/getUserHexaMatrix", post(get_user_hexa_matrix))
         .route("/getUserDojang", post(get_user_dojang))
         .route("/getUserItemEquipment", post(get_user_item_equipment))
+        .route(
+            "/getUserItemEquipmentTotals",
+            post(get_user_item_equipment_totals),
+        )
         .route("/getUserAndroidEquipment", post(get_user_android_equipment))
+        .route("/getUserBeautyEquipment", post(get_user_beauty_equipment))
+        .route("/getUserPetEquipment", post(get_user_pet_equipment))
+        .route("/getUserPopularity", post(get_user_popularity))
         .route(
             "/getUserCashItemEquipment",
             post(get_user_cash_item_equipment),
         )
         .route("/getUserHexStatInfo", post(get_user_hexa_stat_info))
+        .route("/getUserDigest", post(get_user_digest))
+        .route("/getUserSummary", post(get_user_summary))
+}
+
+// v2: 하이퍼 스탯, 세트 효과를 필터링 없이 반환 (?filter=active 로 v1 동작)
+pub fn user_routes_v2() -> Router {
+    Router::new()
+        .route("/getUserHyperStatInfo", post(get_user_hyper_stat_info_v2))
+        .route("/getUserSetEffect", post(get_user_set_effect_v2))
 }
 
 pub fn guild_route() -> Router {
@@ -95,12 +207,24 @@ pub fn guild_route() -> Router {
         .route("/getGuildInfo", post(get_guild_default_info))
 }
 
+pub fn history_route() -> Router {
+    Router::new()
+        .route("/getCubeHistory", get(get_cube_history))
+        .route("/getStarforceHistory", get(get_starforce_history))
+        .route("/getPotentialHistory", get(get_potential_history))
+}
+
 pub fn notice_route() -> Router {
     Router::new()
         .route("/getNotice", get(get_notice))
         .route("/getUpdateNotice", get(get_update_notice))
         .route("/getEvnetNotice", get(get_event_notice))
         .route("/getCashShopNotice", get(get_cash_shop_notice))
+        .route("/getAllNotice", get(get_all_notice))
+        .route("/getNoticeDetail", get(get_notice_detail))
+        .route("/getUpdateNoticeDetail", get(get_update_notice_detail))
+        .route("/getEventNoticeDetail", get(get_event_notice_detail))
+        .route("/getCashShopNoticeDetail", get(get_cash_shop_notice_detail))
 }
 
 pub fn union_route() -> Router {
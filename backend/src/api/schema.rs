@@ -0,0 +1,132 @@
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+// 넥슨 API 원본 응답과 파싱 후 다시 직렬화한 응답의 키 차이 (스키마 변경 감지용)
+// 값이 달라진 것은 비교하지 않고, 객체 키의 경로만 비교한다 ("item_equipment[].item_name")
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KeyDiff {
+    // 원본에만 있는 키 (구조체에 없어 버려지는 필드)
+    pub unknown: BTreeSet<String>,
+    // 파싱 결과에만 있는 키 (원본에서 빠져 기본값으로 채워진 필드, 또는 계산해서 추가한 필드)
+    pub missing: BTreeSet<String>,
+}
+
+impl KeyDiff {
+    pub fn is_empty(&self) -> bool {
+        self.unknown.is_empty() && self.missing.is_empty()
+    }
+}
+
+pub fn diff_keys(upstream: &Value, parsed: &Value) -> KeyDiff {
+    let mut diff = KeyDiff::default();
+    diff_value(upstream, parsed, "", &mut diff);
+    diff
+}
+
+fn diff_value(upstream: &Value, parsed: &Value, path: &str, diff: &mut KeyDiff) {
+    match (upstream, parsed) {
+        (Value::Object(upstream), Value::Object(parsed)) => {
+            diff_object(upstream, parsed, path, diff)
+        }
+        // 배열은 원소 객체의 키를 모두 합쳐서 비교한다 (한쪽이 비어 있으면 비교하지 않음)
+        (Value::Array(upstream), Value::Array(parsed)) => {
+            let (upstream, parsed) = (merge_items(upstream), merge_items(parsed));
+            if !upstream.is_empty() && !parsed.is_empty() {
+                diff_object(&upstream, &parsed, &format!("{path}[]"), diff);
+            }
+        }
+        // 한쪽이 null 이거나 값의 종류가 다른 경우는 값 변경으로 보고 무시
+        _ => {}
+    }
+}
+
+fn diff_object(
+    upstream: &Map<String, Value>,
+    parsed: &Map<String, Value>,
+    path: &str,
+    diff: &mut KeyDiff,
+) {
+    let join = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+
+    for (key, value) in upstream {
+        match parsed.get(key) {
+            Some(parsed) => diff_value(value, parsed, &join(key), diff),
+            None => {
+                diff.unknown.insert(join(key));
+            }
+        }
+    }
+
+    for key in parsed.keys().filter(|key| !upstream.contains_key(*key)) {
+        diff.missing.insert(join(key));
+    }
+}
+
+// 배열 원소 중 객체의 키를 하나의 객체로 합친다 (null 이 아닌 값을 우선)
+fn merge_items(items: &[Value]) -> Map<String, Value> {
+    let mut merged = Map::new();
+
+    for item in items.iter().filter_map(Value::as_object) {
+        for (key, value) in item {
+            match merged.get(key) {
+                Some(existing) if !existing.is_null() => {}
+                _ => {
+                    merged.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reports_unknown_and_missing_paths() {
+        let upstream = json!({
+            "date": "2024-06-01",
+            "new_field": 1,
+            "item_equipment": [
+                { "item_name": "a", "item_new_option": {} },
+                { "item_name": "b", "item_total_option": { "str": "1", "critical": "2" } }
+            ]
+        });
+        let parsed = json!({
+            "date": "2024-06-01",
+            "summary": {},
+            "item_equipment": [
+                { "item_name": "a", "item_total_option": { "str": "1" } }
+            ]
+        });
+
+        let diff = diff_keys(&upstream, &parsed);
+
+        assert_eq!(
+            diff.unknown.into_iter().collect::<Vec<_>>(),
+            [
+                "item_equipment[].item_new_option",
+                "item_equipment[].item_total_option.critical",
+                "new_field",
+            ]
+        );
+        assert_eq!(diff.missing.into_iter().collect::<Vec<_>>(), ["summary"]);
+    }
+
+    #[test]
+    fn ignores_changed_values_and_nulls() {
+        let upstream = json!({ "level": 280, "guild": null, "stat": [{ "name": "STR" }] });
+        let parsed = json!({ "level": "280", "guild": { "name": "" }, "stat": [] });
+
+        assert!(diff_keys(&upstream, &parsed).is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+use tracing::info;
+
+// SIGINT (Ctrl+C) 또는 SIGTERM 을 받을 때까지 대기
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!(signal = "SIGINT", "shutdown started"),
+        _ = terminate => info!(signal = "SIGTERM", "shutdown started"),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn resolves_on_sigterm() {
+        let mut signal = Box::pin(shutdown_signal());
+        // 처음 poll 할 때 시그널 핸들러가 등록된다 (등록 전에 보내면 프로세스가 종료된다)
+        assert!(futures::poll!(signal.as_mut()).is_pending());
+
+        let status = std::process::Command::new("kill")
+            .args(["-TERM", &std::process::id().to_string()])
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        tokio::time::timeout(Duration::from_secs(5), signal)
+            .await
+            .expect("shutdown_signal should resolve after SIGTERM");
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(feature = "server")]
+use axum::{body::Body, extract::MatchedPath, http::Request};
+use tracing::{Span, field::Empty, info_span};
+#[cfg(feature = "server")]
+use tracing_subscriber::EnvFilter;
+
+// RUST_LOG가 없을 때의 기본 로그 레벨
+#[cfg(feature = "server")]
+const DEFAULT_FILTER: &str = "backend=info,tower_http=info";
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+// 로그 출력 설정
+// RUST_LOG=필터 (기본값 backend=info,tower_http=info), json 이면 JSON 한 줄 형식
+#[cfg(feature = "server")]
+pub fn init_tracing(json: bool) {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+    if json {
+        subscriber.json().flatten_event(true).init();
+    } else {
+        subscriber.init();
+    }
+}
+
+// 요청 단위 span (method, path, request_id)
+// 상태 코드와 처리 시간은 TraceLayer의 응답 로그에 기록된다
+#[cfg(feature = "server")]
+pub fn request_span(request: &Request<Body>) -> Span {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(request.uri().path(), MatchedPath::as_str);
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    info_span!(
+        "request",
+        method = %request.method(),
+        path = route,
+        request_id,
+    )
+}
+
+// 넥슨 API 호출 단위 span (요청 span의 하위 span)
+pub fn upstream_span(attempt: u32) -> Span {
+    info_span!(
+        "upstream",
+        attempt,
+        path = Empty,
+        status = Empty,
+        duration_ms = Empty,
+    )
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    // span 필드를 "이름=값" 형태로 모으는 레이어
+    #[derive(Clone, Default)]
+    struct Fields(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{}={value:?}", field.name()));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{}={value}", field.name()));
+        }
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for Fields {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+            attrs.record(&mut self.clone());
+        }
+
+        fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    fn capture(f: impl FnOnce()) -> Vec<String> {
+        let fields = Fields::default();
+        let subscriber = tracing_subscriber::registry().with(fields.clone());
+        tracing::subscriber::with_default(subscriber, f);
+        fields.0.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn request_span_records_method_path_and_request_id() {
+        let request = Request::get("/getUserInfo?date=2024-06-01")
+            .header(REQUEST_ID_HEADER, "req-1")
+            .body(Body::empty())
+            .unwrap();
+
+        let fields = capture(|| {
+            request_span(&request);
+        });
+
+        assert_eq!(
+            fields,
+            ["method=GET", "path=/getUserInfo", "request_id=req-1"]
+        );
+    }
+
+    #[test]
+    fn upstream_span_records_result_fields_later() {
+        let fields = capture(|| {
+            let span = upstream_span(2);
+            span.record("path", "character/basic");
+            span.record("status", 200);
+        });
+
+        assert_eq!(fields, ["attempt=2", "path=character/basic", "status=200"]);
+    }
+}
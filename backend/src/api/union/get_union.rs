@@ -1,36 +1,53 @@
 use crate::api::character::character::UserOcid;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
-use crate::api::union::request::request_parser;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug)]
+// 유니온을 진행하지 않은 계정은 값이 null
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_level: u16,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_grade: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_artifact_level: u16,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_artifact_exp: u32,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_artifact_point: u32,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUnion",
+    tag = "union",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UnionInfo))
+)]
 pub async fn get_user_union_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UnionInfo>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "union", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_data: UnionInfo = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_data))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<UnionInfo>, AppError> {
+    api_key
+        .get_character("user/union", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(Json)
 }
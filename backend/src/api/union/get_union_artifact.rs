@@ -1,48 +1,70 @@
 use crate::api::character::character::UserOcid;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
-use crate::api::union::request::request_parser;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionArtifactEffectInfo {
     name: String,
     level: u8,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionArtifactCrystalInfo {
     name: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    validity_flag: String,
+    date_expire: Option<String>, // 영구 크리스탈은 null
     level: u8,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     crystal_option_name_1: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     crystal_option_name_2: String,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     crystal_option_name_3: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+// 아티팩트를 개방하지 않은 계정은 배열이 비어 있거나 null
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionArtifactInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_artifact_effect: Vec<UnionArtifactEffectInfo>,
-    union_artifact_crystal: Vec<UnionArtifactCrystalInfo>, // 블럭 좌표는 불필요
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_artifact_crystal: Vec<UnionArtifactCrystalInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_artifact_remain_ap: u32,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUnionArtifact",
+    tag = "union",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UnionArtifactInfo))
+)]
 pub async fn get_user_union_artifact_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UnionArtifactInfo>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "union-artifact", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_data: UnionArtifactInfo = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_data))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<UnionArtifactInfo>, AppError> {
+    api_key
+        .get_character("user/union-artifact", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(Json)
 }
@@ -1,46 +1,61 @@
 use crate::api::character::character::UserOcid;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
-use crate::api::union::request::request_parser;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionChampionStatInfo {
     stat: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionChampionInfo {
     champion_name: String,
     champion_slot: u8,
     champion_grade: String,
     champion_class: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     champion_badge_info: Vec<UnionChampionStatInfo>,
 }
-#[derive(Serialize, Deserialize, Debug)]
+
+// 챔피언 슬롯을 개방하지 않은 계정은 배열이 비어 있거나 null
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionChampiontInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_champion: Vec<UnionChampionInfo>,
-    champion_badge_total_info: Vec<UnionChampionStatInfo>, // 블럭 좌표는 불필요
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    champion_badge_total_info: Vec<UnionChampionStatInfo>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/getUnionChampion",
+    tag = "union",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UnionChampiontInfo))
+)]
 pub async fn get_user_union_champion_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UnionChampiontInfo>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "union-champion", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_data: UnionChampiontInfo = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_data))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
-    }
+) -> Result<Json<UnionChampiontInfo>, AppError> {
+    api_key
+        .get_character("user/union-champion", &user_ocid.ocid, &date.resolve()?)
+        .await
+        .map(Json)
 }
@@ -1,42 +1,217 @@
 use crate::api::character::character::UserOcid;
+use crate::api::character::normalize::OptionUnit;
+use crate::api::date::{DateQuery, deserialize_date};
+use crate::api::error::AppError;
+use crate::api::query::Query;
 use crate::api::request::API;
-use crate::api::union::request::request_parser;
 
-use axum::{Extension, http::StatusCode, response::Json};
+use axum::{Extension, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as};
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct UnionInnerStatInfo {
+    stat_field_id: String,
+    stat_field_effect: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct UnionBlockPosition {
+    x: i32,
+    y: i32,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionBlockInfo {
     block_type: String,
     block_class: String,
     block_level: String,
+    #[serde(default)]
+    block_control_point: Option<UnionBlockPosition>,
+    // 배치되지 않은 블럭은 null
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    block_position: Vec<UnionBlockPosition>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct UnionRaiderPreset {
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_raider_stat: Vec<String>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_occupied_stat: Vec<String>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_inner_stat: Vec<UnionInnerStatInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_block: Vec<UnionBlockInfo>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct UnionRaiderInfo {
+    #[serde(default, deserialize_with = "deserialize_date")]
+    date: String,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_raider_stat: Vec<String>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_occupied_stat: Vec<String>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    union_inner_stat: Vec<UnionInnerStatInfo>,
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     union_block: Vec<UnionBlockInfo>,
-    // 블럭 좌표는 불필요
+    #[serde(default)]
+    use_preset_no: Option<u8>,
+    #[serde(default)]
+    union_raider_preset_1: Option<UnionRaiderPreset>,
+    #[serde(default)]
+    union_raider_preset_2: Option<UnionRaiderPreset>,
+    #[serde(default)]
+    union_raider_preset_3: Option<UnionRaiderPreset>,
+    #[serde(default)]
+    union_raider_preset_4: Option<UnionRaiderPreset>,
+    #[serde(default)]
+    union_raider_preset_5: Option<UnionRaiderPreset>,
+    // 점령 효과 합계 (스탯 이름 → 수치 / 단위)
+    #[serde(skip_deserializing)]
+    summary: BTreeMap<String, OccupiedStat>,
+}
+
+// 점령 효과 합계 값
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, ToSchema)]
+pub struct OccupiedStat {
+    value: f64,
+    unit: OptionUnit,
+}
+
+// "STR 5 증가", "보스 몬스터 공격 시 데미지 10% 증가" 형태의 문자열을 (스탯 이름, 수치, 단위)로 변환
+fn parse_occupied_stat(stat: &str) -> Option<(String, OccupiedStat)> {
+    let stat = stat.trim();
+    let stat = stat.strip_suffix("증가").unwrap_or(stat).trim_end();
+
+    let (name, value) = stat.rsplit_once(' ')?;
+    let (value, unit) = match value.strip_suffix('%') {
+        Some(value) => (value, OptionUnit::Percent),
+        None => (value, OptionUnit::Flat),
+    };
+    let value: f64 = value
+        .trim_start_matches('+')
+        .replace(',', "")
+        .parse()
+        .ok()?;
+
+    Some((name.trim().to_string(), OccupiedStat { value, unit }))
 }
 
+// 같은 이름이라도 단위가 다르면 따로 집계한다 (두 단위가 모두 있으면 퍼센트 값의 이름 뒤에 " %" 를 붙인다)
+fn summarize_occupied_stat(stats: &[String]) -> BTreeMap<String, OccupiedStat> {
+    let mut totals: BTreeMap<(String, bool), OccupiedStat> = BTreeMap::new();
+
+    for (name, stat) in stats.iter().filter_map(|stat| parse_occupied_stat(stat)) {
+        totals
+            .entry((name, stat.unit == OptionUnit::Percent))
+            .and_modify(|total| total.value += stat.value)
+            .or_insert(stat);
+    }
+
+    let has_flat = |name: &str| totals.contains_key(&(name.to_string(), false));
+
+    totals
+        .iter()
+        .map(|((name, is_percent), stat)| {
+            let key = if *is_percent && has_flat(name) {
+                format!("{name} %")
+            } else {
+                name.clone()
+            };
+            (key, *stat)
+        })
+        .collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/getUnionRaider",
+    tag = "union",
+    params(DateQuery),
+    request_body = UserOcid,
+    responses((status = 200, body = UnionRaiderInfo))
+)]
 pub async fn get_user_union_raider_info(
     Extension(api_key): Extension<Arc<API>>,
+    Query(date): Query<DateQuery>,
     Json(user_ocid): Json<UserOcid>,
-) -> Result<Json<UnionRaiderInfo>, (StatusCode, &'static str)> {
-    // POST 요청 보내기
-    let response = request_parser(api_key.clone(), "union-raider", &user_ocid.ocid).await;
-
-    // 응답 결과 확인
-    if response.status().is_success() {
-        let user_data: UnionRaiderInfo = response
-            .json()
-            .await
-            .expect("Failed to parse response JSON");
-
-        Ok(Json(user_data))
-    } else {
-        Err((StatusCode::BAD_REQUEST, "Failed to fetch OCID"))
+) -> Result<Json<UnionRaiderInfo>, AppError> {
+    let mut user_data: UnionRaiderInfo = api_key
+        .get_character("user/union-raider", &user_ocid.ocid, &date.resolve()?)
+        .await?;
+
+    user_data.summary = summarize_occupied_stat(&user_data.union_occupied_stat);
+
+    Ok(Json(user_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_percent_unit() {
+        assert_eq!(
+            parse_occupied_stat("보스 몬스터 공격 시 데미지 10% 증가"),
+            Some((
+                "보스 몬스터 공격 시 데미지".to_string(),
+                OccupiedStat {
+                    value: 10.0,
+                    unit: OptionUnit::Percent
+                }
+            ))
+        );
+        assert_eq!(
+            parse_occupied_stat("최대 HP 2,500 증가"),
+            Some((
+                "최대 HP".to_string(),
+                OccupiedStat {
+                    value: 2500.0,
+                    unit: OptionUnit::Flat
+                }
+            ))
+        );
+        assert_eq!(parse_occupied_stat("알 수 없는 효과"), None);
+    }
+
+    #[test]
+    fn summary_adds_values_per_stat_and_unit() {
+        let stats = [
+            "STR 5 증가",
+            "STR 10 증가",
+            "크리티컬 확률 2% 증가",
+            "크리티컬 확률 3% 증가",
+            "최대 HP 250 증가",
+            "최대 HP 5% 증가",
+        ]
+        .map(str::to_string);
+        let summary = summarize_occupied_stat(&stats);
+
+        assert_eq!(summary["STR"].value, 15.0);
+        assert_eq!(summary["STR"].unit, OptionUnit::Flat);
+        assert_eq!(summary["크리티컬 확률"].value, 5.0);
+        assert_eq!(summary["크리티컬 확률"].unit, OptionUnit::Percent);
+        assert_eq!(summary["최대 HP"].value, 250.0);
+        assert_eq!(summary["최대 HP"].unit, OptionUnit::Flat);
+        assert_eq!(summary["최대 HP %"].value, 5.0);
+        assert_eq!(summary["최대 HP %"].unit, OptionUnit::Percent);
     }
 }
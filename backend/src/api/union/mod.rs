@@ -2,4 +2,3 @@ pub mod get_union;
 pub mod get_union_artifact;
 pub mod get_union_champion;
 pub mod get_union_raider;
-pub mod request;
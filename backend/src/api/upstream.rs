@@ -0,0 +1,332 @@
+use crate::api::circuit_breaker::BreakerPermit;
+use crate::api::client::NexonClient;
+use crate::api::error::AppError;
+use crate::api::key_pool::{API_KEY_HEADER, ApiKey};
+use crate::api::metrics::{record_key, record_upstream};
+use crate::api::region::{Region, current_region};
+use crate::api::trace::upstream_span;
+
+use http::StatusCode;
+use reqwest::{RequestBuilder, Response, Url};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::Instant;
+use tracing::{Instrument, Span, debug, warn};
+
+// 캐릭터 이미지 주소 (뒤에 캐릭터마다 다른 토큰이 붙는다)
+const CHARACTER_IMAGE_PATH: &str = "/static/maplestory/character/look/";
+
+// 로그와 오류 메시지에 사용할 넥슨 API 경로 (지역 접두사, 쿼리 제외)
+// 캐릭터 이미지는 메트릭 라벨이 늘어나지 않도록 "character/image"로 묶는다
+fn endpoint(url: Option<&Url>) -> String {
+    url.map(|url| {
+        if url.path().starts_with(CHARACTER_IMAGE_PATH) {
+            return "character/image".to_string();
+        }
+        Region::strip_prefix(url.path()).to_string()
+    })
+    .unwrap_or_else(|| "unknown".to_string())
+}
+
+// 현재 지역에서 제공하지 않는 넥슨 API 이면 업스트림 호출 없이 501
+fn check_region(request: &RequestBuilder) -> Result<(), AppError> {
+    let region = current_region();
+    let Some(url) = request
+        .try_clone()
+        .and_then(|request| request.build().ok())
+        .map(|request| request.url().clone())
+    else {
+        return Ok(());
+    };
+
+    let endpoint = endpoint(Some(&url));
+    if region.supports(&endpoint) {
+        return Ok(());
+    }
+
+    Err(AppError::NotImplemented { endpoint, region })
+}
+
+// 요청 실패를 502 (타임아웃인 경우 504) 오류로 변환
+pub fn request_error(error: reqwest::Error) -> AppError {
+    let endpoint = endpoint(error.url());
+    warn!(endpoint, %error, "failed to send request");
+
+    if error.is_timeout() {
+        return AppError::Timeout { endpoint };
+    }
+
+    AppError::Request { endpoint }
+}
+
+// 재시도를 포함한 전체 대기 시간 상한
+const RETRY_BUDGET: Duration = Duration::from_secs(10);
+
+// 재시도 대상 오류 (연결 실패, 타임아웃)
+fn is_transient(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout()
+}
+
+// 0 ~ delay/2 사이의 지터
+fn jitter(delay: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.subsec_nanos())
+        .unwrap_or_default();
+    let max = delay.as_millis() as u64 / 2 + 1;
+
+    Duration::from_millis(u64::from(nanos) % max)
+}
+
+// 호출 한 번의 결과를 메트릭과 span에 기록 (재시도도 각각 기록)
+fn record_result(
+    span: &Span,
+    key: &ApiKey,
+    result: &Result<Response, reqwest::Error>,
+    started_at: Instant,
+) {
+    let (path, status) = match result {
+        Ok(response) => (
+            endpoint(Some(response.url())),
+            Some(response.status().as_u16()),
+        ),
+        Err(error) => (endpoint(error.url()), None),
+    };
+
+    span.record("path", path.as_str());
+    if let Some(status) = status {
+        span.record("status", status);
+    }
+    span.record("duration_ms", started_at.elapsed().as_millis() as u64);
+    span.in_scope(|| debug!("nexon api call"));
+
+    record_upstream(&path, status, started_at.into_std());
+    record_key(&key.label, status);
+}
+
+// Retry-After 헤더 (초), 없으면 초당 한도 기준으로 1초
+fn retry_after(response: &Response) -> u64 {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(1)
+}
+
+// 호출 한도를 넘긴 (429) 키와 거부된 (403) 키를 잠시 제외
+// 제외한 경우 true
+fn bench_key(key: &ApiKey, response: &Response) -> bool {
+    match response.status() {
+        reqwest::StatusCode::TOO_MANY_REQUESTS => {
+            key.bench_rate_limited(Duration::from_secs(retry_after(response)));
+            true
+        }
+        reqwest::StatusCode::FORBIDDEN => {
+            key.bench_rejected();
+            true
+        }
+        _ => false,
+    }
+}
+
+// 넥슨 API 요청 전송
+// 서킷 브레이커와 호출 한도를 거치고, 연결 실패/타임아웃/5xx 응답은 지수 백오프로 재시도한다 (4xx는 재시도하지 않음)
+// API 키는 요청마다 키 목록에서 돌아가며 선택하고, 429 / 403 을 받은 키는 제외한 뒤 다른 키로 바로 재시도한다
+pub async fn send(api: &NexonClient, request: RequestBuilder) -> Result<Response, AppError> {
+    check_region(&request)?;
+
+    // 차단 중이면 업스트림 호출 없이 바로 503
+    // 재시도를 포함한 요청 하나의 최종 결과만 차단기에 기록한다
+    let permit = api.breaker.check()?;
+
+    let deadline = Instant::now() + RETRY_BUDGET;
+    let mut attempt = 0;
+
+    loop {
+        // 본문이 없는 GET 요청이므로 항상 복제 가능
+        let Some(current) = request.try_clone() else {
+            let key = api.keys.select();
+            key.limiter.acquire().await;
+            let span = upstream_span(attempt);
+            let started_at = Instant::now();
+            let result = request
+                .header(API_KEY_HEADER, key.header())
+                .send()
+                .instrument(span.clone())
+                .await;
+            record_result(&span, key, &result, started_at);
+            record_outcome(permit, &result);
+            return result.map_err(request_error);
+        };
+
+        let key = api.keys.select();
+        key.limiter.acquire().await;
+        let span = upstream_span(attempt);
+        let started_at = Instant::now();
+        let result = current
+            .header(API_KEY_HEADER, key.header())
+            .send()
+            .instrument(span.clone())
+            .await;
+        record_result(&span, key, &result, started_at);
+
+        let benched = result
+            .as_ref()
+            .is_ok_and(|response| bench_key(key, response));
+        if benched && api.keys.has_available() && attempt < api.max_retries {
+            attempt += 1;
+            continue;
+        }
+
+        if !is_retryable(&result) || attempt >= api.max_retries {
+            record_outcome(permit, &result);
+            return result.map_err(request_error);
+        }
+
+        let delay = api.base_delay * 2u32.saturating_pow(attempt);
+        let delay = delay + jitter(delay);
+        if Instant::now() + delay > deadline {
+            record_outcome(permit, &result);
+            return result.map_err(request_error);
+        }
+
+        attempt += 1;
+        tokio::time::sleep(delay).await;
+    }
+}
+
+// 재시도 대상 결과 (연결 실패, 타임아웃, 5xx)
+fn is_retryable(result: &Result<Response, reqwest::Error>) -> bool {
+    match result {
+        Ok(response) => response.status().is_server_error(),
+        Err(error) => is_transient(error),
+    }
+}
+
+// 최종 결과를 차단기에 기록
+// 4xx 응답 등 업스트림 장애가 아닌 결과는 성공으로 기록
+fn record_outcome(permit: BreakerPermit<'_>, result: &Result<Response, reqwest::Error>) {
+    if is_retryable(result) {
+        permit.failure();
+    } else {
+        permit.success();
+    }
+}
+
+// 응답 본문을 역직렬화
+// 실패한 경우 서버 로그에는 serde 오류 전체를, 클라이언트에는 요약만 전달한다
+pub async fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T, AppError> {
+    let endpoint = endpoint(Some(response.url()));
+    let bytes = response.bytes().await.map_err(request_error)?;
+
+    serde_json::from_slice(&bytes).map_err(|error| {
+        warn!(endpoint, %error, "failed to parse response JSON");
+
+        let reason = match error.classify() {
+            Category::Data => "unexpected schema",
+            Category::Syntax | Category::Eof => "malformed JSON",
+            Category::Io => "read error",
+        };
+
+        AppError::Deserialize {
+            endpoint,
+            reason: reason.to_string(),
+        }
+    })
+}
+
+// 넥슨 API 오류 응답 본문
+// {"error": {"name": "OPENAPI00004", "message": "Please input valid parameter"}}
+#[derive(Deserialize, Debug)]
+struct NexonErrorBody {
+    error: NexonError,
+}
+
+#[derive(Deserialize, Debug)]
+struct NexonError {
+    name: String,
+    message: String,
+}
+
+// 넥슨 API의 실패 응답을 오류로 변환
+pub async fn upstream_error(response: Response) -> AppError {
+    // reqwest와 axum의 http 버전이 달라 상태 코드를 변환
+    let status =
+        StatusCode::from_u16(response.status().as_u16()).unwrap_or(StatusCode::BAD_GATEWAY);
+    let endpoint = endpoint(Some(response.url()));
+
+    let retry_after = retry_after(&response);
+
+    let nexon_error = response
+        .bytes()
+        .await
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<NexonErrorBody>(&bytes).ok())
+        .map(|body| body.error);
+
+    let Some(NexonError { name, message }) = nexon_error else {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return AppError::RateLimited { retry_after };
+        }
+
+        return AppError::Upstream {
+            status,
+            message: format!("{endpoint} responded with {status}"),
+        };
+    };
+
+    warn!(endpoint, nexon_error = name, message, "nexon api error");
+
+    match name.as_str() {
+        "OPENAPI00007" => AppError::RateLimited { retry_after },
+        _ => AppError::Nexon { name, message },
+    }
+}
+
+// 넥슨 API의 "데이터 준비 중" 오류 코드
+const DATA_PREPARING: &str = "OPENAPI00009";
+
+// 성공 응답은 그대로 반환하고, 실패 응답은 오류로 변환
+// 해당 날짜의 데이터가 아직 준비되지 않은 경우에만 None을 반환해 다른 날짜로 재시도할 수 있게 한다
+pub async fn ready_response(response: Response) -> Result<Option<Response>, AppError> {
+    if response.status().is_success() {
+        return Ok(Some(response));
+    }
+
+    match upstream_error(response).await {
+        AppError::Nexon { name, .. } if name == DATA_PREPARING => Ok(None),
+        error => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jitter_stays_within_half_of_delay() {
+        for millis in [0, 1, 100, 800] {
+            let delay = Duration::from_millis(millis);
+            assert!(jitter(delay) <= delay / 2 + Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn endpoint_strips_region_prefix_and_groups_images() {
+        let url = |path: &str| Url::parse(&format!("https://open.api.nexon.com{path}")).unwrap();
+
+        assert_eq!(
+            endpoint(Some(&url("/maplestory/v1/character/basic?ocid=abc"))),
+            "character/basic"
+        );
+        assert_eq!(endpoint(Some(&url("/maplestorysea/v1/id"))), "id");
+        assert_eq!(
+            endpoint(Some(&url("/static/maplestory/character/look/ABCDEF"))),
+            "character/image"
+        );
+        assert_eq!(endpoint(None), "unknown");
+    }
+}
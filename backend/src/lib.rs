@@ -0,0 +1,59 @@
+//! melog 백엔드
+//!
+//! Nexon Open API(메이플스토리) 클라이언트와 응답 파서 / 계산기, 조회 핸들러를 제공한다.
+//! HTTP 서버는 `src/main.rs` 의 바이너리에서 `api::request::get_routes` 로 구성한다.
+//!
+//! # Features
+//!
+//! - `server` (기본값): axum 핸들러, 미들웨어, 응답 구조체와 바이너리.
+//!   라이브러리로 클라이언트와 파서만 사용하는 경우 `default-features = false` 로 axum / tower-http 없이 빌드할 수 있다.
+//!
+//! # 넥슨 API 클라이언트
+//!
+//! ```no_run
+//! use backend::api::client::NexonClient;
+//! use backend::api::config::Config;
+//! use backend::api::date::QueryDate;
+//! use serde_json::Value;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = Config::load(Some("API_KEY".to_string()))?;
+//! let client = NexonClient::new(&config)?;
+//!
+//! let ocid = client.get_ocid("닉네임").await?;
+//! let basic: Value = client
+//!     .get_character("character/basic", &ocid, &QueryDate::explicit("2024-06-01"))
+//!     .await?;
+//! println!("{}", basic["character_level"]);
+//!
+//! // 가장 최근 날짜 (데이터가 아직 준비되지 않았으면 하루 전 날짜로 다시 조회)
+//! let stat: Value = client
+//!     .get_character("character/stat", &ocid, &QueryDate::latest())
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # 파서 / 계산기
+//!
+//! ```
+//! use backend::api::character::hexa_cost::{cost_table, fragments_to_max};
+//! use backend::api::character::potential::parse_potential;
+//! use backend::api::canonical::canonical_json;
+//! use backend::api::region::Region;
+//!
+//! let line = serde_json::to_value(parse_potential("보스 몬스터 공격 시 데미지 : +40%")).unwrap();
+//! assert_eq!(line["stat"], "boss_damage");
+//! assert_eq!(line["value"], 40.0);
+//! assert_eq!(line["is_percent"], true);
+//!
+//! let skill_core = cost_table("스킬 코어").unwrap();
+//! assert_eq!(fragments_to_max(skill_core, 0), 4500);
+//!
+//! let value = serde_json::json!({ "b": 1, "a": [true, null] });
+//! assert_eq!(canonical_json(&value), r#"{"a":[true,null],"b":1}"#);
+//!
+//! assert_eq!(Region::parse("MSEA").map(Region::api_prefix), Some("maplestorysea/v1"));
+//! ```
+
+pub mod api;
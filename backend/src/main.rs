@@ -1,35 +1,143 @@
-mod api;
-
-use api::request::API;
-use api::request::get_routes;
-use axum::{Router, extract::Extension, http::HeaderValue};
+use axum::{Router, extract::Extension, middleware};
+use backend::api::cache::cache_status;
+use backend::api::client_limit::{ClientLimits, client_limit};
+use backend::api::compression::compression_layer;
+use backend::api::config::Config;
+use backend::api::demo::{DemoMode, demo_guard};
+use backend::api::metrics::{install_recorder, track_metrics};
+use backend::api::region::region_scope;
+use backend::api::request::API;
+use backend::api::request::get_routes;
+use backend::api::shutdown::shutdown_signal;
+use backend::api::trace::{init_tracing, request_span};
+use std::net::SocketAddr;
 use std::sync::Arc;
-use tower_http::cors::{Any, CorsLayer};
+use tokio::sync::Notify;
+use tower_http::LatencyUnit;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::{DefaultOnResponse, TraceLayer};
+use tracing::{Level, error, info, warn};
 
 #[tokio::main]
 async fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    // 사용법: cargo run [api_key] (API 키는 MELOG_API_KEY 또는 설정 파일로도 지정할 수 있다)
+    let config = match Config::load(std::env::args().nth(1)) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+    };
 
-    if args.len() < 2 {
-        println!("사용법: cargo run <arg>");
-        return;
-    }
+    init_tracing(config.log_json);
+    info!(?config, "configuration loaded");
 
-    let api_key = Arc::new(API::new(args[1].clone()));
+    let metrics = match install_recorder() {
+        Ok(metrics) => metrics,
+        Err(error) => {
+            error!("메트릭 레코더를 설치할 수 없습니다: {error}");
+            std::process::exit(1);
+        }
+    };
 
-    let allowed_origin = HeaderValue::from_static("http://localhost:5173");
+    let api_key = match API::new(&config, metrics) {
+        Ok(api_key) => Arc::new(api_key),
+        Err(error) => {
+            error!("API 키로 클라이언트를 만들 수 없습니다: {error}");
+            std::process::exit(1);
+        }
+    };
 
     let cors = CorsLayer::new()
-        .allow_origin(allowed_origin)
+        .allow_origin(AllowOrigin::list(config.cors_origins.clone()))
         .allow_methods([axum::http::Method::GET, axum::http::Method::POST])
         .allow_headers(Any);
 
     // TODO : VEC 형식으로 가져오는 값 자체가 null인 경우 예외처리 하기
-    let app = Router::new()
-        .merge(get_routes())
+    let mut app = Router::new().merge(get_routes());
+
+    // 데모 모드: 허용된 캐릭터 외의 요청은 업스트림 호출 전에 차단 (카세트 지정 시 기록된 응답만 반환)
+    match DemoMode::new(&config) {
+        Ok(Some(demo)) => {
+            app = app.layer(middleware::from_fn_with_state(Arc::new(demo), demo_guard));
+        }
+        Ok(None) => {}
+        Err(error) => {
+            error!("데모 모드를 설정할 수 없습니다: {error}");
+            std::process::exit(1);
+        }
+    }
+
+    // 클라이언트별 / IP별 요청 수 제한 (데모 모드 검사보다 먼저 실행)
+    if config.client_rate_limit > 0 || config.client_rate_limit_per_ip > 0 {
+        let limits = ClientLimits::new(
+            config.client_rate_limit,
+            config.client_rate_limit_per_ip,
+            config.client_rate_limit_max_clients,
+        );
+        app = app.layer(middleware::from_fn_with_state(
+            Arc::new(limits),
+            client_limit,
+        ));
+    }
+
+    // 요청마다 넥슨 API 지역 결정 (x-melog-region 헤더 또는 설정의 region)
+    app = app.layer(middleware::from_fn_with_state(
+        api_key.clone(),
+        region_scope,
+    ));
+
+    let app = app
+        .layer(middleware::from_fn(cache_status))
+        .layer(middleware::from_fn(track_metrics))
+        // 요청 ID 생성 → 요청 span / 응답 로그 → 응답 헤더에 요청 ID 추가 (아래 레이어가 먼저 실행된다)
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(request_span)
+                .on_response(
+                    DefaultOnResponse::new()
+                        .level(Level::INFO)
+                        .latency_unit(LatencyUnit::Millis),
+                ),
+        )
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        // Accept-Encoding 에 따라 gzip / brotli 압축 (작은 응답은 그대로)
+        .layer(compression_layer(config.compression_min_size))
         .layer(Extension(api_key))
         .layer(cors);
 
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    let listener = match tokio::net::TcpListener::bind(config.bind).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!("{} 에서 연결을 받을 수 없습니다: {error}", config.bind);
+            std::process::exit(1);
+        }
+    };
+
+    // 종료 신호를 받으면 새 연결을 받지 않고, 처리 중인 요청은 제한 시간까지 기다린다
+    let shutdown = Arc::new(Notify::new());
+    // 클라이언트 IP (uuid 헤더가 없는 요청의 요청 수 제한)
+    let app = app.into_make_service_with_connect_info::<SocketAddr>();
+    let server = axum::serve(listener, app).with_graceful_shutdown({
+        let shutdown = shutdown.clone();
+        async move { shutdown.notified().await }
+    });
+    let server = server.into_future();
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => result.unwrap(),
+        _ = shutdown_signal() => {
+            shutdown.notify_one();
+
+            match tokio::time::timeout(config.shutdown_timeout, server).await {
+                Ok(result) => result.unwrap(),
+                Err(_) => warn!("shutdown timed out, dropping in-flight requests"),
+            }
+        }
+    }
+
+    info!("shutdown completed");
 }
@@ -0,0 +1,107 @@
+// 통합 테스트 공용 도구 (모의 넥슨 서버를 가리키는 API / 라우터)
+#![allow(dead_code)]
+
+use axum::body::{Body, to_bytes};
+use axum::http::{HeaderMap, Request, StatusCode};
+use axum::{Extension, Router, middleware};
+use backend::api::cache::cache_status;
+use backend::api::config::Config;
+use backend::api::region::region_scope;
+use backend::api::request::{API, get_routes};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+pub const API_KEY: &str = "test-api-key-0000";
+
+// 모의 서버 주소를 넥슨 API 주소로 사용하는 설정
+pub fn config(base_url: &str) -> Config {
+    let mut config = Config::load_from([], Some(API_KEY.to_string())).expect("default config");
+    config.nexon_base_url = base_url.to_string();
+    config
+}
+
+pub fn api(base_url: &str) -> Arc<API> {
+    // 전역 레코더를 설치하지 않은 핸들 (테스트마다 새로 만들 수 있다)
+    let metrics = PrometheusBuilder::new().build_recorder().handle();
+    let mut api = API::new(&config(base_url), metrics).expect("api");
+    // 재시도 대기 시간을 줄여 5xx 테스트를 빠르게 끝낸다
+    api.nexon.base_delay = std::time::Duration::from_millis(1);
+    // 캐릭터 이미지도 모의 서버에서 받는다
+    api.image_origin = reqwest::Url::parse(base_url).expect("base url");
+    Arc::new(api)
+}
+
+// 바이너리와 같은 순서로 지역 / 캐시 상태 / 확장 레이어를 적용한 라우터
+pub fn app(api: Arc<API>) -> Router {
+    layered(get_routes(), api)
+}
+
+// 데모 모드처럼 라우트에 먼저 레이어를 추가한 경우
+pub fn layered(router: Router, api: Arc<API>) -> Router {
+    router
+        .layer(middleware::from_fn_with_state(api.clone(), region_scope))
+        .layer(middleware::from_fn(cache_status))
+        .layer(Extension(api))
+}
+
+pub async fn post_json(
+    app: Router,
+    uri: &str,
+    body: Value,
+    headers: &[(&str, &str)],
+) -> (StatusCode, Value) {
+    let (status, _, body) = post_json_with_headers(app, uri, body, headers).await;
+    (status, body)
+}
+
+// 응답 헤더까지 확인하는 경우 (x-melog-cache 등)
+pub async fn post_json_with_headers(
+    app: Router,
+    uri: &str,
+    body: Value,
+    headers: &[(&str, &str)],
+) -> (StatusCode, HeaderMap, Value) {
+    let mut request = Request::post(uri).header("content-type", "application/json");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let request = request.body(Body::from(body.to_string())).unwrap();
+
+    send(app, request).await
+}
+
+pub async fn get(app: Router, uri: &str) -> (StatusCode, Value) {
+    let (status, _, body) = send(app, Request::get(uri).body(Body::empty()).unwrap()).await;
+    (status, body)
+}
+
+pub async fn get_with_headers(
+    app: Router,
+    uri: &str,
+    headers: &[(&str, &str)],
+) -> (StatusCode, Value) {
+    let mut request = Request::get(uri);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let (status, _, body) = send(app, request.body(Body::empty()).unwrap()).await;
+    (status, body)
+}
+
+async fn send(app: Router, request: Request<Body>) -> (StatusCode, HeaderMap, Value) {
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+
+    (status, headers, body)
+}
+
+pub fn fixture(name: &str) -> Value {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
@@ -0,0 +1,226 @@
+// 데모 모드 (허용 목록, 요청 수 제한, 카세트 재생) 확인
+mod common;
+
+use axum::http::StatusCode;
+use axum::{Router, middleware};
+use backend::api::config::Config;
+use backend::api::demo::{DemoMode, demo_guard};
+use backend::api::request::get_routes;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn demo_app(server: &MockServer, configure: impl FnOnce(&mut Config)) -> Router {
+    let mut config = common::config(&server.uri());
+    config.demo_characters = vec!["데모".to_string()];
+    configure(&mut config);
+
+    let demo = DemoMode::new(&config).unwrap().expect("demo mode enabled");
+    let routes = get_routes().layer(middleware::from_fn_with_state(Arc::new(demo), demo_guard));
+    common::layered(routes, common::api(&server.uri()))
+}
+
+async fn mount_ocid(server: &MockServer, ocid: &str) {
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ocid": ocid })))
+        .mount(server)
+        .await;
+}
+
+async fn mount_basic(server: &MockServer, expected: u64) {
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::fixture("character_basic.json")),
+        )
+        .expect(expected)
+        .mount(server)
+        .await;
+}
+
+fn assert_forbidden(status: StatusCode, body: &Value) {
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "forbidden");
+    assert!(body["message"].as_str().unwrap().contains("Readme"));
+}
+
+#[tokio::test]
+async fn allowed_name_unlocks_its_ocid() {
+    let server = MockServer::start().await;
+    mount_ocid(&server, "demo-ocid").await;
+    mount_basic(&server, 1).await;
+    let app = demo_app(&server, |_| {});
+
+    let (status, body) =
+        common::post_json(app.clone(), "/getOcid", json!({ "nickName": "데모" }), &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["ocid"], "demo-ocid");
+
+    let (status, _) = common::post_json(
+        app,
+        "/getUserInfo?date=2024-06-01",
+        json!({ "ocid": "demo-ocid" }),
+        &[],
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn blocked_name_and_unknown_ocid_are_rejected_before_upstream() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let app = demo_app(&server, |_| {});
+
+    let (status, body) = common::post_json(
+        app.clone(),
+        "/getOcid",
+        json!({ "nickName": "다른캐릭터" }),
+        &[],
+    )
+    .await;
+    assert_forbidden(status, &body);
+
+    let (status, body) = common::post_json(
+        app.clone(),
+        "/getUserInfo?date=2024-06-01",
+        json!({ "ocid": "other" }),
+        &[],
+    )
+    .await;
+    assert_forbidden(status, &body);
+
+    // 키 소유자 계정으로 조회하는 경로
+    let (status, body) = common::get(app, "/getCharacterList").await;
+    assert_forbidden(status, &body);
+}
+
+#[tokio::test]
+async fn configured_ocids_are_allowed_without_lookup() {
+    let server = MockServer::start().await;
+    mount_basic(&server, 1).await;
+    let app = demo_app(&server, |config| {
+        config.demo_ocids = vec!["saved-ocid".to_string()];
+    });
+
+    let (status, _) = common::post_json(
+        app,
+        "/getUserInfo?date=2024-06-01",
+        json!({ "ocid": "saved-ocid" }),
+        &[],
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn requests_without_character_pass_through() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/notice"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "notice": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let app = demo_app(&server, |_| {});
+
+    let (status, body) = common::get(app, "/getNotice").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["notice"], json!([]));
+}
+
+#[tokio::test]
+async fn demo_requests_share_rate_limit() {
+    let server = MockServer::start().await;
+    mount_ocid(&server, "demo-ocid").await;
+    let app = demo_app(&server, |config| config.demo_rate_limit = 2);
+
+    for _ in 0..2 {
+        let (status, _) =
+            common::post_json(app.clone(), "/getOcid", json!({ "nickName": "데모" }), &[]).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // 클라이언트 헤더를 바꿔도 데모 전체 한도를 공유
+    let (status, body) = common::post_json(
+        app.clone(),
+        "/getOcid",
+        json!({ "nickName": "데모" }),
+        &[("uuid", "another-client")],
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(body["error"], "rate_limited");
+
+    // 헬스 체크는 제한하지 않는다
+    let (status, _) = common::get(app, "/health").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn cassette_replays_recorded_responses_without_upstream() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let app = demo_app(&server, |config| {
+        config.demo_characters = Vec::new();
+        config.demo_cassette = Some(
+            format!(
+                "{}/tests/fixtures/demo_cassette.json",
+                env!("CARGO_MANIFEST_DIR")
+            )
+            .into(),
+        );
+    });
+
+    let (status, body) =
+        common::post_json(app.clone(), "/getOcid", json!({ "nickName": "데모" }), &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["ocid"], "demo-ocid");
+
+    let (status, body) = common::post_json(
+        app.clone(),
+        "/getUserInfo?date=2024-06-01",
+        json!({ "ocid": "demo-ocid" }),
+        &[],
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["character_level"], 285);
+
+    let (status, body) = common::get(app.clone(), "/getNotice").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["notice"], json!([]));
+
+    // 카세트에 없는 요청 (다른 날짜, 다른 캐릭터) 은 거절
+    let (status, body) = common::post_json(
+        app.clone(),
+        "/getUserInfo?date=2024-06-02",
+        json!({ "ocid": "demo-ocid" }),
+        &[],
+    )
+    .await;
+    assert_forbidden(status, &body);
+
+    let (status, body) =
+        common::post_json(app, "/getOcid", json!({ "nickName": "다른캐릭터" }), &[]).await;
+    assert_forbidden(status, &body);
+}
+
+#[tokio::test]
+async fn invalid_cassette_fails_to_start() {
+    let server = MockServer::start().await;
+    let mut config = common::config(&server.uri());
+    config.demo_cassette = Some("/nonexistent/cassette.json".into());
+
+    assert!(DemoMode::new(&config).is_err());
+}
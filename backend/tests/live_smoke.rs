@@ -0,0 +1,254 @@
+// 실제 넥슨 API를 호출하는 스모크 테스트 (넥슨 API 패치 후 주간 점검용)
+// 기본으로는 실행되지 않으며, API 키를 지정해 직접 실행한다
+//   MELOG_SMOKE_API_KEY=... cargo test --test live_smoke -- --ignored --nocapture
+// 값이 바뀐 것은 실패로 보지 않고, 응답을 구조체로 파싱하지 못한 경우에만 실패한다
+// 원본 응답과 파싱 결과의 키 차이 (스키마 변경) 는 보고서로 출력한다
+mod common;
+
+use axum::Router;
+use backend::api::config::Config;
+use backend::api::date::QueryDate;
+use backend::api::request::API;
+use backend::api::schema::{KeyDiff, diff_keys};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+
+const API_KEY_ENV: &str = "MELOG_SMOKE_API_KEY";
+// 조회할 캐릭터 (기본값은 공개 랭킹 상위 캐릭터)
+const CHARACTER_ENV: &str = "MELOG_SMOKE_CHARACTER";
+const DEFAULT_CHARACTER: &str = "아델";
+// 넥슨 API 호출 한도 (초당 호출 수) 안에서 1분 안에 끝나야 한다
+const TIME_LIMIT: Duration = Duration::from_secs(60);
+
+// 조회할 경로와 요청 본문, 키 차이를 비교할 넥슨 API 경로 (응답을 변환하는 핸들러는 비교하지 않음)
+struct Case {
+    route: String,
+    body: Option<Value>,
+    nexon: Option<&'static str>,
+}
+
+impl Case {
+    fn post(route: &str, body: Value, nexon: Option<&'static str>) -> Self {
+        Self {
+            route: route.to_string(),
+            body: Some(body),
+            nexon,
+        }
+    }
+
+    fn get(route: &str) -> Self {
+        Self {
+            route: route.to_string(),
+            body: None,
+            nexon: None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Report {
+    // 응답을 파싱하지 못한 경로 (실패)
+    failures: Vec<String>,
+    // 파싱과 관계없는 오류 (데이터 없음, 점검 중 등)
+    warnings: Vec<String>,
+    drift: Vec<(String, KeyDiff)>,
+}
+
+fn live_api(api_key: String) -> Arc<API> {
+    // 설정 파일 / 환경 변수의 호출 한도를 그대로 사용한다
+    let config = Config::load(Some(api_key)).expect("config");
+    let metrics = PrometheusBuilder::new().build_recorder().handle();
+    Arc::new(API::new(&config, metrics).expect("api"))
+}
+
+async fn call(app: &Router, case: &Case) -> (u16, Value) {
+    let (status, body) = match &case.body {
+        Some(body) => common::post_json(app.clone(), &case.route, body.clone(), &[]).await,
+        None => common::get(app.clone(), &case.route).await,
+    };
+    (status.as_u16(), body)
+}
+
+fn character_cases(ocid: &str) -> Vec<Case> {
+    let body = json!({ "ocid": ocid });
+    let character =
+        |route: &str, nexon: Option<&'static str>| Case::post(route, body.clone(), nexon);
+
+    vec![
+        character("/getUserInfo", Some("character/basic")),
+        character("/getCharacterImageUrl", None),
+        character("/getUserStatInfo", Some("character/stat")),
+        character("/getUserHyperStatInfo", Some("character/hyper-stat")),
+        character("/getUserPropensity", Some("character/propensity")),
+        character("/getUserAbility", Some("character/ability")),
+        character("/getUserItemEquipment", Some("character/item-equipment")),
+        character(
+            "/getUserCashItemEquipment",
+            Some("character/cashitem-equipment"),
+        ),
+        character(
+            "/getUserSymbolEquipment",
+            Some("character/symbol-equipment"),
+        ),
+        character("/getUserSetEffect", Some("character/set-effect")),
+        character(
+            "/getUserBeautyEquipment",
+            Some("character/beauty-equipment"),
+        ),
+        character(
+            "/getUserAndroidEquipment",
+            Some("character/android-equipment"),
+        ),
+        character("/getUserPetEquipment", Some("character/pet-equipment")),
+        character("/getUserCharacterLinkSkill", Some("character/link-skill")),
+        character("/getUserVMatrix", None),
+        character("/getUserHexaMatrix", Some("character/hexamatrix")),
+        character("/getUserHexStatInfo", Some("character/hexamatrix-stat")),
+        character("/getUserDojang", Some("character/dojang")),
+        character("/getUserPopularity", Some("character/popularity")),
+        Case::post(
+            "/getUserCharacterSkill",
+            json!({ "user_ocid": { "ocid": ocid }, "level": "6" }),
+            None,
+        ),
+        character("/getUnion", Some("user/union")),
+        character("/getUnionRaider", Some("user/union-raider")),
+        character("/getUnionArtifact", Some("user/union-artifact")),
+        character("/getUnionChampion", Some("user/union-champion")),
+    ]
+}
+
+fn public_cases() -> Vec<Case> {
+    vec![
+        Case::post("/getOverAllRanking", json!({}), None),
+        Case::post("/getUnionRanking", json!({}), None),
+        Case::post("/getGuildRanking", json!({ "ranking_type": 0 }), None),
+        Case::post("/getDojangRanking", json!({ "difficulty": 1 }), None),
+        Case::post("/getTheseedRanking", json!({}), None),
+        Case::post("/getAchievementRanking", json!({}), None),
+        Case::get("/getNotice"),
+        Case::get("/getUpdateNotice"),
+        Case::get("/getEvnetNotice"),
+        Case::get("/getCashShopNotice"),
+        // API 키 소유자 계정으로 조회하는 경로
+        Case::get("/getCharacterList"),
+        Case::get("/getCubeHistory?count=10"),
+        Case::get("/getStarforceHistory?count=10"),
+        Case::get("/getPotentialHistory?count=10"),
+    ]
+}
+
+async fn check(api: &Arc<API>, app: &Router, ocid: &str, case: &Case, report: &mut Report) {
+    let (status, body) = call(app, case).await;
+
+    if status == 502 && body["error"] == "upstream_invalid_response" {
+        report
+            .failures
+            .push(format!("{}: {}", case.route, body["message"]));
+        return;
+    }
+    if !(200..300).contains(&status) {
+        report
+            .warnings
+            .push(format!("{}: {status} {}", case.route, body["error"]));
+        return;
+    }
+
+    let Some(path) = case.nexon else {
+        return;
+    };
+    match api
+        .get_character::<Value>(path, ocid, &QueryDate::latest())
+        .await
+    {
+        Ok(upstream) => {
+            let diff = diff_keys(&upstream, &body);
+            if !diff.is_empty() {
+                report.drift.push((case.route.clone(), diff));
+            }
+        }
+        Err(error) => report.warnings.push(format!("{path}: {error}")),
+    }
+}
+
+async fn run(api: Arc<API>, nick_name: &str) -> Report {
+    let app = common::app(api.clone());
+    let mut report = Report::default();
+
+    let (status, body) = call(
+        &app,
+        &Case::post("/getOcid", json!({ "nickName": nick_name }), None),
+    )
+    .await;
+    assert_eq!(
+        status, 200,
+        "{nick_name} 캐릭터를 조회할 수 없습니다: {body}"
+    );
+    let ocid = body["ocid"].as_str().expect("ocid").to_string();
+
+    for case in character_cases(&ocid).iter().chain(&public_cases()) {
+        check(&api, &app, &ocid, case, &mut report).await;
+    }
+
+    // 캐릭터가 속한 길드 (길드가 없으면 생략)
+    let (_, basic) = call(&app, &character_cases(&ocid)[0]).await;
+    if let (Some(guild_name), Some(world_name)) = (
+        basic["character_guild_name"]
+            .as_str()
+            .filter(|name| !name.is_empty()),
+        basic["world_name"].as_str(),
+    ) {
+        let guild = Case::post(
+            "/getGuildOcid",
+            json!({ "guildName": guild_name, "worldName": world_name }),
+            None,
+        );
+        let (status, body) = call(&app, &guild).await;
+        match body["oguild_id"].as_str() {
+            Some(oguild_id) => {
+                let info = Case::post("/getGuildInfo", json!({ "oguild_id": oguild_id }), None);
+                check(&api, &app, &ocid, &info, &mut report).await;
+            }
+            None => report
+                .warnings
+                .push(format!("/getGuildOcid: {status} {}", body["error"])),
+        }
+    }
+
+    report
+}
+
+#[tokio::test]
+#[ignore = "실제 넥슨 API 키가 필요하다 (MELOG_SMOKE_API_KEY)"]
+async fn live_schema_smoke() {
+    let api_key = std::env::var(API_KEY_ENV)
+        .unwrap_or_else(|_| panic!("{API_KEY_ENV} 환경 변수에 넥슨 API 키를 지정해 주세요"));
+    let nick_name = std::env::var(CHARACTER_ENV).unwrap_or_else(|_| DEFAULT_CHARACTER.to_string());
+
+    let report = tokio::time::timeout(TIME_LIMIT, run(live_api(api_key), &nick_name))
+        .await
+        .expect("스모크 테스트가 1분 안에 끝나지 않았습니다");
+
+    println!("== schema drift ({} endpoints)", report.drift.len());
+    for (route, diff) in &report.drift {
+        println!("{route}");
+        for key in &diff.unknown {
+            println!("  + {key} (넥슨 API에만 있음)");
+        }
+        for key in &diff.missing {
+            println!("  - {key} (응답에만 있음: 빠진 필드 또는 계산된 필드)");
+        }
+    }
+    println!("== warnings ({})", report.warnings.len());
+    for warning in &report.warnings {
+        println!("  {warning}");
+    }
+
+    assert!(
+        report.failures.is_empty(),
+        "응답을 파싱하지 못했습니다:\n{}",
+        report.failures.join("\n")
+    );
+}
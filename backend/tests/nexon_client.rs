@@ -0,0 +1,690 @@
+// 모의 넥슨 서버로 NexonClient 와 핸들러 전체 흐름 (URL, 헤더, 오류 변환) 확인
+mod common;
+
+use axum::http::StatusCode;
+use backend::api::circuit_breaker::BreakerState;
+use backend::api::date::{QueryDate, default_date, previous_date};
+use backend::api::error::AppError;
+use serde_json::{Value, json};
+use wiremock::matchers::{header, header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn nexon_error(name: &str, message: &str) -> Value {
+    json!({ "error": { "name": name, "message": message } })
+}
+
+#[tokio::test]
+async fn get_ocid_encodes_name_and_sends_api_key() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/id"))
+        .and(query_param("character_name", "a b&c%"))
+        .and(header("x-nxopen-api-key", common::API_KEY))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ocid": "abc" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+    assert_eq!(api.get_ocid("a b&c%").await.unwrap(), "abc");
+}
+
+#[tokio::test]
+async fn get_ocid_handler_returns_ocid() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/id"))
+        .and(query_param("character_name", "테스트"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ocid": "abc" })))
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) =
+        common::post_json(app, "/getOcid", json!({ "nickName": "테스트" }), &[]).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "ocid": "abc" }));
+}
+
+#[tokio::test]
+async fn get_ocid_is_served_from_response_cache() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/id"))
+        .and(query_param("character_name", "테스트"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ocid": "abc" })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let request = || json!({ "nickName": "테스트" });
+
+    let (status, headers, body) =
+        common::post_json_with_headers(app.clone(), "/getOcid", request(), &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "ocid": "abc" }));
+    assert_eq!(headers["x-melog-cache"], "miss");
+
+    // 같은 닉네임은 캐시에서 처리 (모의 서버 호출 수 그대로)
+    let (status, headers, body) =
+        common::post_json_with_headers(app.clone(), "/getOcid", request(), &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "ocid": "abc" }));
+    assert_eq!(headers["x-melog-cache"], "hit");
+
+    // ?refresh=true 는 넥슨 API를 다시 조회한다
+    let (status, headers, _) =
+        common::post_json_with_headers(app, "/getOcid?refresh=true", request(), &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["x-melog-cache"], "miss");
+}
+
+#[tokio::test]
+async fn unknown_character_is_not_cached() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/id"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00003", "Please input valid id")),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+
+    // 새로 만든 캐릭터가 바로 조회되도록 실패한 조회는 저장하지 않는다
+    for _ in 0..2 {
+        assert!(matches!(
+            api.get_ocid("없는캐릭터").await,
+            Err(AppError::OcidNotFound)
+        ));
+    }
+    assert!(api.cache.is_empty());
+}
+
+#[tokio::test]
+async fn get_ocid_handler_maps_unknown_character_to_404() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/id"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00003", "Please input valid id")),
+        )
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) =
+        common::post_json(app, "/getOcid", json!({ "nickName": "없는캐릭터" }), &[]).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "ocid_not_found");
+}
+
+#[tokio::test]
+async fn get_ocid_keeps_other_client_errors() {
+    let cases = [
+        (
+            403,
+            nexon_error("OPENAPI00005", "Please input valid api key"),
+            StatusCode::BAD_GATEWAY,
+            "upstream_error",
+        ),
+        (
+            400,
+            nexon_error("OPENAPI00004", "Please input valid parameter"),
+            StatusCode::BAD_REQUEST,
+            "invalid_parameter",
+        ),
+        (404, json!({}), StatusCode::BAD_REQUEST, "upstream_error"),
+    ];
+
+    for (upstream_status, upstream_body, status, code) in cases {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/maplestory/v1/id"))
+            .respond_with(ResponseTemplate::new(upstream_status).set_body_json(upstream_body))
+            .mount(&server)
+            .await;
+
+        let app = common::app(common::api(&server.uri()));
+        let (actual, body) =
+            common::post_json(app, "/getOcid", json!({ "nickName": "테스트" }), &[]).await;
+
+        assert_eq!(actual, status, "{upstream_status}");
+        assert_eq!(body["error"], code, "{upstream_status}");
+    }
+}
+
+#[tokio::test]
+async fn get_ocid_handler_rejects_invalid_name_without_calling_upstream() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) =
+        common::post_json(app, "/getOcid", json!({ "nickName": "a b" }), &[]).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"], "unprocessable");
+}
+
+#[tokio::test]
+async fn basic_handler_requests_ocid_and_date() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .and(query_param("ocid", "abc"))
+        .and(query_param("date", "2024-06-01"))
+        .and(header("x-nxopen-api-key", common::API_KEY))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::fixture("character_basic.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserInfo?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["date"], "2024-06-01");
+    assert_eq!(body["character_name"], "테스트");
+    assert_eq!(body["character_guild_name"], "");
+    assert_eq!(body["access_flag"], true);
+}
+
+#[tokio::test]
+async fn basic_handler_retries_previous_date_while_default_date_is_prepared() {
+    let server = MockServer::start().await;
+    let date = default_date();
+    let previous = previous_date(&date);
+    let mut basic = common::fixture("character_basic.json");
+    basic["date"] = json!(format!("{previous}T00:00+09:00"));
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .and(query_param("date", date.as_str()))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00009", "Data being prepared")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .and(query_param("date", previous.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(basic))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) =
+        common::post_json(app, "/getUserInfo", json!({ "ocid": "abc" }), &[]).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["date"], previous);
+}
+
+#[tokio::test]
+async fn basic_handler_keeps_explicit_date_while_data_is_prepared() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00009", "Data being prepared")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserInfo?date=2024-06-02",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["nexon_error"], "OPENAPI00009");
+}
+
+#[tokio::test]
+async fn basic_handler_rejects_future_date_without_calling_upstream() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    // 시간대와 관계없이 지역 시간 기준 오늘 이후인 날짜
+    let future = (chrono::Utc::now() + chrono::Duration::days(2)).format("%Y-%m-%d");
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        &format!("/getUserInfo?date={future}"),
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["message"], "date must be earlier than today");
+}
+
+#[tokio::test]
+async fn basic_handler_maps_nexon_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00004", "Please input valid parameter")),
+        )
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserInfo?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "invalid_parameter");
+    assert_eq!(body["nexon_error"], "OPENAPI00004");
+    assert_eq!(body["message"], "Please input valid parameter");
+}
+
+#[tokio::test]
+async fn basic_handler_rejects_empty_ocid_without_calling_upstream() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+    let result = api
+        .get_character::<Value>("character/basic", " ", &QueryDate::explicit("2024-06-01"))
+        .await;
+
+    assert!(matches!(result, Err(AppError::OcidNotFound)));
+}
+
+#[tokio::test]
+async fn item_equipment_handler_returns_parsed_items() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/item-equipment"))
+        .and(query_param("ocid", "abc"))
+        .and(query_param("date", "2024-06-01"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::fixture("item_equipment.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserItemEquipment?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let items = body["item_equipment"].as_array().unwrap();
+    assert_eq!(items.len(), 24);
+    assert_eq!(items[0]["item_equipment_slot"], "모자");
+    assert_eq!(items[0]["starforce_level"], 22);
+    assert_eq!(items[10]["item_equipment_slot"], "무기");
+    assert_eq!(items[10]["max_starforce"], 25);
+    assert_eq!(body["starforce_summary"]["total_stars"], 332);
+}
+
+#[tokio::test]
+async fn item_equipment_handler_maps_schema_mismatch_to_502() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/item-equipment"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "item_equipment": "x" })))
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserItemEquipment?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+    assert_eq!(body["error"], "upstream_invalid_response");
+}
+
+#[tokio::test]
+async fn retried_request_counts_as_single_breaker_failure() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+    let result = api
+        .get_character::<Value>("character/basic", "abc", &QueryDate::explicit("2024-06-01"))
+        .await;
+
+    // 재시도 3회를 포함한 4번의 호출이 실패해도 요청 하나의 실패로만 기록
+    assert!(matches!(result, Err(AppError::Upstream { .. })));
+    assert_eq!(api.breaker.state(), BreakerState::Closed);
+}
+
+#[tokio::test]
+async fn character_image_is_fetched_without_api_key() {
+    let server = MockServer::start().await;
+    let mut basic = common::fixture("character_basic.json");
+    basic["character_image"] = json!(format!(
+        "{}/static/maplestory/character/look/ABC",
+        server.uri()
+    ));
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(basic))
+        .mount(&server)
+        .await;
+    // API 키를 붙여 보내면 실패
+    Mock::given(method("GET"))
+        .and(path("/static/maplestory/character/look/ABC"))
+        .and(header_exists("x-nxopen-api-key"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(0)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/static/maplestory/character/look/ABC"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(vec![1, 2, 3], "image/webp"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+    let app = common::app(api.clone());
+    for _ in 0..2 {
+        let (status, headers, body) = common::post_json_with_headers(
+            app.clone(),
+            "/getCharacterImage?date=2024-06-01",
+            json!({ "ocid": "abc" }),
+            &[],
+        )
+        .await;
+
+        // 캐시에서 꺼낸 응답도 넥슨 응답의 Content-Type 을 유지한다
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers["content-type"], "image/webp");
+        assert_eq!(body, Value::String("\u{1}\u{2}\u{3}".to_string()));
+    }
+    assert_eq!(api.image_cache.len(), 1);
+}
+
+#[tokio::test]
+async fn character_image_from_other_host_is_rejected() {
+    let server = MockServer::start().await;
+    let image_server = MockServer::start().await;
+    let mut basic = common::fixture("character_basic.json");
+    basic["character_image"] = json!(format!("{}/look/ABC", image_server.uri()));
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(basic))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(vec![1, 2, 3], "image/png"))
+        .expect(0)
+        .mount(&image_server)
+        .await;
+
+    let api = common::api(&server.uri());
+    let (status, body) = common::post_json(
+        common::app(api.clone()),
+        "/getCharacterImage?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+    assert_eq!(body["error"], "upstream_invalid_response");
+    assert!(api.image_cache.is_empty());
+}
+
+#[tokio::test]
+async fn union_handler_uses_shared_character_lookup() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/user/union"))
+        .and(query_param("ocid", "abc"))
+        .and(query_param("date", "2024-06-01"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "date": "2024-06-01T00:00+09:00",
+            "union_level": 8000,
+            "union_grade": "그랜드 마스터 유니온 1",
+            "union_artifact_level": null,
+            "union_artifact_exp": null,
+            "union_artifact_point": null
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, headers, body) = common::post_json_with_headers(
+        app.clone(),
+        "/getUnion?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["union_level"], 8000);
+    assert_eq!(body["union_artifact_level"], 0);
+    assert_eq!(headers["x-melog-cache"], "miss");
+
+    // 같은 (ocid, 경로, 날짜) 는 캐시에서 처리
+    let (_, headers, _) = common::post_json_with_headers(
+        app,
+        "/getUnion?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+    assert_eq!(headers["x-melog-cache"], "hit");
+}
+
+#[tokio::test]
+async fn item_equipment_handler_rejects_unknown_slot_with_422() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserItemEquipment?date=2024-06-01&slot=%EB%B0%98%EC%A7%80",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"], "unprocessable");
+    assert!(body["message"].as_str().unwrap().contains("엠블렘"));
+}
+
+#[tokio::test]
+async fn malformed_query_returns_json_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserItemEquipment?min_star=abc",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "invalid_input");
+    assert!(body["message"].as_str().unwrap().contains("min_star"));
+}
+
+#[tokio::test]
+async fn server_errors_are_retried_until_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(2)
+        .with_priority(1)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::fixture("character_basic.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+    let basic: Value = api
+        .get_character("character/basic", "abc", &QueryDate::explicit("2024-06-01"))
+        .await
+        .unwrap();
+
+    assert!(basic["character_name"].is_string());
+    assert_eq!(api.breaker.state(), BreakerState::Closed);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00004", "Please input valid parameter")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let api = common::api(&server.uri());
+    let result = api
+        .get_character::<Value>("character/basic", "abc", &QueryDate::explicit("2024-06-01"))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Nexon { name, .. }) if name == "OPENAPI00004"));
+}
+
+#[tokio::test]
+async fn summary_returns_sections_that_succeeded() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/character/basic"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::fixture("character_basic.json")),
+        )
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00004", "Please input valid parameter")),
+        )
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserSummary?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["basic"]["ok"]["character_name"].is_string());
+    assert_eq!(body["stat"]["error"]["code"], "invalid_parameter");
+    assert_eq!(body["hexamatrix"]["error"]["code"], "invalid_parameter");
+}
+
+#[tokio::test]
+async fn summary_fails_when_every_section_fails() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(nexon_error("OPENAPI00004", "Please input valid parameter")),
+        )
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserSummary?date=2024-06-01",
+        json!({ "ocid": "abc" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_GATEWAY);
+    assert_eq!(body["error"], "upstream_error");
+}
@@ -0,0 +1,89 @@
+// 랭킹 / 길드 조회의 쿼리 파라미터 인코딩 확인
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn overall_ranking_encodes_parameters() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/ranking/overall"))
+        .and(query_param("date", "2024-06-01"))
+        .and(query_param("world_name", "스카니아&page=99"))
+        .and(query_param("class", "전사-히어로"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ranking": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getOverAllRanking",
+        json!({
+            "date": "2024-06-01",
+            "world_name": "스카니아&page=99",
+            "class": "전사-히어로",
+            "page": 2
+        }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["page"], 2);
+}
+
+#[tokio::test]
+async fn guild_ranking_encodes_guild_name() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/ranking/guild"))
+        .and(query_param("ranking_type", "0"))
+        .and(query_param("guild_name", "a&ranking_type=2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "ranking": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, _) = common::post_json(
+        app,
+        "/getGuildRanking",
+        json!({ "date": "2024-06-01", "ranking_type": 0, "guild_name": "a&ranking_type=2" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn guild_info_encodes_guild_id() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestory/v1/guild/basic"))
+        .and(query_param("oguild_id", "abc&date=2020-01-01"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": { "name": "OPENAPI00004", "message": "Please input valid parameter" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getGuildInfo",
+        json!({ "oguild_id": "abc&date=2020-01-01" }),
+        &[],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["nexon_error"], "OPENAPI00004");
+}
@@ -0,0 +1,168 @@
+// x-melog-region 헤더에 따른 넥슨 API 주소 / 지역 제한 확인
+mod common;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::matchers::{method, path, path_regex, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const MSEA: (&str, &str) = ("x-melog-region", "msea");
+
+// 다이제스트의 모든 섹션 구조체로 읽을 수 있는 응답 (섹션별 필수 필드를 합친 것)
+fn digest_section_body(date: &str) -> Value {
+    let mut body = json!({
+        "hyper_stat_preset_1": [],
+        "hyper_stat_preset_1_remain_point": 0,
+        "hyper_stat_preset_2": [],
+        "hyper_stat_preset_2_remain_point": 0,
+        "hyper_stat_preset_3": [],
+        "hyper_stat_preset_3_remain_point": 0,
+        "charisma_level": 0,
+        "sensibility_level": 0,
+        "insight_level": 0,
+        "willingness_level": 0,
+        "handicraft_level": 0,
+        "charm_level": 0,
+        "ability_grade": null,
+        "set_effect": [],
+        "character_v_core_equipment": [],
+        "character_v_matrix_remain_slot_upgrade_point": 0,
+        "character_hexa_core_equipment": [],
+        "character_hexa_stat_core": [],
+        "character_hexa_stat_core_2": [],
+        "character_hexa_stat_core_3": [],
+        "preset_hexa_stat_core": [],
+        "preset_hexa_stat_core_2": [],
+        "preset_hexa_stat_core_3": [],
+        "dojang_best_floor": 0,
+        "date_dojang_record": null,
+        "dojang_best_time": 0
+    });
+    for fixture in [
+        "character_basic.json",
+        "character_stat.json",
+        "item_equipment.json",
+        "symbol_equipment.json",
+    ] {
+        let Value::Object(fields) = common::fixture(fixture) else {
+            panic!("{fixture} is not an object");
+        };
+        body.as_object_mut().unwrap().extend(fields);
+    }
+    body["date"] = json!(date);
+    body
+}
+
+#[tokio::test]
+async fn digest_uses_msea_base_url_for_every_section() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex("^/maplestorysea/v1/character/"))
+        .and(query_param("date", "2025-06-01"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(digest_section_body("2025-06-01T00:00+08:00")),
+        )
+        .expect(14)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex("^/maplestory/v1/"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, headers, body) = common::post_json_with_headers(
+        app.clone(),
+        "/getUserDigest?date=2025-06-01",
+        json!({ "ocid": "abc" }),
+        &[MSEA],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["date"], "2025-06-01");
+    assert!(
+        body["sections"]
+            .as_object()
+            .unwrap()
+            .values()
+            .all(|hash| hash.is_string())
+    );
+    assert_eq!(headers["x-melog-cache"], "miss");
+
+    // 두 번째 요청은 모든 섹션이 캐시에서 처리된다 (모의 서버 호출 수는 그대로 14)
+    let (status, headers, _) = common::post_json_with_headers(
+        app,
+        "/getUserDigest?date=2025-06-01",
+        json!({ "ocid": "abc" }),
+        &[MSEA],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["x-melog-cache"], "hit");
+}
+
+#[tokio::test]
+async fn skill_all_uses_msea_base_url() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/maplestorysea/v1/character/skill"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "date": "2025-06-01T00:00+08:00",
+            "character_skill": []
+        })))
+        .expect(9)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::post_json(
+        app,
+        "/getUserCharacterSkillAll?date=2025-06-01",
+        json!({ "ocid": "abc" }),
+        &[MSEA],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["date"], "2025-06-01");
+}
+
+#[tokio::test]
+async fn kms_only_endpoints_return_501_in_msea() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) = common::get_with_headers(app.clone(), "/getNotice", &[MSEA]).await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    assert_eq!(body["error"], "not_implemented");
+
+    let (status, body) = common::post_json(
+        app,
+        "/getUnionChampion?date=2025-06-01",
+        json!({ "ocid": "abc" }),
+        &[MSEA],
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    assert_eq!(body["error"], "not_implemented");
+}
+
+#[tokio::test]
+async fn unknown_region_header_is_rejected() {
+    let server = MockServer::start().await;
+    let app = common::app(common::api(&server.uri()));
+    let (status, body) =
+        common::get_with_headers(app, "/getNotice", &[("x-melog-region", "gms")]).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], "invalid_input");
+}
@@ -0,0 +1,109 @@
+// 넥슨 응답의 date 가 YYYY-MM-DD 로 정규화되어 응답에 포함되는지 확인
+// (직접 지정한 날짜 / 기본 날짜가 준비되지 않아 전날로 다시 조회한 경우)
+mod common;
+
+use axum::http::StatusCode;
+use backend::api::date::{default_date, previous_date};
+use serde_json::{Value, json};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// (라우트, 넥슨 API 경로, 응답 fixture)
+const CASES: [(&str, &str, &str); 7] = [
+    ("/getUserInfo", "character/basic", "character_basic.json"),
+    (
+        "/getUserItemEquipment",
+        "character/item-equipment",
+        "item_equipment.json",
+    ),
+    (
+        "/getUserItemEquipmentTotals",
+        "character/item-equipment",
+        "item_equipment.json",
+    ),
+    ("/getUserStatInfo", "character/stat", "character_stat.json"),
+    (
+        "/getUserStatInfo?normalized=true",
+        "character/stat",
+        "character_stat.json",
+    ),
+    (
+        "/getUserSymbolEquipment",
+        "character/symbol-equipment",
+        "symbol_equipment.json",
+    ),
+    ("/getUnion", "user/union", "union.json"),
+];
+
+// fixture 의 date 를 지정한 날짜로 바꾼 넥슨 응답
+fn fixture_at(name: &str, date: &str) -> Value {
+    let mut fixture = common::fixture(name);
+    fixture["date"] = json!(format!("{date}T00:00+09:00"));
+    fixture
+}
+
+fn with_date(route: &str, date: &str) -> String {
+    let separator = if route.contains('?') { '&' } else { '?' };
+    format!("{route}{separator}date={date}")
+}
+
+#[tokio::test]
+async fn explicit_date_is_serialized_in_responses() {
+    for (route, upstream, fixture) in CASES {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/maplestory/v1/{upstream}")))
+            .and(query_param("date", "2024-06-01"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(fixture_at(fixture, "2024-06-01")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let app = common::app(common::api(&server.uri()));
+        let (status, body) = common::post_json(
+            app,
+            &with_date(route, "2024-06-01"),
+            json!({ "ocid": "abc" }),
+            &[],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{route}");
+        assert_eq!(body["date"], "2024-06-01", "{route}");
+    }
+}
+
+#[tokio::test]
+async fn fallback_date_is_serialized_in_responses() {
+    let date = default_date();
+    let previous = previous_date(&date);
+
+    for (route, upstream, fixture) in CASES {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/maplestory/v1/{upstream}")))
+            .and(query_param("date", date.as_str()))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "error": { "name": "OPENAPI00009", "message": "Data being prepared" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/maplestory/v1/{upstream}")))
+            .and(query_param("date", previous.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture_at(fixture, &previous)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let app = common::app(common::api(&server.uri()));
+        let (status, body) = common::post_json(app, route, json!({ "ocid": "abc" }), &[]).await;
+
+        // 요청한 기본 날짜가 아니라 실제로 조회된 전날 날짜
+        assert_eq!(status, StatusCode::OK, "{route}");
+        assert_eq!(body["date"], previous.as_str(), "{route}");
+    }
+}